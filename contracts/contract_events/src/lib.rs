@@ -0,0 +1,72 @@
+//! # Contract events
+//!
+//! The typed event convention shared by every contract in the workspace.
+//! Each contract declares its event structs and implements [`Event`] for
+//! them; an event's topics are `(action, subject, EVENT_VERSION)` and its
+//! data is the struct itself, encoded as a map keyed by field name.
+//!
+//! With the `testutils` feature, [`testutils`] provides assertions on the
+//! events a contract published.
+
+#![no_std]
+
+use soroban_sdk::{Env, IntoVal, Symbol, Val};
+
+/// Version of the event layouts, published as the third topic of every
+/// event. Raised whenever a field of any contract's events is added,
+/// removed or changes meaning.
+pub const EVENT_VERSION: u32 = 1;
+
+/// A typed event with fixed topics.
+pub trait Event: IntoVal<Env, Val> + Sized {
+    const ACTION: Symbol;
+    const SUBJECT: Symbol;
+
+    /// The full topic list published with this event.
+    fn topics() -> (Symbol, Symbol, u32) {
+        (Self::ACTION, Self::SUBJECT, EVENT_VERSION)
+    }
+
+    fn publish(self, env: &Env) {
+        env.events().publish(Self::topics(), self);
+    }
+}
+
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils {
+    use super::Event;
+    use soroban_sdk::testutils::Events as _;
+    use soroban_sdk::{vec, Address, Env, IntoVal};
+
+    /// Assert that the last event `contract` published during the most
+    /// recent top-level invocation is exactly `event`.
+    pub fn assert_last_event<E: Event>(env: &Env, contract: &Address, event: E) {
+        let last = env
+            .events()
+            .all()
+            .iter()
+            .filter(|(address, _, _)| address == contract)
+            .last()
+            .expect("no event published");
+        let expected = (
+            contract.clone(),
+            E::topics().into_val(env),
+            event.into_val(env),
+        );
+        assert_eq!(vec![env, last], vec![env, expected]);
+    }
+
+    /// Assert that `contract` published `event` during the most recent
+    /// top-level invocation, in any position.
+    pub fn assert_event_published<E: Event>(env: &Env, contract: &Address, event: E) {
+        let expected = (
+            contract.clone(),
+            E::topics().into_val(env),
+            event.into_val(env),
+        );
+        assert!(
+            env.events().all().contains(&expected),
+            "event not published"
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use soroban_sdk::contracterror;
+
+/// Error codes returned by the BITE token.
+///
+/// Codes are part of the contract's public interface: never renumber an
+/// existing variant, only append new ones.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    // Access control and lifecycle.
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    /// Caller is not the proposed admin.
+    Unauthorized = 3,
+    /// Only the contract admin may perform this action.
+    NotAdmin = 4,
+    /// Only the contract admin or the minter may mint.
+    NotMinter = 5,
+    NoPendingAdmin = 6,
+
+    // Balances and allowances.
+    /// Mint, transfer or burn amount is zero or negative.
+    InvalidAmount = 7,
+    InsufficientBalance = 8,
+    /// Approved amount is negative.
+    NegativeAllowance = 9,
+    /// A non-zero approval expires before the current ledger.
+    ExpirationInPast = 10,
+    InsufficientAllowance = 11,
+
+    // Storage TTL.
+    /// A TTL threshold exceeds its extension, or an extension is zero or
+    /// above the network maximum.
+    InvalidTtlPolicy = 12,
+}
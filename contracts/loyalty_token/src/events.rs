@@ -0,0 +1,184 @@
+//! # Events
+//!
+//! Every state change publishes exactly one event. Its topics are
+//! `(action, subject, EVENT_VERSION)` and its data is one of the structs
+//! below, encoded as a map keyed by field name. Indexers should match on
+//! all three topics and decode the data as the struct for that version.
+//!
+//! | Action     | Subject    | Data                       |
+//! |------------|------------|----------------------------|
+//! | `init`     | `contract` | [`Initialized`]            |
+//! | `mint`     | `BITE`     | [`Minted`]                 |
+//! | `minter`   | `BITE`     | [`MinterSet`]              |
+//! | `approve`  | `BITE`     | [`Approved`]               |
+//! | `transfer` | `BITE`     | [`Transferred`]            |
+//! | `burn`     | `BITE`     | [`Burned`]                 |
+//! | `ttl`      | `policy`   | [`TtlPolicySet`]           |
+//! | `propose`  | `admin`    | [`AdminTransferProposed`]  |
+//! | `accept`   | `admin`    | [`AdminTransferAccepted`]  |
+//! | `cancel`   | `admin`    | [`AdminTransferCancelled`] |
+//! | `upgrade`  | `contract` | [`ContractUpgraded`]       |
+//! | `migrate`  | `contract` | [`ContractMigrated`]       |
+
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Symbol};
+
+use crate::TtlPolicy;
+
+#[cfg(test)]
+pub(crate) use contract_events::testutils::assert_last_event;
+pub(crate) use contract_events::Event;
+pub use contract_events::EVENT_VERSION;
+
+/// The contract was initialised.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Initialized {
+    pub admin: Address,
+    pub minter: Address,
+}
+
+impl Event for Initialized {
+    const ACTION: Symbol = symbol_short!("init");
+    const SUBJECT: Symbol = symbol_short!("contract");
+}
+
+/// New BITE was minted.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Minted {
+    pub to: Address,
+    pub amount: i128,
+}
+
+impl Event for Minted {
+    const ACTION: Symbol = symbol_short!("mint");
+    const SUBJECT: Symbol = symbol_short!("BITE");
+}
+
+/// The secondary minter was replaced.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinterSet {
+    pub minter: Address,
+}
+
+impl Event for MinterSet {
+    const ACTION: Symbol = symbol_short!("minter");
+    const SUBJECT: Symbol = symbol_short!("BITE");
+}
+
+/// An allowance was set.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Approved {
+    pub from: Address,
+    pub spender: Address,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+impl Event for Approved {
+    const ACTION: Symbol = symbol_short!("approve");
+    const SUBJECT: Symbol = symbol_short!("BITE");
+}
+
+/// BITE moved between accounts, directly or through an allowance.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transferred {
+    pub from: Address,
+    pub to: Address,
+    pub amount: i128,
+}
+
+impl Event for Transferred {
+    const ACTION: Symbol = symbol_short!("transfer");
+    const SUBJECT: Symbol = symbol_short!("BITE");
+}
+
+/// BITE was burned, directly or through an allowance.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Burned {
+    pub from: Address,
+    pub amount: i128,
+}
+
+impl Event for Burned {
+    const ACTION: Symbol = symbol_short!("burn");
+    const SUBJECT: Symbol = symbol_short!("BITE");
+}
+
+/// The TTL policy was replaced.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TtlPolicySet {
+    pub policy: TtlPolicy,
+}
+
+impl Event for TtlPolicySet {
+    const ACTION: Symbol = symbol_short!("ttl");
+    const SUBJECT: Symbol = symbol_short!("policy");
+}
+
+/// The admin proposed a successor.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminTransferProposed {
+    pub admin: Address,
+    pub new_admin: Address,
+}
+
+impl Event for AdminTransferProposed {
+    const ACTION: Symbol = symbol_short!("propose");
+    const SUBJECT: Symbol = symbol_short!("admin");
+}
+
+/// A proposed admin accepted the role.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminTransferAccepted {
+    pub previous_admin: Address,
+    pub new_admin: Address,
+}
+
+impl Event for AdminTransferAccepted {
+    const ACTION: Symbol = symbol_short!("accept");
+    const SUBJECT: Symbol = symbol_short!("admin");
+}
+
+/// The admin withdrew a pending proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminTransferCancelled {
+    pub admin: Address,
+}
+
+impl Event for AdminTransferCancelled {
+    const ACTION: Symbol = symbol_short!("cancel");
+    const SUBJECT: Symbol = symbol_short!("admin");
+}
+
+/// The contract code was replaced.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractUpgraded {
+    pub new_wasm_hash: BytesN<32>,
+}
+
+impl Event for ContractUpgraded {
+    const ACTION: Symbol = symbol_short!("upgrade");
+    const SUBJECT: Symbol = symbol_short!("contract");
+}
+
+/// The stored schema version was raised.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractMigrated {
+    pub schema_version: u32,
+}
+
+impl Event for ContractMigrated {
+    const ACTION: Symbol = symbol_short!("migrate");
+    const SUBJECT: Symbol = symbol_short!("contract");
+}
@@ -22,10 +22,36 @@
 //! ## SEP-41 surface
 //! Implements the full `token::Interface` trait so the token appears correctly
 //! in Stellar wallets.
+//!
+//! ## Events
+//! Every state change publishes a typed, versioned event; see [`events`].
+//!
+//! ## Storage TTL
+//! Writes extend entries according to an admin-set [`TtlPolicy`]. Anyone can
+//! keep a dormant balance alive with `bump_balance`, and `balance_ttl`
+//! reports how long it has left.
+//!
+//! ## Upgrades
+//! The admin can replace the contract code with `upgrade`; balances stay in
+//! place. The stored [`SCHEMA_VERSION`] tells a later version whether
+//! balance records need `migrate`.
 
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, String};
+mod error;
+pub mod events;
+mod ttl;
+
+use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, String};
+
+pub use error::Error;
+use events::{
+    AdminTransferAccepted, AdminTransferCancelled, AdminTransferProposed, Approved, Burned,
+    ContractMigrated, ContractUpgraded, Event, Initialized, Minted, MinterSet, Transferred,
+    TtlPolicySet,
+};
+use ttl::{extend_balance, extend_instance};
+pub use ttl::{TtlPolicy, DAY_IN_LEDGERS};
 
 // ---------------------------------------------------------------------------
 // Storage keys
@@ -35,6 +61,8 @@ use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, E
 pub enum DataKey {
     /// The platform admin who controls minting.
     Admin,
+    /// Proposed next admin awaiting `accept_admin_transfer`.
+    PendingAdmin,
     /// Optional secondary minter (e.g. the Order contract address).
     Minter,
     /// Total tokens in circulation.
@@ -43,8 +71,17 @@ pub enum DataKey {
     Balance(Address),
     /// Allowances: (owner, spender) → (amount, expiration_ledger).
     Allowance(Address, Address),
+    /// Layout version of the stored records.
+    SchemaVersion,
+    /// TTL policy set by the admin.
+    TtlPolicy,
+    /// Ledger until which an account's balance is known to live.
+    LiveUntil(Address),
 }
 
+/// Layout version of the records written by this code.
+pub const SCHEMA_VERSION: u32 = 1;
+
 // ---------------------------------------------------------------------------
 // Token metadata (stored once at init)
 // ---------------------------------------------------------------------------
@@ -91,9 +128,9 @@ impl LoyaltyToken {
     /// # Arguments
     /// - `admin`   – address with mint authority.
     /// - `minter`  – optional secondary minter (pass `admin` to disable).
-    pub fn initialize(env: Env, admin: Address, minter: Address) {
+    pub fn initialize(env: Env, admin: Address, minter: Address) -> Result<(), Error> {
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            return Err(Error::AlreadyInitialized);
         }
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Minter, &minter);
@@ -106,7 +143,13 @@ impl LoyaltyToken {
                 decimals: 7,
             },
         );
-        env.storage().instance().extend_ttl(17_280, 17_280);
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        extend_instance(&env);
+
+        Initialized { admin, minter }.publish(&env);
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
@@ -114,12 +157,12 @@ impl LoyaltyToken {
     // -----------------------------------------------------------------------
 
     /// Mint `amount` BITE to `to`.  Only callable by admin or minter.
-    pub fn mint(env: Env, caller: Address, to: Address, amount: i128) {
+    pub fn mint(env: Env, caller: Address, to: Address, amount: i128) -> Result<(), Error> {
         caller.require_auth();
-        Self::assert_admin_or_minter(&env, &caller);
+        Self::assert_admin_or_minter(&env, &caller)?;
 
         if amount <= 0 {
-            panic!("amount must be positive");
+            return Err(Error::InvalidAmount);
         }
 
         let new_balance = Self::balance_of(&env, &to) + amount;
@@ -133,26 +176,204 @@ impl LoyaltyToken {
         env.storage()
             .instance()
             .set(&DataKey::TotalSupply, &(supply + amount));
-        env.storage().instance().extend_ttl(17_280, 17_280);
+        extend_instance(&env);
 
-        env.events()
-            .publish((symbol_short!("mint"), symbol_short!("BITE")), (to, amount));
+        Minted { to, amount }.publish(&env);
+        Ok(())
     }
 
     /// Update the authorised minter address (admin only).
-    pub fn set_minter(env: Env, caller: Address, new_minter: Address) {
+    pub fn set_minter(env: Env, caller: Address, new_minter: Address) -> Result<(), Error> {
         caller.require_auth();
-        Self::assert_admin_or_panic(&env, &caller);
+        Self::assert_admin(&env, &caller)?;
         env.storage().instance().set(&DataKey::Minter, &new_minter);
-        env.storage().instance().extend_ttl(17_280, 17_280);
+        extend_instance(&env);
+
+        MinterSet { minter: new_minter }.publish(&env);
+        Ok(())
     }
 
-    /// Transfer the admin role.
-    pub fn transfer_admin(env: Env, caller: Address, new_admin: Address) {
+    /// Propose `new_admin` as the next contract admin (admin only).
+    ///
+    /// The role only moves once `new_admin` calls `accept_admin_transfer`,
+    /// so a mistyped address cannot lock the contract. Replaces any pending
+    /// proposal.
+    pub fn propose_admin_transfer(
+        env: Env,
+        caller: Address,
+        new_admin: Address,
+    ) -> Result<(), Error> {
         caller.require_auth();
-        Self::assert_admin_or_panic(&env, &caller);
+        Self::assert_admin(&env, &caller)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &new_admin);
+        extend_instance(&env);
+
+        AdminTransferProposed {
+            admin: caller,
+            new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Accept a pending admin proposal and become the contract admin.
+    pub fn accept_admin_transfer(env: Env, new_admin: Address) -> Result<(), Error> {
+        new_admin.require_auth();
+
+        let pending: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(Error::NoPendingAdmin)?;
+        if pending != new_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        let previous: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
         env.storage().instance().set(&DataKey::Admin, &new_admin);
-        env.storage().instance().extend_ttl(17_280, 17_280);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        extend_instance(&env);
+
+        AdminTransferAccepted {
+            previous_admin: previous,
+            new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Withdraw a pending admin proposal (admin only).
+    pub fn cancel_admin_transfer(env: Env, caller: Address) -> Result<(), Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
+        if !env.storage().instance().has(&DataKey::PendingAdmin) {
+            return Err(Error::NoPendingAdmin);
+        }
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        AdminTransferCancelled { admin: caller }.publish(&env);
+        Ok(())
+    }
+
+    /// Replace the TTL policy (admin only).
+    ///
+    /// # Errors
+    /// [`Error::InvalidTtlPolicy`] if a threshold exceeds its extension, an
+    /// extension is zero, or an extension exceeds the network's maximum TTL.
+    pub fn set_ttl_policy(env: Env, caller: Address, policy: TtlPolicy) -> Result<(), Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
+        if !policy.is_valid(env.storage().max_ttl()) {
+            return Err(Error::InvalidTtlPolicy);
+        }
+        env.storage().instance().set(&DataKey::TtlPolicy, &policy);
+        extend_instance(&env);
+
+        TtlPolicySet { policy }.publish(&env);
+        Ok(())
+    }
+
+    /// Replace the contract code with the uploaded Wasm `new_wasm_hash`
+    /// (admin only).
+    ///
+    /// Storage is left untouched; call `migrate` afterwards if the new code
+    /// raises [`SCHEMA_VERSION`].
+    pub fn upgrade(env: Env, caller: Address, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        ContractUpgraded { new_wasm_hash }.publish(&env);
+        Ok(())
+    }
+
+    /// Record [`SCHEMA_VERSION`] as the stored version (admin only).
+    ///
+    /// Balance and allowance records still have their version-1 layout, so
+    /// there is nothing to rewrite yet; a version that changes them converts
+    /// the affected accounts here.
+    pub fn migrate(env: Env, caller: Address) -> Result<(), Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        extend_instance(&env);
+
+        ContractMigrated {
+            schema_version: SCHEMA_VERSION,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Return the admin address.
+    pub fn admin(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)
+    }
+
+    /// Return the proposed next admin, if a handover is in progress.
+    pub fn pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Return the layout version of the stored data.
+    ///
+    /// Deployments that predate versioning report `1`.
+    pub fn schema_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(1)
+    }
+
+    // -----------------------------------------------------------------------
+    // Storage TTL
+    // -----------------------------------------------------------------------
+
+    /// Extend `account`'s balance entry and the contract instance. Anyone may
+    /// call this, e.g. to keep a dormant holder's balance from being
+    /// archived.
+    ///
+    /// # Returns
+    /// The balance entry's remaining TTL, in ledgers; `0` if `account` has
+    /// never held BITE.
+    pub fn bump_balance(env: Env, account: Address) -> u32 {
+        extend_instance(&env);
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Balance(account.clone()))
+        {
+            return 0;
+        }
+        extend_balance(&env, &account)
+    }
+
+    /// Return the remaining TTL of `account`'s balance entry, in ledgers, as
+    /// last recorded by this contract.
+    ///
+    /// Entries not extended since TTL tracking was introduced report `0`
+    /// until they are next written or bumped.
+    pub fn balance_ttl(env: Env, account: Address) -> u32 {
+        ttl::remaining_ttl(&env, &account)
+    }
+
+    /// Return the TTL policy in force.
+    pub fn ttl_policy(env: Env) -> TtlPolicy {
+        ttl::policy(&env)
     }
 
     // -----------------------------------------------------------------------
@@ -165,9 +386,9 @@ impl LoyaltyToken {
     }
 
     /// Transfer `amount` BITE from `from` to `to`.
-    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) -> Result<(), Error> {
         from.require_auth();
-        Self::do_transfer(&env, &from, &to, amount);
+        Self::do_transfer(&env, &from, &to, amount)
     }
 
     /// Return the current allowance for `spender` to spend on behalf of `from`.
@@ -185,13 +406,13 @@ impl LoyaltyToken {
         spender: Address,
         amount: i128,
         expiration_ledger: u32,
-    ) {
+    ) -> Result<(), Error> {
         from.require_auth();
         if amount < 0 {
-            panic!("allowance amount cannot be negative");
+            return Err(Error::NegativeAllowance);
         }
         if amount > 0 && expiration_ledger < env.ledger().sequence() {
-            panic!("expiration_ledger is in the past");
+            return Err(Error::ExpirationInPast);
         }
         let data = AllowanceData {
             amount,
@@ -208,19 +429,29 @@ impl LoyaltyToken {
                 ttl,
             );
         }
-        env.events().publish(
-            (symbol_short!("approve"), symbol_short!("BITE")),
-            (from, spender, amount, expiration_ledger),
-        );
+        Approved {
+            from,
+            spender,
+            amount,
+            expiration_ledger,
+        }
+        .publish(&env);
+        Ok(())
     }
 
     /// Transfer `amount` on behalf of `from` using a prior allowance.
-    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+    pub fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
         spender.require_auth();
 
         let current = Self::get_allowance(&env, &from, &spender);
         if current < amount {
-            panic!("insufficient allowance");
+            return Err(Error::InsufficientAllowance);
         }
 
         // Decrement allowance.
@@ -236,22 +467,22 @@ impl LoyaltyToken {
         data.amount -= amount;
         env.storage().temporary().set(&allowance_key, &data);
 
-        Self::do_transfer(&env, &from, &to, amount);
+        Self::do_transfer(&env, &from, &to, amount)
     }
 
     /// Burn `amount` BITE from `from`'s account.
-    pub fn burn(env: Env, from: Address, amount: i128) {
+    pub fn burn(env: Env, from: Address, amount: i128) -> Result<(), Error> {
         from.require_auth();
-        Self::do_burn(&env, &from, amount);
+        Self::do_burn(&env, &from, amount)
     }
 
     /// Burn `amount` BITE from `from` using a spender's allowance.
-    pub fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+    pub fn burn_from(env: Env, spender: Address, from: Address, amount: i128) -> Result<(), Error> {
         spender.require_auth();
 
         let current = Self::get_allowance(&env, &from, &spender);
         if current < amount {
-            panic!("insufficient allowance");
+            return Err(Error::InsufficientAllowance);
         }
 
         let allowance_key = DataKey::Allowance(from.clone(), spender.clone());
@@ -266,26 +497,23 @@ impl LoyaltyToken {
         data.amount -= amount;
         env.storage().temporary().set(&allowance_key, &data);
 
-        Self::do_burn(&env, &from, amount);
+        Self::do_burn(&env, &from, amount)
     }
 
     // -----------------------------------------------------------------------
     // Token metadata (SEP-41)
     // -----------------------------------------------------------------------
 
-    pub fn name(env: Env) -> String {
-        let meta: TokenMeta = env.storage().instance().get(&MetaKey::Meta).unwrap();
-        meta.name
+    pub fn name(env: Env) -> Result<String, Error> {
+        Ok(Self::meta(&env)?.name)
     }
 
-    pub fn symbol(env: Env) -> String {
-        let meta: TokenMeta = env.storage().instance().get(&MetaKey::Meta).unwrap();
-        meta.symbol
+    pub fn symbol(env: Env) -> Result<String, Error> {
+        Ok(Self::meta(&env)?.symbol)
     }
 
-    pub fn decimals(env: Env) -> u32 {
-        let meta: TokenMeta = env.storage().instance().get(&MetaKey::Meta).unwrap();
-        meta.decimals
+    pub fn decimals(env: Env) -> Result<u32, Error> {
+        Ok(Self::meta(&env)?.decimals)
     }
 
     pub fn total_supply(env: Env) -> i128 {
@@ -299,6 +527,13 @@ impl LoyaltyToken {
     // Private helpers
     // -----------------------------------------------------------------------
 
+    fn meta(env: &Env) -> Result<TokenMeta, Error> {
+        env.storage()
+            .instance()
+            .get(&MetaKey::Meta)
+            .ok_or(Error::NotInitialized)
+    }
+
     fn balance_of(env: &Env, account: &Address) -> i128 {
         env.storage()
             .persistent()
@@ -307,39 +542,39 @@ impl LoyaltyToken {
     }
 
     fn set_balance(env: &Env, account: &Address, amount: i128) {
-        let ttl: u32 = 2_073_600;
         env.storage()
             .persistent()
             .set(&DataKey::Balance(account.clone()), &amount);
-        env.storage()
-            .persistent()
-            .extend_ttl(&DataKey::Balance(account.clone()), ttl, ttl);
+        extend_balance(env, account);
     }
 
-    fn do_transfer(env: &Env, from: &Address, to: &Address, amount: i128) {
+    fn do_transfer(env: &Env, from: &Address, to: &Address, amount: i128) -> Result<(), Error> {
         if amount <= 0 {
-            panic!("transfer amount must be positive");
+            return Err(Error::InvalidAmount);
         }
         let from_bal = Self::balance_of(env, from);
         if from_bal < amount {
-            panic!("insufficient balance");
+            return Err(Error::InsufficientBalance);
         }
         Self::set_balance(env, from, from_bal - amount);
         Self::set_balance(env, to, Self::balance_of(env, to) + amount);
 
-        env.events().publish(
-            (symbol_short!("transfer"), symbol_short!("BITE")),
-            (from.clone(), to.clone(), amount),
-        );
+        Transferred {
+            from: from.clone(),
+            to: to.clone(),
+            amount,
+        }
+        .publish(env);
+        Ok(())
     }
 
-    fn do_burn(env: &Env, from: &Address, amount: i128) {
+    fn do_burn(env: &Env, from: &Address, amount: i128) -> Result<(), Error> {
         if amount <= 0 {
-            panic!("burn amount must be positive");
+            return Err(Error::InvalidAmount);
         }
         let bal = Self::balance_of(env, from);
         if bal < amount {
-            panic!("insufficient balance");
+            return Err(Error::InsufficientBalance);
         }
         Self::set_balance(env, from, bal - amount);
 
@@ -351,12 +586,14 @@ impl LoyaltyToken {
         env.storage()
             .instance()
             .set(&DataKey::TotalSupply, &(supply - amount));
-        env.storage().instance().extend_ttl(17_280, 17_280);
+        extend_instance(env);
 
-        env.events().publish(
-            (symbol_short!("burn"), symbol_short!("BITE")),
-            (from.clone(), amount),
-        );
+        Burned {
+            from: from.clone(),
+            amount,
+        }
+        .publish(env);
+        Ok(())
     }
 
     fn get_allowance(env: &Env, from: &Address, spender: &Address) -> i128 {
@@ -377,19 +614,23 @@ impl LoyaltyToken {
         }
     }
 
-    fn assert_admin_or_panic(env: &Env, caller: &Address) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if caller != &admin {
-            panic!("unauthorized: admin only");
+    fn assert_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+        if caller != &Self::admin(env.clone())? {
+            return Err(Error::NotAdmin);
         }
+        Ok(())
     }
 
-    fn assert_admin_or_minter(env: &Env, caller: &Address) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        let minter: Address = env.storage().instance().get(&DataKey::Minter).unwrap();
-        if caller != &admin && caller != &minter {
-            panic!("unauthorized: admin or minter only");
+    fn assert_admin_or_minter(env: &Env, caller: &Address) -> Result<(), Error> {
+        let minter: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Minter)
+            .ok_or(Error::NotInitialized)?;
+        if caller != &Self::admin(env.clone())? && caller != &minter {
+            return Err(Error::NotMinter);
         }
+        Ok(())
     }
 }
 
@@ -400,7 +641,8 @@ impl LoyaltyToken {
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::testutils::Address as _;
+    use crate::events::assert_last_event;
+    use soroban_sdk::testutils::{Address as _, Ledger};
     use soroban_sdk::Env;
 
     fn setup() -> (Env, LoyaltyTokenClient<'static>, Address) {
@@ -427,6 +669,14 @@ mod test {
         let user = Address::generate(&env);
 
         client.mint(&admin, &user, &1_000_000);
+        assert_last_event(
+            &env,
+            &client.address,
+            Minted {
+                to: user.clone(),
+                amount: 1_000_000,
+            },
+        );
         assert_eq!(client.balance(&user), 1_000_000);
         assert_eq!(client.total_supply(), 1_000_000);
     }
@@ -439,6 +689,15 @@ mod test {
 
         client.mint(&admin, &alice, &500_000);
         client.transfer(&alice, &bob, &200_000);
+        assert_last_event(
+            &env,
+            &client.address,
+            Transferred {
+                from: alice.clone(),
+                to: bob.clone(),
+                amount: 200_000,
+            },
+        );
 
         assert_eq!(client.balance(&alice), 300_000);
         assert_eq!(client.balance(&bob), 200_000);
@@ -455,6 +714,16 @@ mod test {
         // Alice approves bob to spend 300_000 for 1000 ledgers.
         let expiry = env.ledger().sequence() + 1_000;
         client.approve(&alice, &bob, &300_000, &expiry);
+        assert_last_event(
+            &env,
+            &client.address,
+            Approved {
+                from: alice.clone(),
+                spender: bob.clone(),
+                amount: 300_000,
+                expiration_ledger: expiry,
+            },
+        );
         assert_eq!(client.allowance(&alice, &bob), 300_000);
 
         client.transfer_from(&bob, &alice, &bob, &100_000);
@@ -469,27 +738,90 @@ mod test {
 
         client.mint(&admin, &user, &500_000);
         client.burn(&user, &200_000);
+        assert_last_event(
+            &env,
+            &client.address,
+            Burned {
+                from: user.clone(),
+                amount: 200_000,
+            },
+        );
 
         assert_eq!(client.balance(&user), 300_000);
         assert_eq!(client.total_supply(), 300_000);
     }
 
     #[test]
-    #[should_panic(expected = "insufficient balance")]
-    fn test_transfer_overdraft_panics() {
+    fn test_transfer_overdraft_rejected() {
         let (env, client, admin) = setup();
         let alice = Address::generate(&env);
         let bob = Address::generate(&env);
 
         client.mint(&admin, &alice, &100_000);
-        client.transfer(&alice, &bob, &200_000);
+        assert_eq!(
+            client.try_transfer(&alice, &bob, &200_000),
+            Err(Ok(Error::InsufficientBalance))
+        );
     }
 
     #[test]
-    #[should_panic(expected = "unauthorized: admin or minter only")]
-    fn test_unauthorised_mint_panics() {
+    fn test_unauthorised_mint_rejected() {
         let (env, client, _admin) = setup();
         let rando = Address::generate(&env);
-        client.mint(&rando, &rando, &1_000_000);
+        assert_eq!(
+            client.try_mint(&rando, &rando, &1_000_000),
+            Err(Ok(Error::NotMinter))
+        );
+    }
+
+    #[test]
+    fn test_admin_transfer() {
+        let (env, client, admin) = setup();
+        let new_admin = Address::generate(&env);
+
+        client.propose_admin_transfer(&admin, &new_admin);
+        assert_eq!(client.pending_admin(), Some(new_admin.clone()));
+        client.accept_admin_transfer(&new_admin);
+        assert_eq!(client.admin(), new_admin);
+        assert_eq!(client.pending_admin(), None);
+    }
+
+    #[test]
+    fn test_non_admin_cannot_propose_admin() {
+        let (env, client, _admin) = setup();
+        let rando = Address::generate(&env);
+        assert_eq!(
+            client.try_propose_admin_transfer(&rando, &rando),
+            Err(Ok(Error::NotAdmin))
+        );
+    }
+
+    #[test]
+    fn test_only_admin_upgrades_and_migrates() {
+        let (env, client, admin) = setup();
+        let rando = Address::generate(&env);
+
+        assert_eq!(
+            client.try_upgrade(&rando, &BytesN::from_array(&env, &[0; 32])),
+            Err(Ok(Error::NotAdmin))
+        );
+        assert_eq!(client.try_migrate(&rando), Err(Ok(Error::NotAdmin)));
+        client.migrate(&admin);
+        assert_eq!(client.schema_version(), SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_bump_balance_reports_ttl() {
+        let (env, client, admin) = setup();
+        let user = Address::generate(&env);
+        assert_eq!(client.bump_balance(&user), 0);
+
+        client.mint(&admin, &user, &1_000);
+        let full = TtlPolicy::DEFAULT.persistent_extend_to;
+        assert_eq!(client.balance_ttl(&user), full);
+        env.ledger().with_mut(|l| l.sequence_number += 1_000);
+        assert_eq!(client.balance_ttl(&user), full - 1_000);
+        assert_eq!(client.bump_balance(&user), full);
+        assert_eq!(client.balance_ttl(&user), full);
     }
 }
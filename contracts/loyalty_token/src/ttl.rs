@@ -0,0 +1,39 @@
+//! # Storage TTL
+//!
+//! Every write extends the entries it touches according to the contract's
+//! [`TtlPolicy`], which the admin may tune. The policy and the extension
+//! logic live in the shared `storage-ttl` crate; this module binds them to
+//! the balance entries.
+
+use soroban_sdk::{Address, Env};
+pub use storage_ttl::{TtlPolicy, DAY_IN_LEDGERS};
+
+use crate::DataKey;
+
+pub(crate) fn policy(env: &Env) -> TtlPolicy {
+    storage_ttl::policy(env, &DataKey::TtlPolicy)
+}
+
+/// Extend the contract instance according to the policy.
+pub(crate) fn extend_instance(env: &Env) {
+    storage_ttl::extend_instance(env, &policy(env));
+}
+
+/// Extend a balance entry and record until when it lives.
+///
+/// Returns the entry's remaining TTL.
+pub(crate) fn extend_balance(env: &Env, account: &Address) -> u32 {
+    let live_until_key = DataKey::LiveUntil(account.clone());
+    storage_ttl::extend_tracked(env, &policy(env), &live_until_key, |extend_to| {
+        env.storage().persistent().extend_ttl(
+            &DataKey::Balance(account.clone()),
+            extend_to,
+            extend_to,
+        );
+    })
+}
+
+/// Remaining TTL recorded for a balance entry; `0` if none was recorded.
+pub(crate) fn remaining_ttl(env: &Env, account: &Address) -> u32 {
+    storage_ttl::remaining_ttl(env, &DataKey::LiveUntil(account.clone()))
+}
@@ -0,0 +1,92 @@
+use soroban_sdk::contracterror;
+
+/// Error codes returned by the order contract.
+///
+/// Codes are part of the contract's public interface: never renumber an
+/// existing variant, only append new ones.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    // Access control and lifecycle.
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    /// Caller may not act on this order.
+    Unauthorized = 3,
+    /// Only the contract admin may perform this action.
+    NotAdmin = 4,
+    NoPendingAdmin = 5,
+
+    // Placing orders.
+    OrderNotFound = 6,
+    /// Order has no line items.
+    EmptyOrder = 7,
+    /// A line item has a quantity of zero.
+    InvalidQuantity = 8,
+    MenuItemNotFound = 9,
+    MenuItemUnavailable = 10,
+    /// A line item's unit price differs from the catalog price.
+    PriceMismatch = 11,
+    /// Line items are priced in different tokens.
+    MixedTokens = 12,
+    RestaurantNotFound = 13,
+    /// Restaurant is not open at the requested time, per its opening hours.
+    RestaurantClosed = 14,
+    ScheduledTimeInPast = 15,
+    /// Restaurant is below the configured minimum verification level.
+    InsufficientVerification = 16,
+
+    // Status changes.
+    AlreadyDelivered = 17,
+    AlreadyCancelled = 18,
+    /// Customers may only cancel orders that are still `Pending`.
+    NotPending = 19,
+
+    // Storage TTL.
+    /// A TTL threshold exceeds its extension, or an extension is zero or
+    /// above the network maximum.
+    InvalidTtlPolicy = 20,
+
+    // Tables and channels.
+    TableNotFound = 21,
+    /// Table is switched off in the registry.
+    TableInactive = 22,
+    /// Dine-in orders must name a table.
+    TableRequired = 23,
+    /// Only dine-in orders may name a table.
+    TableNotAllowed = 24,
+
+    // Restaurant status.
+    /// Restaurant has been deactivated by its owner or the registry admin.
+    RestaurantInactive = 25,
+    /// Restaurant is suspended by the registry admin.
+    RestaurantSuspended = 26,
+    /// Restaurant has been closed for good in the registry.
+    RestaurantPermanentlyClosed = 27,
+
+    // Order limits.
+    /// An order total does not fit in an `i128`.
+    Overflow = 28,
+    /// Order holds more line items than `OrderLimits::max_items`.
+    TooManyItems = 29,
+    /// A line item's quantity exceeds `OrderLimits::max_quantity`.
+    QuantityTooLarge = 30,
+    /// Order total exceeds `OrderLimits::max_total`.
+    TotalTooLarge = 31,
+    /// An order limit is zero or negative.
+    InvalidOrderLimits = 32,
+
+    // Modifying orders.
+    /// `modify_order` was called without any changes.
+    NoChanges = 33,
+    /// The Payment contract already holds a payment for the order.
+    AlreadyEscrowed = 34,
+
+    // Status overrides.
+    /// `set_status` was asked for the status the order already has.
+    StatusUnchanged = 35,
+
+    // Payment tokens.
+    /// The restaurant's profile no longer accepts the menu items' token.
+    TokenNotAccepted = 36,
+}
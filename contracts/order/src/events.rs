@@ -0,0 +1,232 @@
+//! # Events
+//!
+//! Every state change publishes exactly one event. Its topics are
+//! `(action, subject, EVENT_VERSION)` and its data is one of the structs
+//! below, encoded as a map keyed by field name. Indexers should match on
+//! all three topics and decode the data as the struct for that version.
+//!
+//! | Action      | Subject    | Data                       |
+//! |-------------|------------|----------------------------|
+//! | `init`      | `contract` | [`Initialized`]            |
+//! | `placed`    | `order`    | [`OrderPlaced`]            |
+//! | `modified`  | `order`    | [`OrderModified`]          |
+//! | `cancelled` | `order`    | [`OrderCancelled`]         |
+//! | `advanced`  | `order`    | [`OrderAdvanced`]          |
+//! | `setstatus` | `order`    | [`OrderStatusSet`]         |
+//! | `minverif`  | `order`    | [`MinVerificationSet`]     |
+//! | `payment`   | `contract` | [`PaymentContractSet`]     |
+//! | `ttl`       | `policy`   | [`TtlPolicySet`]           |
+//! | `limits`    | `order`    | [`OrderLimitsSet`]         |
+//! | `propose`   | `admin`    | [`AdminTransferProposed`]  |
+//! | `accept`    | `admin`    | [`AdminTransferAccepted`]  |
+//! | `cancel`    | `admin`    | [`AdminTransferCancelled`] |
+//! | `upgrade`   | `contract` | [`ContractUpgraded`]       |
+//! | `migrate`   | `contract` | [`ContractMigrated`]       |
+
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Symbol};
+
+use crate::registry::VerificationLevel;
+use crate::{Fulfilment, OrderLimits, OrderStatus, TtlPolicy};
+
+#[cfg(test)]
+pub(crate) use contract_events::testutils::assert_last_event;
+pub(crate) use contract_events::Event;
+pub use contract_events::EVENT_VERSION;
+
+/// The contract was initialised.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Initialized {
+    pub admin: Address,
+    pub registry: Address,
+}
+
+impl Event for Initialized {
+    const ACTION: Symbol = symbol_short!("init");
+    const SUBJECT: Symbol = symbol_short!("contract");
+}
+
+/// A customer placed an order.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderPlaced {
+    pub order_id: u64,
+    pub restaurant_id: u64,
+    pub customer: Address,
+    pub token: Address,
+    pub total_amount: i128,
+    pub scheduled_for: Option<u64>,
+    pub fulfilment: Fulfilment,
+}
+
+impl Event for OrderPlaced {
+    const ACTION: Symbol = symbol_short!("placed");
+    const SUBJECT: Symbol = symbol_short!("order");
+}
+
+/// A customer changed the items of a pending order.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderModified {
+    pub order_id: u64,
+    pub revision: u32,
+    pub total_amount: i128,
+}
+
+impl Event for OrderModified {
+    const ACTION: Symbol = symbol_short!("modified");
+    const SUBJECT: Symbol = symbol_short!("order");
+}
+
+/// An order was cancelled by its customer or the admin.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderCancelled {
+    pub order_id: u64,
+    pub cancelled_by: Address,
+}
+
+impl Event for OrderCancelled {
+    const ACTION: Symbol = symbol_short!("cancelled");
+    const SUBJECT: Symbol = symbol_short!("order");
+}
+
+/// An order moved one step along its lifecycle.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderAdvanced {
+    pub order_id: u64,
+    pub status: OrderStatus,
+}
+
+impl Event for OrderAdvanced {
+    const ACTION: Symbol = symbol_short!("advanced");
+    const SUBJECT: Symbol = symbol_short!("order");
+}
+
+/// The admin set an order's status directly.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderStatusSet {
+    pub order_id: u64,
+    pub status: OrderStatus,
+}
+
+impl Event for OrderStatusSet {
+    const ACTION: Symbol = symbol_short!("setstatus");
+    const SUBJECT: Symbol = symbol_short!("order");
+}
+
+/// The minimum verification level for taking orders changed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinVerificationSet {
+    pub level: VerificationLevel,
+}
+
+impl Event for MinVerificationSet {
+    const ACTION: Symbol = symbol_short!("minverif");
+    const SUBJECT: Symbol = symbol_short!("order");
+}
+
+/// The Payment contract that modifications are checked against was set.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaymentContractSet {
+    pub payment_contract: Address,
+}
+
+impl Event for PaymentContractSet {
+    const ACTION: Symbol = symbol_short!("payment");
+    const SUBJECT: Symbol = symbol_short!("contract");
+}
+
+/// The TTL policy was replaced.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TtlPolicySet {
+    pub policy: TtlPolicy,
+}
+
+impl Event for TtlPolicySet {
+    const ACTION: Symbol = symbol_short!("ttl");
+    const SUBJECT: Symbol = symbol_short!("policy");
+}
+
+/// The order limits were replaced.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderLimitsSet {
+    pub limits: OrderLimits,
+}
+
+impl Event for OrderLimitsSet {
+    const ACTION: Symbol = symbol_short!("limits");
+    const SUBJECT: Symbol = symbol_short!("order");
+}
+
+/// The admin proposed a successor.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminTransferProposed {
+    pub admin: Address,
+    pub new_admin: Address,
+}
+
+impl Event for AdminTransferProposed {
+    const ACTION: Symbol = symbol_short!("propose");
+    const SUBJECT: Symbol = symbol_short!("admin");
+}
+
+/// A proposed admin accepted the role.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminTransferAccepted {
+    pub previous_admin: Address,
+    pub new_admin: Address,
+}
+
+impl Event for AdminTransferAccepted {
+    const ACTION: Symbol = symbol_short!("accept");
+    const SUBJECT: Symbol = symbol_short!("admin");
+}
+
+/// The admin withdrew a pending proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminTransferCancelled {
+    pub admin: Address,
+}
+
+impl Event for AdminTransferCancelled {
+    const ACTION: Symbol = symbol_short!("cancel");
+    const SUBJECT: Symbol = symbol_short!("admin");
+}
+
+/// The contract code was replaced.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractUpgraded {
+    pub new_wasm_hash: BytesN<32>,
+}
+
+impl Event for ContractUpgraded {
+    const ACTION: Symbol = symbol_short!("upgrade");
+    const SUBJECT: Symbol = symbol_short!("contract");
+}
+
+/// A batch of legacy orders was migrated.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractMigrated {
+    /// Stored layout version after this batch; it only changes with the
+    /// final batch.
+    pub schema_version: u32,
+    /// Number of orders rewritten by this batch.
+    pub migrated: u32,
+}
+
+impl Event for ContractMigrated {
+    const ACTION: Symbol = symbol_short!("migrate");
+    const SUBJECT: Symbol = symbol_short!("contract");
+}
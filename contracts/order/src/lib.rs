@@ -1,8 +1,9 @@
 //! # Order Contract
 //!
 //! Manages food orders placed by customers on the restaurant platform.
-//! Orders progress through a well-defined lifecycle and emit events at each
-//! transition so that off-chain indexers can stay in sync.
+//! Orders progress through a well-defined lifecycle and emit typed
+//! [`events`] at each transition so that off-chain indexers can stay in
+//! sync.
 //!
 //! ## Order lifecycle
 //! ```text
@@ -13,15 +14,96 @@
 //!
 //! ## Roles
 //! - **Admin** – contract deployer; full control.
-//! - **Restaurant owner** – confirms, updates, and marks orders as ready/delivered
-//!   for orders belonging to their restaurant.
-//! - **Customer** – places an order; can cancel while it is still `Pending`.
+//! - **Restaurant owner** – confirms, prepares, marks as ready/delivered and
+//!   cancels orders belonging to their restaurant.
+//! - **Restaurant staff** – accounts granted a role in the registry advance
+//!   and cancel that restaurant's orders as their role permits
+//!   (`ConfirmOrder`, `PrepareOrder`, `ReadyOrder`, `DeliverOrder`,
+//!   `CancelOrder`).
+//! - **Customer** – places an order; can modify or cancel it while it is still
+//!   `Pending`.
+//!
+//! ## Pricing
+//! Line items reference the menu catalog held by the Restaurant Registry.
+//! `place_order` checks every item against that catalog, so the stored
+//! prices are always the restaurant's own.
+//!
+//! ## Status history
+//! Every status change made by `advance_status`, `set_status` or
+//! `cancel_order` appends a [`StatusChange`] recording who moved the order
+//! from which status to which, when, and (for cancellations and admin
+//! overrides) an optional reason code. `get_order_history` pages through
+//! them oldest first.
+//!
+//! ## Modifying orders
+//! While an order is `Pending` its customer may add, remove and re-quantify
+//! line items with `modify_order`. Every modification recomputes the total
+//! and bumps the order's `revision`, so the restaurant and payment sides can
+//! tell which version of the order they saw. Once the Payment contract set
+//! with `set_payment_contract` holds a payment from the order's customer to
+//! its restaurant, its amount is fixed and `modify_order` is refused. The
+//! payment records the revision it covers.
+//!
+//! ## Restaurant status
+//! Orders are only accepted for restaurants the registry reports as active.
+//! Unknown, deactivated, suspended and permanently closed restaurants each
+//! fail with their own [`Error`].
+//!
+//! ## Opening hours
+//! Orders are only accepted while the registry reports the restaurant as
+//! open. A scheduled order is checked against its scheduled time instead of
+//! the current ledger time.
+//!
+//! ## Channels and tables
+//! Every order records its [`Fulfilment`]: the [`FulfilmentChannel`] and,
+//! for dine-in orders, a table registered in the registry, which must be
+//! active. Kitchens list a table's open orders with `get_table_orders`.
+//!
+//! ## Verification
+//! The admin may require a minimum registry [`VerificationLevel`]; orders
+//! for restaurants below it are refused.
+//!
+//! ## Limits
+//! The admin bounds the number of line items, the quantity per line and the
+//! total of a single order with [`OrderLimits`]. Totals are computed with
+//! checked arithmetic and fail with [`Error::Overflow`] rather than wrap.
+//!
+//! ## Storage TTL
+//! Writes extend entries according to an admin-set [`TtlPolicy`]. Anyone can
+//! keep an order alive, together with its status history, with `bump_order`,
+//! and `order_ttl` reports how long its record has left.
+//!
+//! ## Upgrades
+//! The admin can replace the contract code with `upgrade`. Stored orders
+//! carry a [`SCHEMA_VERSION`]; after an upgrade that changes their layout,
+//! `migrate` rewrites legacy orders in batches, resuming from a stored
+//! cursor; the stored version only changes once the final batch is done.
 
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, vec, Address, Env, String, Vec,
+    contract, contractimpl, contracttype, vec, Address, BytesN, Env, Map, String, Symbol,
+    TryFromVal, Val, Vec,
+};
+
+mod error;
+pub mod events;
+mod limits;
+pub mod payment;
+pub mod registry;
+mod ttl;
+
+pub use error::Error;
+use events::{
+    AdminTransferAccepted, AdminTransferCancelled, AdminTransferProposed, ContractMigrated,
+    ContractUpgraded, Event, Initialized, MinVerificationSet, OrderAdvanced, OrderCancelled,
+    OrderLimitsSet, OrderModified, OrderPlaced, OrderStatusSet, PaymentContractSet, TtlPolicySet,
 };
+pub use limits::OrderLimits;
+use payment::{PaymentClient, PaymentStatus};
+use registry::{Permission, RegistryClient, RestaurantStatus, VerificationLevel};
+use ttl::{extend_instance, extend_order, extend_persistent};
+pub use ttl::{TtlPolicy, DAY_IN_LEDGERS};
 
 // ---------------------------------------------------------------------------
 // Types
@@ -39,20 +121,71 @@ pub enum OrderStatus {
     Cancelled,
 }
 
+/// How an order reaches the customer.
+#[contracttype]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FulfilmentChannel {
+    /// Served at a table in the restaurant.
+    DineIn,
+    /// Collected by the customer.
+    Pickup,
+    /// Brought to the customer.
+    Delivery,
+}
+
+/// How and where an order is fulfilled.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fulfilment {
+    pub channel: FulfilmentChannel,
+    /// Registry table the order is served at; set for dine-in orders only.
+    pub table_id: Option<u64>,
+}
+
 /// A single line-item in an order.
 #[contracttype]
 #[derive(Clone)]
 pub struct OrderItem {
-    /// Backend menu-item primary key for cross-system correlation.
+    /// Menu item ID in the Restaurant Registry catalog.
     pub menu_item_id: u64,
-    /// Snapshot of the item name at time of ordering.
+    /// Snapshot of the item name at time of ordering (taken from the catalog).
     pub name: String,
     /// Number of portions ordered.
     pub quantity: u32,
-    /// Price per unit in stroops (1 XLM = 10 000 000 stroops).
+    /// Price per unit in stroops (1 XLM = 10 000 000 stroops). Must match the
+    /// catalog price.
     pub unit_price: i128,
 }
 
+/// One entry in an order's status history.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatusChange {
+    pub from: OrderStatus,
+    pub to: OrderStatus,
+    /// Address that made the change.
+    pub actor: Address,
+    /// Ledger timestamp of the change.
+    pub at: u64,
+    /// Short reason code given by the actor, e.g. `out_of_stock`.
+    pub reason: Option<Symbol>,
+}
+
+/// Most entries returned by one page of `get_order_history`.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// One edit applied by `modify_order`. Lines are matched by menu item ID.
+#[contracttype]
+#[derive(Clone)]
+pub enum ItemChange {
+    /// Add a catalog item, or more portions of one already on the order.
+    Add(OrderItem),
+    /// Remove the line for a menu item.
+    Remove(u64),
+    /// Set the quantity of the line for a menu item.
+    SetQuantity(u64, u32),
+}
+
 /// A complete order stored on-chain.
 #[contracttype]
 #[derive(Clone)]
@@ -61,6 +194,8 @@ pub struct Order {
     pub restaurant_id: u64,
     pub customer: Address,
     pub items: Vec<OrderItem>,
+    /// SEP-41 token every item is priced in.
+    pub token: Address,
     /// Sum of (quantity * unit_price) for all items, in stroops.
     pub total_amount: i128,
     pub status: OrderStatus,
@@ -68,6 +203,36 @@ pub struct Order {
     pub updated_at: u64,
     /// Optional delivery/special instructions.
     pub notes: String,
+    /// Requested fulfilment time for scheduled orders; `None` for ASAP.
+    pub scheduled_for: Option<u64>,
+    pub fulfilment: Fulfilment,
+    /// Number of times the customer has modified the order; `0` as placed.
+    pub revision: u32,
+}
+
+impl Order {
+    /// Whether the order still needs work, i.e. is neither delivered nor
+    /// cancelled.
+    fn is_open(&self) -> bool {
+        !matches!(self.status, OrderStatus::Delivered | OrderStatus::Cancelled)
+    }
+}
+
+/// The parts of an [`Order`] other contracts need to check it.
+///
+/// Kept separate from `Order` so that callers such as the registry's
+/// reviews module do not break when line items change shape.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderSummary {
+    pub id: u64,
+    pub restaurant_id: u64,
+    pub customer: Address,
+    pub token: Address,
+    pub total_amount: i128,
+    pub status: OrderStatus,
+    pub updated_at: u64,
+    pub revision: u32,
 }
 
 // ---------------------------------------------------------------------------
@@ -77,12 +242,101 @@ pub struct Order {
 #[contracttype]
 pub enum DataKey {
     Admin,
+    /// Proposed next admin awaiting `accept_admin_transfer`.
+    PendingAdmin,
+    /// Address of the Restaurant Registry contract.
+    Registry,
+    /// Address of the Payment contract, once set by the admin.
+    PaymentContract,
+    /// Minimum verification level a restaurant needs to take orders.
+    MinVerification,
     Count,
     Order(u64),
     /// Ordered list of order IDs for a restaurant (for pagination off-chain).
     RestaurantOrders(u64),
     /// Ordered list of order IDs for a customer.
     CustomerOrders(Address),
+    /// Layout version of the stored records.
+    SchemaVersion,
+    /// Next order ID `migrate` will examine, while a migration is in
+    /// progress.
+    MigrationCursor,
+    /// TTL policy set by the admin.
+    TtlPolicy,
+    /// Ledger until which an order record is known to live.
+    LiveUntil(u64),
+    /// IDs of the open orders at a (restaurant ID, table ID).
+    TableOrders(u64, u64),
+    /// IDs of a restaurant's open orders.
+    OpenOrders(u64),
+    /// Number of entries in an order's status history.
+    HistoryCount(u64),
+    /// Entry of an order's status history, by (order ID, index from 0).
+    History(u64, u32),
+    /// Order size limits set by the admin.
+    OrderLimits,
+}
+
+// ---------------------------------------------------------------------------
+// Legacy layouts
+// ---------------------------------------------------------------------------
+
+/// Layout version of the records written by this code.
+///
+/// - **1** – original layout: `Order` without `token` or `scheduled_for`.
+/// - **2** – `Order` without `fulfilment`.
+/// - **3** – `Order` without `revision`.
+/// - **4** – current layout.
+pub const SCHEMA_VERSION: u32 = 4;
+
+/// `Order` as stored by schema version 1.
+#[contracttype]
+#[derive(Clone)]
+struct OrderV1 {
+    id: u64,
+    restaurant_id: u64,
+    customer: Address,
+    items: Vec<OrderItem>,
+    total_amount: i128,
+    status: OrderStatus,
+    created_at: u64,
+    updated_at: u64,
+    notes: String,
+}
+
+/// `Order` as stored by schema version 2.
+#[contracttype]
+#[derive(Clone)]
+struct OrderV2 {
+    id: u64,
+    restaurant_id: u64,
+    customer: Address,
+    items: Vec<OrderItem>,
+    token: Address,
+    total_amount: i128,
+    status: OrderStatus,
+    created_at: u64,
+    updated_at: u64,
+    notes: String,
+    scheduled_for: Option<u64>,
+}
+
+/// `Order` as stored by schema version 3.
+#[contracttype]
+#[derive(Clone)]
+struct OrderV3 {
+    id: u64,
+    restaurant_id: u64,
+    customer: Address,
+    items: Vec<OrderItem>,
+    token: Address,
+    total_amount: i128,
+    status: OrderStatus,
+    created_at: u64,
+    updated_at: u64,
+    notes: String,
+    scheduled_for: Option<u64>,
+    fulfilment: Fulfilment,
 }
 
 // ---------------------------------------------------------------------------
@@ -99,13 +353,24 @@ impl OrderContract {
     // -----------------------------------------------------------------------
 
     /// Deploy and initialise the order contract.
-    pub fn initialize(env: Env, admin: Address) {
+    ///
+    /// # Arguments
+    /// - `admin`    – full-control address (platform operator).
+    /// - `registry` – Restaurant Registry contract holding the menu catalogs.
+    pub fn initialize(env: Env, admin: Address, registry: Address) -> Result<(), Error> {
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            return Err(Error::AlreadyInitialized);
         }
         env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Registry, &registry);
         env.storage().instance().set(&DataKey::Count, &0u64);
-        env.storage().instance().extend_ttl(17_280, 17_280);
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        extend_instance(&env);
+
+        Initialized { admin, registry }.publish(&env);
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
@@ -117,8 +382,19 @@ impl OrderContract {
     /// # Arguments
     /// - `customer`       – wallet placing the order (must sign the tx).
     /// - `restaurant_id`  – target restaurant (registered in the registry).
-    /// - `items`          – non-empty list of line items.
+    /// - `items`          – non-empty list of line items; each must be an
+    ///                      available catalog item at its current price.
     /// - `notes`          – optional delivery / allergy notes.
+    /// - `scheduled_for`  – future time the order is wanted for, or `None`
+    ///                      for as soon as possible.
+    /// - `fulfilment`     – how the order reaches the customer, and the
+    ///                      registry table of a dine-in order.
+    ///
+    /// The restaurant must be registered and active (not deactivated,
+    /// suspended or closed for good), and open now, or at `scheduled_for`
+    /// for a scheduled order. A dine-in table must exist and be active.
+    /// The restaurant must still accept the menu items' token, and the order
+    /// must stay within the current [`OrderLimits`].
     ///
     /// # Returns
     /// The auto-assigned order ID.
@@ -128,100 +404,210 @@ impl OrderContract {
         restaurant_id: u64,
         items: Vec<OrderItem>,
         notes: String,
-    ) -> u64 {
+        scheduled_for: Option<u64>,
+        fulfilment: Fulfilment,
+    ) -> Result<u64, Error> {
         customer.require_auth();
 
         if items.is_empty() {
-            panic!("order must contain at least one item");
+            return Err(Error::EmptyOrder);
+        }
+        let limits = limits::limits(&env);
+        if items.len() > limits.max_items {
+            return Err(Error::TooManyItems);
         }
 
-        // Compute total from items.
-        let mut total: i128 = 0;
-        for item in items.iter() {
-            if item.quantity == 0 {
-                panic!("quantity must be greater than zero");
-            }
-            if item.unit_price <= 0 {
-                panic!("unit price must be positive");
-            }
-            total += item.unit_price * item.quantity as i128;
+        let now = env.ledger().timestamp();
+        let registry = Self::registry(&env)?;
+        Self::check_restaurant(&registry, restaurant_id)?;
+        let level = match registry.try_verification_level(&restaurant_id) {
+            Ok(Ok(level)) => level,
+            _ => return Err(Error::RestaurantNotFound),
+        };
+        if level < Self::min_verification(env.clone()) {
+            return Err(Error::InsufficientVerification);
+        }
+        let open_at = match scheduled_for {
+            Some(at) if at <= now => return Err(Error::ScheduledTimeInPast),
+            Some(at) => at,
+            None => now,
+        };
+        match registry.try_is_open_at(&restaurant_id, &open_at) {
+            Ok(Ok(true)) => {}
+            Ok(Ok(false)) => return Err(Error::RestaurantClosed),
+            _ => return Err(Error::RestaurantNotFound),
+        }
+
+        Self::check_table(&env, restaurant_id, &fulfilment)?;
+        let (items, token) = Self::price_items(&env, restaurant_id, &items, &limits)?;
+        let total = Self::order_total(&items)?;
+        if total > limits.max_total {
+            return Err(Error::TotalTooLarge);
         }
 
         let count: u64 = env.storage().instance().get(&DataKey::Count).unwrap_or(0);
         let id: u64 = count + 1;
-        let now = env.ledger().timestamp();
 
         let order = Order {
             id,
             restaurant_id,
             customer: customer.clone(),
-            items: items.clone(),
+            items,
+            token: token.clone(),
             total_amount: total,
             status: OrderStatus::Pending,
             created_at: now,
             updated_at: now,
             notes,
+            scheduled_for,
+            fulfilment: fulfilment.clone(),
+            revision: 0,
         };
 
-        let ttl: u32 = 2_073_600;
-        env.storage().persistent().set(&DataKey::Order(id), &order);
-        env.storage()
-            .persistent()
-            .extend_ttl(&DataKey::Order(id), ttl, ttl);
+        Self::save_order(&env, &order);
 
         // Append to restaurant index.
-        Self::append_to_list(&env, DataKey::RestaurantOrders(restaurant_id), id, ttl);
+        Self::append_to_list(&env, DataKey::RestaurantOrders(restaurant_id), id);
         // Append to customer index.
-        Self::append_to_list(&env, DataKey::CustomerOrders(customer.clone()), id, ttl);
+        Self::append_to_list(&env, DataKey::CustomerOrders(customer.clone()), id);
 
         env.storage().instance().set(&DataKey::Count, &id);
-        env.storage().instance().extend_ttl(17_280, 17_280);
+        extend_instance(&env);
 
-        env.events().publish(
-            (symbol_short!("placed"), symbol_short!("order")),
-            (id, restaurant_id, customer, total),
-        );
+        OrderPlaced {
+            order_id: id,
+            restaurant_id,
+            customer,
+            token,
+            total_amount: total,
+            scheduled_for,
+            fulfilment,
+        }
+        .publish(&env);
+
+        Ok(id)
+    }
+
+    /// Change the items of a `Pending` order (customer only).
+    ///
+    /// Applies `changes` in order, then checks the result as `place_order`
+    /// would: every line must be an available catalog item at its current
+    /// price, in the order's token, which the restaurant still accepts,
+    /// within the [`OrderLimits`]. A line whose catalog price changed since
+    /// it was ordered must be removed and added again at the new price.
+    ///
+    /// # Returns
+    /// The order's new revision.
+    pub fn modify_order(
+        env: Env,
+        customer: Address,
+        order_id: u64,
+        changes: Vec<ItemChange>,
+    ) -> Result<u32, Error> {
+        customer.require_auth();
+
+        let mut order = Self::load_order(&env, order_id)?;
+        if customer != order.customer {
+            return Err(Error::Unauthorized);
+        }
+        if order.status != OrderStatus::Pending {
+            return Err(Error::NotPending);
+        }
+        if changes.is_empty() {
+            return Err(Error::NoChanges);
+        }
+        if Self::is_paid(&env, &order) {
+            return Err(Error::AlreadyEscrowed);
+        }
+
+        let mut items = order.items.clone();
+        for change in changes.iter() {
+            Self::apply_change(&mut items, change)?;
+        }
+        if items.is_empty() {
+            return Err(Error::EmptyOrder);
+        }
+        let limits = limits::limits(&env);
+        if items.len() > limits.max_items {
+            return Err(Error::TooManyItems);
+        }
+        let (items, token) = Self::price_items(&env, order.restaurant_id, &items, &limits)?;
+        if token != order.token {
+            return Err(Error::MixedTokens);
+        }
+        let total = Self::order_total(&items)?;
+        if total > limits.max_total {
+            return Err(Error::TotalTooLarge);
+        }
+
+        order.items = items;
+        order.total_amount = total;
+        order.revision += 1;
+        order.updated_at = env.ledger().timestamp();
+        Self::save_order(&env, &order);
 
-        id
+        OrderModified {
+            order_id,
+            revision: order.revision,
+            total_amount: total,
+        }
+        .publish(&env);
+        Ok(order.revision)
     }
 
     /// Cancel an order.
     ///
     /// - Customers may cancel while the order is `Pending`.
-    /// - The admin may cancel at any time (for dispute resolution).
-    pub fn cancel_order(env: Env, caller: Address, order_id: u64) {
+    /// - The admin, the restaurant's owner and its staff holding
+    ///   `CancelOrder` may cancel any order not yet delivered.
+    ///
+    /// `reason` is an optional code stored in the order's status history.
+    pub fn cancel_order(
+        env: Env,
+        caller: Address,
+        order_id: u64,
+        reason: Option<Symbol>,
+    ) -> Result<(), Error> {
         caller.require_auth();
 
-        let mut order = Self::load_order(&env, order_id);
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        let mut order = Self::load_order(&env, order_id)?;
 
-        let is_admin = caller == admin;
         let is_customer = caller == order.customer;
-
-        if !is_admin && !is_customer {
-            panic!("unauthorized");
+        let is_staff = Self::assert_restaurant_staff(
+            &env,
+            &caller,
+            order.restaurant_id,
+            Permission::CancelOrder,
+        )
+        .is_ok();
+
+        if !is_staff && !is_customer {
+            return Err(Error::Unauthorized);
         }
 
         if order.status == OrderStatus::Delivered {
-            panic!("cannot cancel a delivered order");
+            return Err(Error::AlreadyDelivered);
         }
 
         if order.status == OrderStatus::Cancelled {
-            panic!("order already cancelled");
+            return Err(Error::AlreadyCancelled);
         }
 
-        if is_customer && order.status != OrderStatus::Pending {
-            panic!("customers may only cancel pending orders");
+        if !is_staff && order.status != OrderStatus::Pending {
+            return Err(Error::NotPending);
         }
 
+        Self::record_change(&env, &order, OrderStatus::Cancelled, &caller, reason);
         order.status = OrderStatus::Cancelled;
         order.updated_at = env.ledger().timestamp();
         Self::save_order(&env, &order);
 
-        env.events().publish(
-            (symbol_short!("cancelled"), symbol_short!("order")),
-            (order_id, caller),
-        );
+        OrderCancelled {
+            order_id,
+            cancelled_by: caller,
+        }
+        .publish(&env);
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
@@ -230,232 +616,2074 @@ impl OrderContract {
 
     /// Advance the order to the next status in the lifecycle.
     ///
-    /// Only the contract admin may call this; in production you would add a
-    /// check against the restaurant registry to allow restaurant owners too.
+    /// Callable by the contract admin, the owner of the order's restaurant,
+    /// or staff holding the registry permission for the step:
     ///
-    /// Valid transitions (in order):
-    /// `Pending → Confirmed → Preparing → Ready → Delivered`
-    pub fn advance_status(env: Env, caller: Address, order_id: u64) {
+    /// | Transition              | Permission     |
+    /// |-------------------------|----------------|
+    /// | `Pending → Confirmed`   | `ConfirmOrder` |
+    /// | `Confirmed → Preparing` | `PrepareOrder` |
+    /// | `Preparing → Ready`     | `ReadyOrder`   |
+    /// | `Ready → Delivered`     | `DeliverOrder` |
+    ///
+    /// Owners and staff are always checked against the order's own
+    /// restaurant, so they cannot touch another restaurant's orders.
+    pub fn advance_status(env: Env, caller: Address, order_id: u64) -> Result<(), Error> {
         caller.require_auth();
-        Self::assert_admin_or_panic(&env, &caller);
 
-        let mut order = Self::load_order(&env, order_id);
+        let mut order = Self::load_order(&env, order_id)?;
 
-        order.status = match order.status {
-            OrderStatus::Pending => OrderStatus::Confirmed,
-            OrderStatus::Confirmed => OrderStatus::Preparing,
-            OrderStatus::Preparing => OrderStatus::Ready,
-            OrderStatus::Ready => OrderStatus::Delivered,
-            OrderStatus::Delivered => panic!("order already delivered"),
-            OrderStatus::Cancelled => panic!("cannot advance a cancelled order"),
+        let (next, permission) = match order.status {
+            OrderStatus::Pending => (OrderStatus::Confirmed, Permission::ConfirmOrder),
+            OrderStatus::Confirmed => (OrderStatus::Preparing, Permission::PrepareOrder),
+            OrderStatus::Preparing => (OrderStatus::Ready, Permission::ReadyOrder),
+            OrderStatus::Ready => (OrderStatus::Delivered, Permission::DeliverOrder),
+            OrderStatus::Delivered => return Err(Error::AlreadyDelivered),
+            OrderStatus::Cancelled => return Err(Error::AlreadyCancelled),
         };
+        Self::assert_restaurant_staff(&env, &caller, order.restaurant_id, permission)?;
+
+        Self::record_change(&env, &order, next.clone(), &caller, None);
+        order.status = next;
         order.updated_at = env.ledger().timestamp();
         Self::save_order(&env, &order);
 
-        env.events().publish(
-            (symbol_short!("advanced"), symbol_short!("order")),
+        OrderAdvanced {
             order_id,
-        );
+            status: order.status,
+        }
+        .publish(&env);
+        Ok(())
     }
 
     /// Directly set an order's status (admin only – for dispute resolution).
-    pub fn set_status(env: Env, caller: Address, order_id: u64, status: OrderStatus) {
+    ///
+    /// `reason` is an optional code stored in the order's status history.
+    /// Setting the status the order already has fails with
+    /// [`Error::StatusUnchanged`] and records nothing.
+    pub fn set_status(
+        env: Env,
+        caller: Address,
+        order_id: u64,
+        status: OrderStatus,
+        reason: Option<Symbol>,
+    ) -> Result<(), Error> {
         caller.require_auth();
-        Self::assert_admin_or_panic(&env, &caller);
+        Self::assert_admin(&env, &caller)?;
 
-        let mut order = Self::load_order(&env, order_id);
-        order.status = status;
+        let mut order = Self::load_order(&env, order_id)?;
+        if order.status == status {
+            return Err(Error::StatusUnchanged);
+        }
+        Self::record_change(&env, &order, status.clone(), &caller, reason);
+        order.status = status.clone();
         order.updated_at = env.ledger().timestamp();
         Self::save_order(&env, &order);
 
-        env.events().publish(
-            (symbol_short!("setstatus"), symbol_short!("order")),
-            order_id,
-        );
+        OrderStatusSet { order_id, status }.publish(&env);
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
-    // View functions
+    // Admin
     // -----------------------------------------------------------------------
 
-    /// Fetch a single order by ID.
-    pub fn get_order(env: Env, order_id: u64) -> Order {
-        Self::load_order(&env, order_id)
-    }
-
-    /// Return a list of order IDs for a restaurant.
-    pub fn get_restaurant_orders(env: Env, restaurant_id: u64) -> Vec<u64> {
+    /// Set the verification level restaurants need to take orders
+    /// (admin only). Defaults to `Unverified`, i.e. no requirement.
+    pub fn set_min_verification(
+        env: Env,
+        caller: Address,
+        level: VerificationLevel,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
         env.storage()
-            .persistent()
-            .get(&DataKey::RestaurantOrders(restaurant_id))
-            .unwrap_or_else(|| vec![&env])
+            .instance()
+            .set(&DataKey::MinVerification, &level);
+        extend_instance(&env);
+
+        MinVerificationSet { level }.publish(&env);
+        Ok(())
     }
 
-    /// Return a list of order IDs for a customer.
-    pub fn get_customer_orders(env: Env, customer: Address) -> Vec<u64> {
+    /// Set the Payment contract that `modify_order` checks for an existing
+    /// payment (admin only). Until it is set, orders can be modified
+    /// regardless of payment.
+    pub fn set_payment_contract(
+        env: Env,
+        caller: Address,
+        payment_contract: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
         env.storage()
-            .persistent()
-            .get(&DataKey::CustomerOrders(customer))
-            .unwrap_or_else(|| vec![&env])
+            .instance()
+            .set(&DataKey::PaymentContract, &payment_contract);
+        extend_instance(&env);
+
+        PaymentContractSet { payment_contract }.publish(&env);
+        Ok(())
     }
 
-    /// Total orders ever placed.
-    pub fn get_count(env: Env) -> u64 {
-        env.storage().instance().get(&DataKey::Count).unwrap_or(0)
+    /// Replace the TTL policy (admin only).
+    ///
+    /// # Errors
+    /// [`Error::InvalidTtlPolicy`] if a threshold exceeds its extension, an
+    /// extension is zero, or an extension exceeds the network's maximum TTL.
+    pub fn set_ttl_policy(env: Env, caller: Address, policy: TtlPolicy) -> Result<(), Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
+        if !policy.is_valid(env.storage().max_ttl()) {
+            return Err(Error::InvalidTtlPolicy);
+        }
+        env.storage().instance().set(&DataKey::TtlPolicy, &policy);
+        extend_instance(&env);
+
+        TtlPolicySet { policy }.publish(&env);
+        Ok(())
     }
 
-    // -----------------------------------------------------------------------
-    // Private helpers
-    // -----------------------------------------------------------------------
+    /// Replace the order size limits (admin only).
+    ///
+    /// # Errors
+    /// [`Error::InvalidOrderLimits`] if any limit is zero or negative.
+    pub fn set_order_limits(env: Env, caller: Address, limits: OrderLimits) -> Result<(), Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
+        if !limits.is_valid() {
+            return Err(Error::InvalidOrderLimits);
+        }
+        env.storage().instance().set(&DataKey::OrderLimits, &limits);
+        extend_instance(&env);
 
-    fn load_order(env: &Env, order_id: u64) -> Order {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Order(order_id))
-            .unwrap_or_else(|| panic!("order not found"))
+        OrderLimitsSet { limits }.publish(&env);
+        Ok(())
     }
 
-    fn save_order(env: &Env, order: &Order) {
-        let ttl: u32 = 2_073_600;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Order(order.id), order);
+    /// Propose `new_admin` as the next contract admin (admin only).
+    ///
+    /// The role only moves once `new_admin` calls `accept_admin_transfer`,
+    /// so a mistyped address cannot lock the contract. Replaces any pending
+    /// proposal.
+    pub fn propose_admin_transfer(
+        env: Env,
+        caller: Address,
+        new_admin: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
         env.storage()
-            .persistent()
-            .extend_ttl(&DataKey::Order(order.id), ttl, ttl);
-    }
+            .instance()
+            .set(&DataKey::PendingAdmin, &new_admin);
+        extend_instance(&env);
 
-    fn assert_admin_or_panic(env: &Env, caller: &Address) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if caller != &admin {
-            panic!("unauthorized: admin only");
+        AdminTransferProposed {
+            admin: caller,
+            new_admin,
         }
+        .publish(&env);
+        Ok(())
     }
 
-    fn append_to_list(env: &Env, key: DataKey, id: u64, ttl: u32) {
-        let mut list: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or_else(|| vec![env]);
-        list.push_back(id);
-        env.storage().persistent().set(&key, &list);
-        env.storage().persistent().extend_ttl(&key, ttl, ttl);
-    }
-}
+    /// Accept a pending admin proposal and become the contract admin.
+    pub fn accept_admin_transfer(env: Env, new_admin: Address) -> Result<(), Error> {
+        new_admin.require_auth();
 
-// ---------------------------------------------------------------------------
-// Tests
-// ---------------------------------------------------------------------------
+        let pending: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(Error::NoPendingAdmin)?;
+        if pending != new_admin {
+            return Err(Error::Unauthorized);
+        }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::testutils::Address as _;
-    use soroban_sdk::{vec, Env, String};
+        let previous = Self::admin(env.clone())?;
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        extend_instance(&env);
 
-    fn make_item(env: &Env, id: u64, qty: u32, price: i128) -> OrderItem {
-        OrderItem {
-            menu_item_id: id,
-            name: String::from_str(env, "Jollof Rice"),
-            quantity: qty,
-            unit_price: price,
+        AdminTransferAccepted {
+            previous_admin: previous,
+            new_admin,
         }
+        .publish(&env);
+        Ok(())
     }
 
-    fn setup() -> (Env, OrderContractClient<'static>) {
-        let env = Env::default();
-        env.mock_all_auths();
-        let cid = env.register(OrderContract, ());
-        let client = OrderContractClient::new(&env, &cid);
-        (env, client)
+    /// Withdraw a pending admin proposal (admin only).
+    pub fn cancel_admin_transfer(env: Env, caller: Address) -> Result<(), Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
+        if !env.storage().instance().has(&DataKey::PendingAdmin) {
+            return Err(Error::NoPendingAdmin);
+        }
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        AdminTransferCancelled { admin: caller }.publish(&env);
+        Ok(())
     }
 
-    #[test]
-    fn test_place_and_get_order() {
-        let (env, client) = setup();
-        let admin = Address::generate(&env);
-        let customer = Address::generate(&env);
+    // -----------------------------------------------------------------------
+    // Upgrades
+    // -----------------------------------------------------------------------
 
-        client.initialize(&admin);
+    /// Replace the contract code with the uploaded Wasm `new_wasm_hash`
+    /// (admin only).
+    ///
+    /// Storage is left untouched; call `migrate` afterwards if the new code
+    /// raises [`SCHEMA_VERSION`].
+    pub fn upgrade(env: Env, caller: Address, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
 
-        let items = vec![&env, make_item(&env, 1, 2, 5_000_000)]; // 2 × 0.5 XLM
-        let id = client.place_order(
-            &customer,
-            &42,
-            &items,
-            &String::from_str(&env, "No onions please"),
-        );
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
 
-        assert_eq!(id, 1);
-        let order = client.get_order(&id);
-        assert_eq!(order.total_amount, 10_000_000);
-        assert_eq!(order.status, OrderStatus::Pending);
+        ContractUpgraded { new_wasm_hash }.publish(&env);
+        Ok(())
     }
 
-    #[test]
-    fn test_advance_status() {
-        let (env, client) = setup();
-        let admin = Address::generate(&env);
-        let customer = Address::generate(&env);
-        client.initialize(&admin);
+    /// Rewrite the next `limit` orders from a legacy layout into the
+    /// current one (admin only).
+    ///
+    /// Version 1 orders did not record their token, so `token` is stored on
+    /// every version-1 order rewritten by this call. Orders from versions 1
+    /// and 2 are recorded as deliveries, and every legacy order starts at
+    /// revision `0`.
+    ///
+    /// Each call resumes from the migration cursor, so the admin calls this
+    /// with batches small enough to fit the transaction budget until
+    /// `schema_version` reports [`SCHEMA_VERSION`]. The batch that passes
+    /// the last order records [`SCHEMA_VERSION`] as the stored version and
+    /// clears the cursor. Does nothing once the data is current.
+    ///
+    /// # Returns
+    /// The number of orders rewritten by this call.
+    pub fn migrate(env: Env, caller: Address, limit: u32, token: Address) -> Result<u32, Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
 
-        let items = vec![&env, make_item(&env, 1, 1, 7_000_000)];
-        let id = client.place_order(&customer, &1, &items, &String::from_str(&env, ""));
+        if Self::schema_version(env.clone()) == SCHEMA_VERSION {
+            return Ok(0);
+        }
 
-        client.advance_status(&admin, &id);
-        assert_eq!(client.get_order(&id).status, OrderStatus::Confirmed);
+        let instance = env.storage().instance();
+        let count: u64 = instance.get(&DataKey::Count).unwrap_or(0);
+        let start: u64 = instance.get(&DataKey::MigrationCursor).unwrap_or(1);
+        let end = start.saturating_add(limit.into()).min(count + 1);
 
-        client.advance_status(&admin, &id);
-        assert_eq!(client.get_order(&id).status, OrderStatus::Preparing);
+        let mut migrated = 0u32;
+        for order_id in start..end {
+            if Self::migrate_order(&env, order_id, &token) {
+                migrated += 1;
+            }
+        }
 
-        client.advance_status(&admin, &id);
-        assert_eq!(client.get_order(&id).status, OrderStatus::Ready);
+        if end > count {
+            instance.set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+            instance.remove(&DataKey::MigrationCursor);
+        } else {
+            instance.set(&DataKey::MigrationCursor, &end);
+        }
+        extend_instance(&env);
 
-        client.advance_status(&admin, &id);
-        assert_eq!(client.get_order(&id).status, OrderStatus::Delivered);
+        ContractMigrated {
+            schema_version: Self::schema_version(env.clone()),
+            migrated,
+        }
+        .publish(&env);
+        Ok(migrated)
     }
 
-    #[test]
-    fn test_customer_cancel_pending() {
-        let (env, client) = setup();
-        let admin = Address::generate(&env);
-        let customer = Address::generate(&env);
-        client.initialize(&admin);
-
-        let items = vec![&env, make_item(&env, 2, 1, 3_000_000)];
-        let id = client.place_order(&customer, &1, &items, &String::from_str(&env, ""));
+    // -----------------------------------------------------------------------
+    // Storage TTL
+    // -----------------------------------------------------------------------
 
-        client.cancel_order(&customer, &id);
-        assert_eq!(client.get_order(&id).status, OrderStatus::Cancelled);
+    /// Extend an order record and the contract instance. Anyone may call
+    /// this, e.g. to keep a long-running order from being archived.
+    ///
+    /// # Returns
+    /// The order record's remaining TTL, in ledgers.
+    pub fn bump_order(env: Env, order_id: u64) -> Result<u32, Error> {
+        Self::load_order(&env, order_id)?;
+        let remaining = extend_order(&env, order_id);
+        extend_instance(&env);
+        Ok(remaining)
     }
 
-    #[test]
-    #[should_panic(expected = "customers may only cancel pending orders")]
-    fn test_customer_cannot_cancel_confirmed() {
-        let (env, client) = setup();
-        let admin = Address::generate(&env);
-        let customer = Address::generate(&env);
-        client.initialize(&admin);
+    /// Return the remaining TTL of an order record, in ledgers, as last
+    /// recorded by this contract.
+    ///
+    /// Records not extended since TTL tracking was introduced report `0`
+    /// until they are next written or bumped.
+    pub fn order_ttl(env: Env, order_id: u64) -> Result<u32, Error> {
+        Self::load_order(&env, order_id)?;
+        Ok(ttl::remaining_ttl(&env, order_id))
+    }
 
-        let items = vec![&env, make_item(&env, 1, 1, 5_000_000)];
-        let id = client.place_order(&customer, &1, &items, &String::from_str(&env, ""));
-        client.advance_status(&admin, &id);
-        client.cancel_order(&customer, &id);
+    /// Return the TTL policy in force.
+    pub fn ttl_policy(env: Env) -> TtlPolicy {
+        ttl::policy(&env)
     }
 
-    #[test]
-    fn test_get_restaurant_orders() {
-        let (env, client) = setup();
-        let admin = Address::generate(&env);
+    // -----------------------------------------------------------------------
+    // View functions
+    // -----------------------------------------------------------------------
+
+    /// Fetch a single order by ID.
+    pub fn get_order(env: Env, order_id: u64) -> Result<Order, Error> {
+        Self::load_order(&env, order_id)
+    }
+
+    /// Fetch the identifying fields and status of an order.
+    pub fn get_order_summary(env: Env, order_id: u64) -> Result<OrderSummary, Error> {
+        let order = Self::load_order(&env, order_id)?;
+        Ok(OrderSummary {
+            id: order.id,
+            restaurant_id: order.restaurant_id,
+            customer: order.customer,
+            token: order.token,
+            total_amount: order.total_amount,
+            status: order.status,
+            updated_at: order.updated_at,
+            revision: order.revision,
+        })
+    }
+
+    /// Return an order's status changes, oldest first.
+    ///
+    /// Returns up to `limit` entries (capped at [`MAX_PAGE_SIZE`]) starting
+    /// from index `start`. To fetch the next page, call again with `start`
+    /// advanced by the number of entries returned; an empty result means
+    /// the end of the history has been reached.
+    pub fn get_order_history(
+        env: Env,
+        order_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<StatusChange>, Error> {
+        Self::load_order(&env, order_id)?;
+        let storage = env.storage().persistent();
+        let count: u32 = storage.get(&DataKey::HistoryCount(order_id)).unwrap_or(0);
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+        let mut page = vec![&env];
+        for index in start..end {
+            if let Some(change) = storage.get(&DataKey::History(order_id, index)) {
+                page.push_back(change);
+            }
+        }
+        Ok(page)
+    }
+
+    /// Return a list of order IDs for a restaurant.
+    pub fn get_restaurant_orders(env: Env, restaurant_id: u64) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RestaurantOrders(restaurant_id))
+            .unwrap_or_else(|| vec![&env])
+    }
+
+    /// Return the IDs of a restaurant's orders that are neither delivered
+    /// nor cancelled, oldest first.
+    ///
+    /// The registry checks this before letting a restaurant close.
+    pub fn get_open_orders(env: Env, restaurant_id: u64) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OpenOrders(restaurant_id))
+            .unwrap_or_else(|| vec![&env])
+    }
+
+    /// Return the IDs of the orders at a table that are neither delivered
+    /// nor cancelled, oldest first.
+    pub fn get_table_orders(env: Env, restaurant_id: u64, table_id: u64) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TableOrders(restaurant_id, table_id))
+            .unwrap_or_else(|| vec![&env])
+    }
+
+    /// Return a list of order IDs for a customer.
+    pub fn get_customer_orders(env: Env, customer: Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CustomerOrders(customer))
+            .unwrap_or_else(|| vec![&env])
+    }
+
+    /// Total orders ever placed.
+    pub fn get_count(env: Env) -> u64 {
+        env.storage().instance().get(&DataKey::Count).unwrap_or(0)
+    }
+
+    /// Return the admin address.
+    pub fn admin(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)
+    }
+
+    /// Return the minimum verification level required to take orders.
+    pub fn min_verification(env: Env) -> VerificationLevel {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinVerification)
+            .unwrap_or(VerificationLevel::Unverified)
+    }
+
+    /// Return the current order size limits.
+    pub fn order_limits(env: Env) -> OrderLimits {
+        limits::limits(&env)
+    }
+
+    /// Return the proposed next admin, if a handover is in progress.
+    pub fn pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Return the layout version of the stored data.
+    ///
+    /// Deployments that predate versioning report `1`.
+    pub fn schema_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(1)
+    }
+
+    /// Return the next order ID `migrate` will examine, or `None` if no
+    /// migration is in progress.
+    pub fn migration_cursor(env: Env) -> Option<u64> {
+        env.storage().instance().get(&DataKey::MigrationCursor)
+    }
+
+    // -----------------------------------------------------------------------
+    // Private helpers
+    // -----------------------------------------------------------------------
+
+    /// Check every line item against the registry catalog.
+    ///
+    /// Returns the items with their names snapshotted from the catalog,
+    /// together with the token they are all priced in.
+    fn price_items(
+        env: &Env,
+        restaurant_id: u64,
+        items: &Vec<OrderItem>,
+        limits: &OrderLimits,
+    ) -> Result<(Vec<OrderItem>, Address), Error> {
+        let registry = Self::registry(env)?;
+
+        let mut priced: Vec<OrderItem> = vec![env];
+        let mut token: Option<Address> = None;
+        for mut item in items.iter() {
+            if item.quantity == 0 {
+                return Err(Error::InvalidQuantity);
+            }
+            if item.quantity > limits.max_quantity {
+                return Err(Error::QuantityTooLarge);
+            }
+
+            let menu_item = match registry.try_get_menu_item(&restaurant_id, &item.menu_item_id) {
+                Ok(Ok(menu_item)) => menu_item,
+                _ => return Err(Error::MenuItemNotFound),
+            };
+            if !menu_item.is_available {
+                return Err(Error::MenuItemUnavailable);
+            }
+            if item.unit_price != menu_item.price {
+                return Err(Error::PriceMismatch);
+            }
+            match &token {
+                None => token = Some(menu_item.token.clone()),
+                Some(t) if *t != menu_item.token => return Err(Error::MixedTokens),
+                Some(_) => {}
+            }
+
+            item.name = menu_item.name;
+            priced.push_back(item);
+        }
+
+        // Menu tokens are checked against the profile when items are saved,
+        // but the profile may have dropped the token since.
+        let token = token.ok_or(Error::EmptyOrder)?;
+        match registry.try_accepts_token(&restaurant_id, &token) {
+            Ok(Ok(true)) => {}
+            Ok(Ok(false)) => return Err(Error::TokenNotAccepted),
+            _ => return Err(Error::RestaurantNotFound),
+        }
+        Ok((priced, token))
+    }
+
+    /// Append the move of `order` to `to` by `actor` to its status history.
+    fn record_change(
+        env: &Env,
+        order: &Order,
+        to: OrderStatus,
+        actor: &Address,
+        reason: Option<Symbol>,
+    ) {
+        let count_key = DataKey::HistoryCount(order.id);
+        let index: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        let entry_key = DataKey::History(order.id, index);
+        let change = StatusChange {
+            from: order.status.clone(),
+            to,
+            actor: actor.clone(),
+            at: env.ledger().timestamp(),
+            reason,
+        };
+        env.storage().persistent().set(&entry_key, &change);
+        extend_persistent(env, &entry_key);
+        env.storage().persistent().set(&count_key, &(index + 1));
+        extend_persistent(env, &count_key);
+    }
+
+    /// Apply one [`ItemChange`] to `items`.
+    ///
+    /// Adding to an existing line requires its recorded unit price.
+    fn apply_change(items: &mut Vec<OrderItem>, change: ItemChange) -> Result<(), Error> {
+        let find = |menu_item_id: u64| {
+            items
+                .iter()
+                .position(|line| line.menu_item_id == menu_item_id)
+                .map(|index| index as u32)
+        };
+        match change {
+            ItemChange::Add(item) => match find(item.menu_item_id) {
+                Some(index) => {
+                    let mut line = items.get_unchecked(index);
+                    if line.unit_price != item.unit_price {
+                        return Err(Error::PriceMismatch);
+                    }
+                    line.quantity = line
+                        .quantity
+                        .checked_add(item.quantity)
+                        .ok_or(Error::QuantityTooLarge)?;
+                    items.set(index, line);
+                }
+                None => items.push_back(item),
+            },
+            ItemChange::Remove(menu_item_id) => {
+                let index = find(menu_item_id).ok_or(Error::MenuItemNotFound)?;
+                items.remove(index);
+            }
+            ItemChange::SetQuantity(menu_item_id, quantity) => {
+                let index = find(menu_item_id).ok_or(Error::MenuItemNotFound)?;
+                let mut line = items.get_unchecked(index);
+                line.quantity = quantity;
+                items.set(index, line);
+            }
+        }
+        Ok(())
+    }
+
+    /// Sum of `quantity * unit_price` over `items`, failing on overflow.
+    fn order_total(items: &Vec<OrderItem>) -> Result<i128, Error> {
+        items.iter().try_fold(0i128, |total, item| {
+            item.unit_price
+                .checked_mul(item.quantity as i128)
+                .and_then(|line| total.checked_add(line))
+                .ok_or(Error::Overflow)
+        })
+    }
+
+    /// Fail unless the registry knows the restaurant and reports it as
+    /// active, mapping each other status to its own error.
+    fn check_restaurant(registry: &RegistryClient, restaurant_id: u64) -> Result<(), Error> {
+        match registry.try_get_status(&restaurant_id) {
+            Ok(Ok(RestaurantStatus::Active)) => Ok(()),
+            Ok(Ok(RestaurantStatus::Inactive)) => Err(Error::RestaurantInactive),
+            Ok(Ok(RestaurantStatus::Suspended)) => Err(Error::RestaurantSuspended),
+            Ok(Ok(RestaurantStatus::Closed)) => Err(Error::RestaurantPermanentlyClosed),
+            _ => Err(Error::RestaurantNotFound),
+        }
+    }
+
+    /// Check that a table is named exactly when the channel is dine-in, and
+    /// that the table is active in the registry.
+    fn check_table(env: &Env, restaurant_id: u64, fulfilment: &Fulfilment) -> Result<(), Error> {
+        let table_id = match (fulfilment.channel, fulfilment.table_id) {
+            (FulfilmentChannel::DineIn, Some(table_id)) => table_id,
+            (FulfilmentChannel::DineIn, None) => return Err(Error::TableRequired),
+            (_, Some(_)) => return Err(Error::TableNotAllowed),
+            (_, None) => return Ok(()),
+        };
+        match Self::registry(env)?.try_get_table(&restaurant_id, &table_id) {
+            Ok(Ok(table)) if table.is_active => Ok(()),
+            Ok(Ok(_)) => Err(Error::TableInactive),
+            _ => Err(Error::TableNotFound),
+        }
+    }
+
+    fn registry(env: &Env) -> Result<RegistryClient<'_>, Error> {
+        let address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Registry)
+            .ok_or(Error::NotInitialized)?;
+        Ok(RegistryClient::new(env, &address))
+    }
+
+    /// Whether the configured Payment contract holds or has released a
+    /// payment for the order from its customer to its restaurant.
+    ///
+    /// Payments by anyone else do not count, so a stranger cannot freeze an
+    /// order by escrowing against its ID; nor do refunded ones.
+    fn is_paid(env: &Env, order: &Order) -> bool {
+        let Some(address) = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::PaymentContract)
+        else {
+            return false;
+        };
+        match PaymentClient::new(env, &address).try_get_payment(&order.id) {
+            Ok(Ok(payment)) => {
+                payment.status != PaymentStatus::Refunded
+                    && payment.payer == order.customer
+                    && payment.restaurant_id == order.restaurant_id
+            }
+            _ => false,
+        }
+    }
+
+    fn load_order(env: &Env, order_id: u64) -> Result<Order, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Order(order_id))
+            .ok_or(Error::OrderNotFound)
+    }
+
+    fn save_order(env: &Env, order: &Order) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Order(order.id), order);
+        extend_order(env, order.id);
+
+        // Keep the lists of open orders in step with the status.
+        Self::sync_open_list(env, DataKey::OpenOrders(order.restaurant_id), order);
+        if let Some(table_id) = order.fulfilment.table_id {
+            let key = DataKey::TableOrders(order.restaurant_id, table_id);
+            Self::sync_open_list(env, key, order);
+        }
+    }
+
+    /// List `order` under `key` while it is open, and unlist it once not.
+    fn sync_open_list(env: &Env, key: DataKey, order: &Order) {
+        let listed = env
+            .storage()
+            .persistent()
+            .get::<_, Vec<u64>>(&key)
+            .is_some_and(|ids| ids.contains(order.id));
+        if order.is_open() && !listed {
+            Self::append_to_list(env, key, order.id);
+        } else if !order.is_open() && listed {
+            Self::remove_from_list(env, key, order.id);
+        }
+    }
+
+    /// Rewrite one legacy order. Returns `false` if there is nothing to do.
+    ///
+    /// Orders from before channels were recorded are taken to be deliveries.
+    fn migrate_order(env: &Env, order_id: u64, token: &Address) -> bool {
+        let Some(fields) = env
+            .storage()
+            .persistent()
+            .get::<_, Map<Symbol, Val>>(&DataKey::Order(order_id))
+        else {
+            return false;
+        };
+        if fields.contains_key(Symbol::new(env, "revision")) {
+            return false;
+        }
+
+        let order = if fields.contains_key(Symbol::new(env, "fulfilment")) {
+            let Ok(legacy) = OrderV3::try_from_val(env, fields.as_val()) else {
+                return false;
+            };
+            Order {
+                id: legacy.id,
+                restaurant_id: legacy.restaurant_id,
+                customer: legacy.customer,
+                items: legacy.items,
+                token: legacy.token,
+                total_amount: legacy.total_amount,
+                status: legacy.status,
+                created_at: legacy.created_at,
+                updated_at: legacy.updated_at,
+                notes: legacy.notes,
+                scheduled_for: legacy.scheduled_for,
+                fulfilment: legacy.fulfilment,
+                revision: 0,
+            }
+        } else if fields.contains_key(Symbol::new(env, "token")) {
+            let Ok(legacy) = OrderV2::try_from_val(env, fields.as_val()) else {
+                return false;
+            };
+            Order {
+                id: legacy.id,
+                restaurant_id: legacy.restaurant_id,
+                customer: legacy.customer,
+                items: legacy.items,
+                token: legacy.token,
+                total_amount: legacy.total_amount,
+                status: legacy.status,
+                created_at: legacy.created_at,
+                updated_at: legacy.updated_at,
+                notes: legacy.notes,
+                scheduled_for: legacy.scheduled_for,
+                fulfilment: Fulfilment {
+                    channel: FulfilmentChannel::Delivery,
+                    table_id: None,
+                },
+                revision: 0,
+            }
+        } else {
+            let Ok(legacy) = OrderV1::try_from_val(env, fields.as_val()) else {
+                return false;
+            };
+            Order {
+                id: legacy.id,
+                restaurant_id: legacy.restaurant_id,
+                customer: legacy.customer,
+                items: legacy.items,
+                token: token.clone(),
+                total_amount: legacy.total_amount,
+                status: legacy.status,
+                created_at: legacy.created_at,
+                updated_at: legacy.updated_at,
+                notes: legacy.notes,
+                scheduled_for: None,
+                fulfilment: Fulfilment {
+                    channel: FulfilmentChannel::Delivery,
+                    table_id: None,
+                },
+                revision: 0,
+            }
+        };
+        Self::save_order(env, &order);
+        true
+    }
+
+    fn assert_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+        if caller != &Self::admin(env.clone())? {
+            return Err(Error::NotAdmin);
+        }
+        Ok(())
+    }
+
+    /// Allow the admin, the restaurant's owner, or staff holding
+    /// `permission` at that restaurant.
+    fn assert_restaurant_staff(
+        env: &Env,
+        caller: &Address,
+        restaurant_id: u64,
+        permission: Permission,
+    ) -> Result<(), Error> {
+        if Self::assert_admin(env, caller).is_ok() {
+            return Ok(());
+        }
+        let registry = Self::registry(env)?;
+        if let Ok(Ok(restaurant)) = registry.try_get_restaurant(&restaurant_id) {
+            if &restaurant.owner == caller {
+                return Ok(());
+            }
+        }
+        match registry.try_has_permission(&restaurant_id, caller, &permission) {
+            Ok(Ok(true)) => Ok(()),
+            _ => Err(Error::Unauthorized),
+        }
+    }
+
+    fn append_to_list(env: &Env, key: DataKey, id: u64) {
+        let mut list: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| vec![env]);
+        list.push_back(id);
+        env.storage().persistent().set(&key, &list);
+        extend_persistent(env, &key);
+    }
+
+    fn remove_from_list(env: &Env, key: DataKey, id: u64) {
+        let mut list: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| vec![env]);
+        if let Some(index) = list.first_index_of(id) {
+            list.remove(index);
+        }
+        env.storage().persistent().set(&key, &list);
+        extend_persistent(env, &key);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::events::{
+        assert_last_event, ContractMigrated, OrderLimitsSet, OrderModified, PaymentContractSet,
+    };
+    use ::payment::{PaymentContract, PaymentContractClient};
+    use restaurant_registry::{
+        OpeningHours, OpeningPeriod, ProfileUpdate, RestaurantRegistry, RestaurantRegistryClient,
+        StaffRole, SuspensionReason,
+    };
+    use soroban_sdk::testutils::{storage::Persistent as _, Address as _, Ledger};
+    use soroban_sdk::{token, vec, BytesN, Env, String};
+
+    /// Monday 2024-01-01 00:00:00 UTC.
+    const MONDAY: u64 = 1_704_067_200;
+    const HOUR: u64 = 3_600;
+
+    fn make_item(env: &Env, id: u64, qty: u32, price: i128) -> OrderItem {
+        OrderItem {
+            menu_item_id: id,
+            name: String::from_str(env, ""),
+            quantity: qty,
+            unit_price: price,
+        }
+    }
+
+    fn pickup() -> Fulfilment {
+        Fulfilment {
+            channel: FulfilmentChannel::Pickup,
+            table_id: None,
+        }
+    }
+
+    /// Deploy a registry with one restaurant whose menu is:
+    /// 1. Jollof Rice – 5_000_000
+    /// 2. Suya        – 3_000_000
+    /// 3. Chapman     – 7_000_000
+    fn setup() -> (
+        Env,
+        OrderContractClient<'static>,
+        RestaurantRegistryClient<'static>,
+        Address,
+        u64,
+    ) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+
+        let registry_id = env.register(RestaurantRegistry, ());
+        let registry = RestaurantRegistryClient::new(&env, &registry_id);
+        registry.initialize(&admin);
+        let restaurant_id = registry.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "mamas-kitchen"),
+        );
+        for (name, price) in [
+            ("Jollof Rice", 5_000_000),
+            ("Suya", 3_000_000),
+            ("Chapman", 7_000_000),
+        ] {
+            registry.add_menu_item(
+                &owner,
+                &restaurant_id,
+                &String::from_str(&env, name),
+                &price,
+                &token,
+            );
+        }
+
+        let cid = env.register(OrderContract, ());
+        let client = OrderContractClient::new(&env, &cid);
+        client.initialize(&admin, &registry_id);
+        (env, client, registry, admin, restaurant_id)
+    }
+
+    #[test]
+    fn test_place_and_get_order() {
+        let (env, client, registry, _admin, rid) = setup();
+        let customer = Address::generate(&env);
+        let token = registry.get_menu_item(&rid, &1).token;
+
+        let items = vec![&env, make_item(&env, 1, 2, 5_000_000)]; // 2 × 0.5 XLM
+        let id = client.place_order(
+            &customer,
+            &rid,
+            &items,
+            &String::from_str(&env, "No onions please"),
+            &None,
+            &pickup(),
+        );
+        assert_last_event(
+            &env,
+            &client.address,
+            OrderPlaced {
+                order_id: id,
+                restaurant_id: rid,
+                customer,
+                token,
+                total_amount: 10_000_000,
+                scheduled_for: None,
+                fulfilment: pickup(),
+            },
+        );
+
+        assert_eq!(id, 1);
+        let order = client.get_order(&id);
+        assert_eq!(order.total_amount, 10_000_000);
+        assert_eq!(order.status, OrderStatus::Pending);
+        assert_eq!(
+            order.items.get(0).unwrap().name,
+            String::from_str(&env, "Jollof Rice")
+        );
+    }
+
+    #[test]
+    fn test_advance_status() {
+        let (env, client, _registry, admin, rid) = setup();
+        let customer = Address::generate(&env);
+
+        let items = vec![&env, make_item(&env, 3, 1, 7_000_000)];
+        let id = client.place_order(
+            &customer,
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &None,
+            &pickup(),
+        );
+
+        client.advance_status(&admin, &id);
+        assert_eq!(client.get_order(&id).status, OrderStatus::Confirmed);
+
+        client.advance_status(&admin, &id);
+        assert_eq!(client.get_order(&id).status, OrderStatus::Preparing);
+
+        client.advance_status(&admin, &id);
+        assert_eq!(client.get_order(&id).status, OrderStatus::Ready);
+
+        client.advance_status(&admin, &id);
+        assert_last_event(
+            &env,
+            &client.address,
+            OrderAdvanced {
+                order_id: id,
+                status: OrderStatus::Delivered,
+            },
+        );
+        assert_eq!(client.get_order(&id).status, OrderStatus::Delivered);
+    }
+
+    #[test]
+    fn test_restaurant_owner_and_staff_advance_status() {
+        let (env, client, registry, _admin, rid) = setup();
+        let owner = registry.get_restaurant(&rid).owner;
+        let kitchen = Address::generate(&env);
+        let cashier = Address::generate(&env);
+        registry.grant_role(&owner, &rid, &kitchen, &StaffRole::Kitchen);
+        registry.grant_role(&owner, &rid, &cashier, &StaffRole::Cashier);
+
+        let items = vec![&env, make_item(&env, 1, 1, 5_000_000)];
+        let id = client.place_order(
+            &Address::generate(&env),
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &None,
+            &pickup(),
+        );
+
+        // Each role may only take the steps it is permitted.
+        assert_eq!(
+            client.try_advance_status(&kitchen, &id),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.advance_status(&cashier, &id);
+        assert_eq!(
+            client.try_advance_status(&cashier, &id),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.advance_status(&kitchen, &id);
+        client.advance_status(&kitchen, &id);
+        client.advance_status(&owner, &id);
+        assert_last_event(
+            &env,
+            &client.address,
+            OrderAdvanced {
+                order_id: id,
+                status: OrderStatus::Delivered,
+            },
+        );
+        assert_eq!(client.get_order(&id).status, OrderStatus::Delivered);
+    }
+
+    #[test]
+    fn test_other_restaurants_cannot_advance_status() {
+        let (env, client, registry, _admin, rid) = setup();
+        let other_owner = Address::generate(&env);
+        let other_id = registry.register_restaurant(
+            &other_owner,
+            &String::from_str(&env, "Suya Spot"),
+            &String::from_str(&env, "suya-spot"),
+        );
+        let other_manager = Address::generate(&env);
+        registry.grant_role(&other_owner, &other_id, &other_manager, &StaffRole::Manager);
+
+        let items = vec![&env, make_item(&env, 1, 1, 5_000_000)];
+        let id = client.place_order(
+            &Address::generate(&env),
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &None,
+            &pickup(),
+        );
+
+        for caller in [other_owner, other_manager, Address::generate(&env)] {
+            assert_eq!(
+                client.try_advance_status(&caller, &id),
+                Err(Ok(Error::Unauthorized))
+            );
+        }
+        assert_eq!(client.get_order(&id).status, OrderStatus::Pending);
+    }
+
+    #[test]
+    fn test_modify_order() {
+        let (env, client, _registry, _admin, rid) = setup();
+        let customer = Address::generate(&env);
+        env.ledger().set_timestamp(MONDAY);
+
+        let items = vec![
+            &env,
+            make_item(&env, 1, 2, 5_000_000),
+            make_item(&env, 2, 1, 3_000_000),
+        ];
+        let id = client.place_order(
+            &customer,
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &None,
+            &pickup(),
+        );
+        assert_eq!(client.get_order(&id).revision, 0);
+
+        env.ledger().set_timestamp(MONDAY + HOUR);
+        let changes = vec![
+            &env,
+            ItemChange::Remove(2),
+            ItemChange::SetQuantity(1, 1),
+            ItemChange::Add(make_item(&env, 3, 2, 7_000_000)),
+            ItemChange::Add(make_item(&env, 1, 1, 5_000_000)),
+        ];
+        assert_eq!(client.modify_order(&customer, &id, &changes), 1);
+        assert_last_event(
+            &env,
+            &client.address,
+            OrderModified {
+                order_id: id,
+                revision: 1,
+                total_amount: 24_000_000,
+            },
+        );
+
+        let order = client.get_order(&id);
+        assert_eq!(order.revision, 1);
+        assert_eq!(order.total_amount, 24_000_000);
+        assert_eq!(order.updated_at, MONDAY + HOUR);
+        assert_eq!(order.items.len(), 2);
+        let added = order.items.get(1).unwrap();
+        assert_eq!(added.name, String::from_str(&env, "Chapman"));
+        assert_eq!(added.quantity, 2);
+        assert_eq!(order.items.get(0).unwrap().quantity, 2);
+
+        let changes = vec![&env, ItemChange::SetQuantity(3, 1)];
+        assert_eq!(client.modify_order(&customer, &id, &changes), 2);
+        assert_eq!(client.get_order(&id).total_amount, 17_000_000);
+    }
+
+    #[test]
+    fn test_modify_order_rejected() {
+        let (env, client, _registry, admin, rid) = setup();
+        let customer = Address::generate(&env);
+
+        let items = vec![&env, make_item(&env, 1, 1, 5_000_000)];
+        let id = client.place_order(
+            &customer,
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &None,
+            &pickup(),
+        );
+
+        let add = vec![&env, ItemChange::Add(make_item(&env, 2, 1, 3_000_000))];
+        assert_eq!(
+            client.try_modify_order(&Address::generate(&env), &id, &add),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(
+            client.try_modify_order(&customer, &id, &vec![&env]),
+            Err(Ok(Error::NoChanges))
+        );
+        assert_eq!(
+            client.try_modify_order(&customer, &id, &vec![&env, ItemChange::Remove(2)]),
+            Err(Ok(Error::MenuItemNotFound))
+        );
+        assert_eq!(
+            client.try_modify_order(&customer, &id, &vec![&env, ItemChange::Remove(1)]),
+            Err(Ok(Error::EmptyOrder))
+        );
+        assert_eq!(
+            client.try_modify_order(
+                &customer,
+                &id,
+                &vec![&env, ItemChange::Add(make_item(&env, 2, 1, 1))]
+            ),
+            Err(Ok(Error::PriceMismatch))
+        );
+        assert_eq!(
+            client.try_modify_order(&customer, &id, &vec![&env, ItemChange::SetQuantity(1, 0)]),
+            Err(Ok(Error::InvalidQuantity))
+        );
+        assert_eq!(client.get_order(&id).revision, 0);
+
+        client.advance_status(&admin, &id);
+        assert_eq!(
+            client.try_modify_order(&customer, &id, &add),
+            Err(Ok(Error::NotPending))
+        );
+    }
+
+    #[test]
+    fn test_modify_after_escrow_rejected() {
+        let (env, client, registry, admin, rid) = setup();
+        let customer = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        let payments = PaymentContractClient::new(&env, &env.register(PaymentContract, ()));
+        payments.initialize(&admin, &Address::generate(&env), &0, &registry.address);
+        payments.set_order_contract(&admin, &client.address);
+        client.set_payment_contract(&admin, &payments.address);
+        assert_last_event(
+            &env,
+            &client.address,
+            PaymentContractSet {
+                payment_contract: payments.address.clone(),
+            },
+        );
+
+        let items = vec![&env, make_item(&env, 1, 1, 5_000_000)];
+        let id = client.place_order(
+            &customer,
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &None,
+            &pickup(),
+        );
+        let add = vec![&env, ItemChange::Add(make_item(&env, 2, 1, 3_000_000))];
+        assert_eq!(client.modify_order(&customer, &id, &add), 1);
+
+        let token = registry.get_menu_item(&rid, &1).token;
+        let minter = token::StellarAssetClient::new(&env, &token);
+        minter.mint(&customer, &8_000_000);
+        minter.mint(&stranger, &8_000_000);
+
+        // Someone else cannot freeze the order by paying for it.
+        assert!(payments
+            .try_escrow_payment(&stranger, &id, &token, &8_000_000)
+            .is_err());
+        assert_eq!(client.modify_order(&customer, &id, &add), 2);
+        let undo = vec![&env, ItemChange::SetQuantity(2, 1)];
+        assert_eq!(client.modify_order(&customer, &id, &undo), 3);
+
+        payments.escrow_payment(&customer, &id, &token, &8_000_000);
+        assert_eq!(payments.get_payment(&id).order_revision, 3);
+
+        assert_eq!(
+            client.try_modify_order(&customer, &id, &add),
+            Err(Ok(Error::AlreadyEscrowed))
+        );
+        let order = client.get_order(&id);
+        assert_eq!(order.revision, 3);
+        assert_eq!(order.total_amount, payments.get_payment(&id).amount);
+
+        // A refunded payment no longer freezes the order.
+        payments.refund_payment(&admin, &id);
+        assert_eq!(client.modify_order(&customer, &id, &add), 4);
+    }
+
+    #[test]
+    fn test_order_history() {
+        let (env, client, registry, admin, rid) = setup();
+        let owner = registry.get_restaurant(&rid).owner;
+        let customer = Address::generate(&env);
+        env.ledger().set_timestamp(MONDAY);
+
+        let items = vec![&env, make_item(&env, 1, 1, 5_000_000)];
+        let id = client.place_order(
+            &customer,
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &None,
+            &pickup(),
+        );
+        assert_eq!(client.get_order_history(&id, &0, &10), vec![&env]);
+
+        client.advance_status(&owner, &id);
+        env.ledger().set_timestamp(MONDAY + HOUR);
+        let reason = Some(Symbol::new(&env, "customer_dispute"));
+        client.set_status(&admin, &id, &OrderStatus::Pending, &reason);
+        assert_eq!(
+            client.try_set_status(&admin, &id, &OrderStatus::Pending, &None),
+            Err(Ok(Error::StatusUnchanged))
+        );
+        client.cancel_order(&customer, &id, &None);
+
+        let history = client.get_order_history(&id, &0, &10);
+        assert_eq!(
+            history,
+            vec![
+                &env,
+                StatusChange {
+                    from: OrderStatus::Pending,
+                    to: OrderStatus::Confirmed,
+                    actor: owner,
+                    at: MONDAY,
+                    reason: None,
+                },
+                StatusChange {
+                    from: OrderStatus::Confirmed,
+                    to: OrderStatus::Pending,
+                    actor: admin,
+                    at: MONDAY + HOUR,
+                    reason,
+                },
+                StatusChange {
+                    from: OrderStatus::Pending,
+                    to: OrderStatus::Cancelled,
+                    actor: customer,
+                    at: MONDAY + HOUR,
+                    reason: None,
+                },
+            ]
+        );
+
+        // Pages follow on from each other.
+        assert_eq!(client.get_order_history(&id, &0, &2).len(), 2);
+        assert_eq!(
+            client.get_order_history(&id, &2, &2),
+            vec![&env, history.get(2).unwrap()]
+        );
+        assert_eq!(client.get_order_history(&id, &3, &2), vec![&env]);
+        assert_eq!(
+            client.try_get_order_history(&99, &0, &10),
+            Err(Ok(Error::OrderNotFound))
+        );
+    }
+
+    #[test]
+    fn test_failed_transition_leaves_no_history() {
+        let (env, client, _registry, _admin, rid) = setup();
+        let customer = Address::generate(&env);
+
+        let items = vec![&env, make_item(&env, 1, 1, 5_000_000)];
+        let id = client.place_order(
+            &customer,
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &None,
+            &pickup(),
+        );
+        assert_eq!(
+            client.try_advance_status(&customer, &id),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.cancel_order(&customer, &id, &None);
+        assert_eq!(
+            client.try_cancel_order(&customer, &id, &None),
+            Err(Ok(Error::AlreadyCancelled))
+        );
+        assert_eq!(client.get_order_history(&id, &0, &10).len(), 1);
+    }
+
+    #[test]
+    fn test_customer_cancel_pending() {
+        let (env, client, _registry, _admin, rid) = setup();
+        let customer = Address::generate(&env);
+
+        let items = vec![&env, make_item(&env, 2, 1, 3_000_000)];
+        let id = client.place_order(
+            &customer,
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &None,
+            &pickup(),
+        );
+
+        client.cancel_order(&customer, &id, &None);
+        assert_last_event(
+            &env,
+            &client.address,
+            OrderCancelled {
+                order_id: id,
+                cancelled_by: customer,
+            },
+        );
+        assert_eq!(client.get_order(&id).status, OrderStatus::Cancelled);
+    }
+
+    #[test]
+    fn test_cashier_can_cancel_confirmed() {
+        let (env, client, registry, admin, rid) = setup();
+        let owner = registry.get_restaurant(&rid).owner;
+        let cashier = Address::generate(&env);
+        let cook = Address::generate(&env);
+        registry.grant_role(&owner, &rid, &cashier, &StaffRole::Cashier);
+        registry.grant_role(&owner, &rid, &cook, &StaffRole::Kitchen);
+
+        let items = vec![&env, make_item(&env, 1, 1, 5_000_000)];
+        let id = client.place_order(
+            &Address::generate(&env),
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &None,
+            &pickup(),
+        );
+        client.advance_status(&admin, &id);
+
+        assert_eq!(
+            client.try_cancel_order(&cook, &id, &None),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.cancel_order(&cashier, &id, &None);
+        assert_eq!(client.get_order(&id).status, OrderStatus::Cancelled);
+    }
+
+    #[test]
+    fn test_customer_cannot_cancel_confirmed() {
+        let (env, client, _registry, admin, rid) = setup();
+        let customer = Address::generate(&env);
+
+        let items = vec![&env, make_item(&env, 1, 1, 5_000_000)];
+        let id = client.place_order(
+            &customer,
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &None,
+            &pickup(),
+        );
+        client.advance_status(&admin, &id);
+        assert_eq!(
+            client.try_cancel_order(&customer, &id, &None),
+            Err(Ok(Error::NotPending))
+        );
+    }
+
+    #[test]
+    fn test_get_restaurant_orders() {
+        let (env, client, _registry, _admin, rid) = setup();
         let customer = Address::generate(&env);
-        client.initialize(&admin);
 
         let items = vec![&env, make_item(&env, 1, 1, 5_000_000)];
-        client.place_order(&customer, &7, &items.clone(), &String::from_str(&env, ""));
-        client.place_order(&customer, &7, &items, &String::from_str(&env, ""));
+        client.place_order(
+            &customer,
+            &rid,
+            &items.clone(),
+            &String::from_str(&env, ""),
+            &None,
+            &pickup(),
+        );
+        client.place_order(
+            &customer,
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &None,
+            &pickup(),
+        );
 
-        let orders = client.get_restaurant_orders(&7);
+        let orders = client.get_restaurant_orders(&rid);
         assert_eq!(orders.len(), 2);
     }
+
+    #[test]
+    fn test_customer_price_rejected() {
+        let (env, client, _registry, _admin, rid) = setup();
+        let customer = Address::generate(&env);
+
+        let items = vec![&env, make_item(&env, 1, 10, 1)];
+        assert_eq!(
+            client.try_place_order(
+                &customer,
+                &rid,
+                &items,
+                &String::from_str(&env, ""),
+                &None,
+                &pickup(),
+            ),
+            Err(Ok(Error::PriceMismatch))
+        );
+    }
+
+    #[test]
+    fn test_unknown_menu_item_rejected() {
+        let (env, client, _registry, _admin, rid) = setup();
+        let customer = Address::generate(&env);
+
+        let items = vec![&env, make_item(&env, 99, 1, 5_000_000)];
+        assert_eq!(
+            client.try_place_order(
+                &customer,
+                &rid,
+                &items,
+                &String::from_str(&env, ""),
+                &None,
+                &pickup(),
+            ),
+            Err(Ok(Error::MenuItemNotFound))
+        );
+    }
+
+    #[test]
+    fn test_unavailable_menu_item_rejected() {
+        let (env, client, registry, admin, rid) = setup();
+        let customer = Address::generate(&env);
+
+        registry.set_menu_item_available(&admin, &rid, &2, &false);
+        let items = vec![&env, make_item(&env, 2, 1, 3_000_000)];
+        assert_eq!(
+            client.try_place_order(
+                &customer,
+                &rid,
+                &items,
+                &String::from_str(&env, ""),
+                &None,
+                &pickup(),
+            ),
+            Err(Ok(Error::MenuItemUnavailable))
+        );
+    }
+
+    #[test]
+    fn test_dropped_token_rejected() {
+        let (env, client, registry, admin, rid) = setup();
+        let customer = Address::generate(&env);
+        let items = vec![&env, make_item(&env, 1, 1, 5_000_000)];
+        let id = client.place_order(
+            &customer,
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &None,
+            &pickup(),
+        );
+
+        // The restaurant stops accepting the menu's token without touching
+        // the menu.
+        registry.update_profile(
+            &admin,
+            &rid,
+            &0,
+            &ProfileUpdate {
+                payout_wallet: registry.get_payout_wallet(&rid),
+                accepted_tokens: vec![&env, Address::generate(&env)],
+                cuisine_tags: vec![&env],
+                geohash: String::from_str(&env, ""),
+                contact_hash: None,
+            },
+        );
+
+        assert_eq!(
+            client.try_place_order(
+                &customer,
+                &rid,
+                &items,
+                &String::from_str(&env, ""),
+                &None,
+                &pickup(),
+            ),
+            Err(Ok(Error::TokenNotAccepted))
+        );
+        let add = vec![&env, ItemChange::Add(make_item(&env, 2, 1, 3_000_000))];
+        assert_eq!(
+            client.try_modify_order(&customer, &id, &add),
+            Err(Ok(Error::TokenNotAccepted))
+        );
+    }
+
+    /// Weekdays 09:00–17:00 UTC.
+    fn set_office_hours(env: &Env, registry: &RestaurantRegistryClient, admin: &Address, rid: u64) {
+        let mut periods = vec![env];
+        for day in 0..5 {
+            periods.push_back(OpeningPeriod {
+                day,
+                open_minute: 9 * 60,
+                close_minute: 17 * 60,
+            });
+        }
+        registry.set_opening_hours(
+            admin,
+            &rid,
+            &OpeningHours {
+                utc_offset: 0,
+                periods,
+                closures: vec![env],
+            },
+        );
+    }
+
+    #[test]
+    fn test_order_refused_outside_opening_hours() {
+        let (env, client, registry, admin, rid) = setup();
+        let customer = Address::generate(&env);
+        set_office_hours(&env, &registry, &admin, rid);
+
+        env.ledger().set_timestamp(MONDAY + 20 * HOUR);
+        let items = vec![&env, make_item(&env, 1, 1, 5_000_000)];
+        assert_eq!(
+            client.try_place_order(
+                &customer,
+                &rid,
+                &items,
+                &String::from_str(&env, ""),
+                &None,
+                &pickup(),
+            ),
+            Err(Ok(Error::RestaurantClosed))
+        );
+    }
+
+    #[test]
+    fn test_scheduled_order_checked_against_scheduled_time() {
+        let (env, client, registry, admin, rid) = setup();
+        let customer = Address::generate(&env);
+        set_office_hours(&env, &registry, &admin, rid);
+
+        // Closed now, but open at the requested time the next morning.
+        env.ledger().set_timestamp(MONDAY + 20 * HOUR);
+        let items = vec![&env, make_item(&env, 1, 1, 5_000_000)];
+        let tomorrow = MONDAY + 34 * HOUR;
+        let id = client.place_order(
+            &customer,
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &Some(tomorrow),
+            &pickup(),
+        );
+        assert_eq!(client.get_order(&id).scheduled_for, Some(tomorrow));
+
+        // Saturday is closed.
+        let saturday = MONDAY + 5 * 24 * HOUR + 12 * HOUR;
+        assert_eq!(
+            client.try_place_order(
+                &customer,
+                &rid,
+                &items,
+                &String::from_str(&env, ""),
+                &Some(saturday),
+                &pickup(),
+            ),
+            Err(Ok(Error::RestaurantClosed))
+        );
+    }
+
+    #[test]
+    fn test_suspended_restaurant_refuses_orders() {
+        let (env, client, registry, admin, rid) = setup();
+        let customer = Address::generate(&env);
+
+        registry.suspend_restaurant(&admin, &rid, &SuspensionReason::Fraud, &None);
+        let items = vec![&env, make_item(&env, 1, 1, 5_000_000)];
+        assert_eq!(
+            client.try_place_order(
+                &customer,
+                &rid,
+                &items,
+                &String::from_str(&env, ""),
+                &None,
+                &pickup(),
+            ),
+            Err(Ok(Error::RestaurantSuspended))
+        );
+    }
+
+    #[test]
+    fn test_restaurant_status_checked() {
+        let (env, client, registry, admin, rid) = setup();
+        let customer = Address::generate(&env);
+        let items = vec![&env, make_item(&env, 1, 1, 5_000_000)];
+        let place = |restaurant_id: u64| {
+            client.try_place_order(
+                &customer,
+                &restaurant_id,
+                &items,
+                &String::from_str(&env, ""),
+                &None,
+                &pickup(),
+            )
+        };
+
+        assert_eq!(place(42), Err(Ok(Error::RestaurantNotFound)));
+
+        registry.set_active(&admin, &rid, &false);
+        assert_eq!(place(rid), Err(Ok(Error::RestaurantInactive)));
+        registry.set_active(&admin, &rid, &true);
+
+        registry.suspend_restaurant(&admin, &rid, &SuspensionReason::Fraud, &None);
+        assert_eq!(place(rid), Err(Ok(Error::RestaurantSuspended)));
+        registry.lift_suspension(&admin, &rid);
+
+        assert_eq!(place(rid), Ok(Ok(1)));
+        client.cancel_order(&admin, &1, &None);
+
+        let payments = PaymentContractClient::new(&env, &env.register(PaymentContract, ()));
+        payments.initialize(&admin, &Address::generate(&env), &0, &registry.address);
+        payments.set_order_contract(&admin, &client.address);
+        registry.set_order_contract(&admin, &client.address);
+        registry.set_payment_contract(&admin, &payments.address);
+        registry.close_restaurant(&admin, &rid);
+        assert_eq!(place(rid), Err(Ok(Error::RestaurantPermanentlyClosed)));
+    }
+
+    #[test]
+    fn test_min_verification_enforced() {
+        let (env, client, registry, admin, rid) = setup();
+        let customer = Address::generate(&env);
+        let attester = Address::generate(&env);
+        let items = vec![&env, make_item(&env, 1, 1, 5_000_000)];
+
+        client.set_min_verification(&admin, &VerificationLevel::Identity);
+        assert_eq!(
+            client.try_place_order(
+                &customer,
+                &rid,
+                &items,
+                &String::from_str(&env, ""),
+                &None,
+                &pickup(),
+            ),
+            Err(Ok(Error::InsufficientVerification))
+        );
+
+        registry.add_attester(&admin, &attester);
+        registry.attest(
+            &attester,
+            &rid,
+            &restaurant_registry::VerificationLevel::Identity,
+            &BytesN::from_array(&env, &[1; 32]),
+            &1_000,
+        );
+        client.place_order(
+            &customer,
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &None,
+            &pickup(),
+        );
+    }
+
+    #[test]
+    fn test_admin_transfer() {
+        let (env, client, _registry, admin, _rid) = setup();
+        let new_admin = Address::generate(&env);
+
+        client.propose_admin_transfer(&admin, &new_admin);
+        assert_last_event(
+            &env,
+            &client.address,
+            AdminTransferProposed {
+                admin: admin.clone(),
+                new_admin: new_admin.clone(),
+            },
+        );
+        assert_eq!(client.pending_admin(), Some(new_admin.clone()));
+
+        client.accept_admin_transfer(&new_admin);
+        assert_last_event(
+            &env,
+            &client.address,
+            AdminTransferAccepted {
+                previous_admin: admin,
+                new_admin: new_admin.clone(),
+            },
+        );
+        assert_eq!(client.admin(), new_admin);
+        assert_eq!(client.pending_admin(), None);
+    }
+
+    #[test]
+    fn test_cancelled_admin_transfer_cannot_be_accepted() {
+        let (env, client, _registry, admin, _rid) = setup();
+        let new_admin = Address::generate(&env);
+
+        client.propose_admin_transfer(&admin, &new_admin);
+        client.cancel_admin_transfer(&admin);
+        assert_eq!(
+            client.try_accept_admin_transfer(&new_admin),
+            Err(Ok(Error::NoPendingAdmin))
+        );
+    }
+
+    #[test]
+    fn test_migrate_version_1_order() {
+        let (env, client, _registry, admin, rid) = setup();
+        let customer = Address::generate(&env);
+        let token = Address::generate(&env);
+
+        // Simulate orders written by a version-1 deployment.
+        env.as_contract(&client.address, || {
+            let storage = env.storage();
+            storage.instance().remove(&DataKey::SchemaVersion);
+            storage.instance().set(&DataKey::Count, &2u64);
+            for id in [1, 2] {
+                storage.persistent().set(
+                    &DataKey::Order(id),
+                    &OrderV1 {
+                        id,
+                        restaurant_id: rid,
+                        customer: customer.clone(),
+                        items: vec![&env, make_item(&env, 1, 2, 5_000_000)],
+                        total_amount: 10_000_000,
+                        status: OrderStatus::Confirmed,
+                        created_at: 0,
+                        updated_at: 0,
+                        notes: String::from_str(&env, ""),
+                    },
+                );
+            }
+        });
+        assert_eq!(client.schema_version(), 1);
+
+        // A partial batch advances the cursor but leaves the version alone.
+        assert_eq!(client.migrate(&admin, &1, &token), 1);
+        assert_last_event(
+            &env,
+            &client.address,
+            ContractMigrated {
+                schema_version: 1,
+                migrated: 1,
+            },
+        );
+        assert_eq!(client.schema_version(), 1);
+        assert_eq!(client.migration_cursor(), Some(2));
+
+        assert_eq!(client.migrate(&admin, &10, &token), 1);
+        assert_eq!(client.schema_version(), SCHEMA_VERSION);
+        assert_eq!(client.migration_cursor(), None);
+        assert_eq!(client.get_order(&2).token, token);
+        let order = client.get_order(&1);
+        assert_eq!(order.token, token);
+        assert_eq!(order.scheduled_for, None);
+        assert_eq!(order.status, OrderStatus::Confirmed);
+
+        // Migrating current data is a no-op.
+        assert_eq!(client.migrate(&admin, &10, &token), 0);
+    }
+
+    #[test]
+    fn test_only_admin_upgrades() {
+        let (env, client, _registry, _admin, _rid) = setup();
+        let rando = Address::generate(&env);
+        assert_eq!(
+            client.try_upgrade(&rando, &BytesN::from_array(&env, &[0; 32])),
+            Err(Ok(Error::NotAdmin))
+        );
+    }
+
+    #[test]
+    fn test_bump_order_reports_ttl() {
+        let (env, client, _registry, admin, rid) = setup();
+        let customer = Address::generate(&env);
+        let id = client.place_order(
+            &customer,
+            &rid,
+            &vec![&env, make_item(&env, 1, 1, 5_000_000)],
+            &String::from_str(&env, ""),
+            &None,
+            &pickup(),
+        );
+        let full = TtlPolicy::DEFAULT.persistent_extend_to;
+        assert_eq!(client.order_ttl(&id), full);
+
+        client.advance_status(&admin, &id);
+
+        env.ledger().with_mut(|l| l.sequence_number += 1_000);
+        assert_eq!(client.order_ttl(&id), full - 1_000);
+        assert_eq!(client.bump_order(&id), full);
+        assert_eq!(client.order_ttl(&id), full);
+        env.as_contract(&client.address, || {
+            let storage = env.storage().persistent();
+            assert_eq!(storage.get_ttl(&DataKey::HistoryCount(id)), full);
+            assert_eq!(storage.get_ttl(&DataKey::History(id, 0)), full);
+        });
+
+        let policy = TtlPolicy {
+            persistent_threshold: full + 1,
+            ..TtlPolicy::DEFAULT
+        };
+        assert_eq!(
+            client.try_set_ttl_policy(&admin, &policy),
+            Err(Ok(Error::InvalidTtlPolicy))
+        );
+    }
+
+    #[test]
+    fn test_dine_in_order_tracked_by_table() {
+        let (env, client, registry, admin, rid) = setup();
+        let customer = Address::generate(&env);
+        let table = registry.add_table(&admin, &rid, &String::from_str(&env, "Table 1"));
+        let items = vec![&env, make_item(&env, 2, 1, 3_000_000)];
+
+        let first = client.place_order(
+            &customer,
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &None,
+            &Fulfilment {
+                channel: FulfilmentChannel::DineIn,
+                table_id: Some(table),
+            },
+        );
+        let second = client.place_order(
+            &customer,
+            &rid,
+            &items,
+            &String::from_str(&env, ""),
+            &None,
+            &Fulfilment {
+                channel: FulfilmentChannel::DineIn,
+                table_id: Some(table),
+            },
+        );
+        let order = client.get_order(&first);
+        assert_eq!(order.fulfilment.channel, FulfilmentChannel::DineIn);
+        assert_eq!(order.fulfilment.table_id, Some(table));
+        assert_eq!(
+            client.get_table_orders(&rid, &table),
+            vec![&env, first, second]
+        );
+
+        assert_eq!(client.get_open_orders(&rid), vec![&env, first, second]);
+
+        // Finished orders leave the open lists.
+        client.set_status(&admin, &first, &OrderStatus::Delivered, &None);
+        client.cancel_order(&customer, &second, &None);
+        assert_eq!(client.get_table_orders(&rid, &table), vec![&env]);
+        assert_eq!(client.get_open_orders(&rid), vec![&env]);
+    }
+
+    #[test]
+    fn test_table_checked_against_channel_and_registry() {
+        let (env, client, registry, admin, rid) = setup();
+        let customer = Address::generate(&env);
+        let table = registry.add_table(&admin, &rid, &String::from_str(&env, "Table 1"));
+        let items = vec![&env, make_item(&env, 2, 1, 3_000_000)];
+        let place = |channel: FulfilmentChannel, table_id: Option<u64>| {
+            client.try_place_order(
+                &customer,
+                &rid,
+                &items,
+                &String::from_str(&env, ""),
+                &None,
+                &Fulfilment { channel, table_id },
+            )
+        };
+
+        assert_eq!(
+            place(FulfilmentChannel::DineIn, None),
+            Err(Ok(Error::TableRequired))
+        );
+        assert_eq!(
+            place(FulfilmentChannel::Delivery, Some(table)),
+            Err(Ok(Error::TableNotAllowed))
+        );
+        assert_eq!(
+            place(FulfilmentChannel::DineIn, Some(99)),
+            Err(Ok(Error::TableNotFound))
+        );
+        registry.set_table_active(&admin, &rid, &table, &false);
+        assert_eq!(
+            place(FulfilmentChannel::DineIn, Some(table)),
+            Err(Ok(Error::TableInactive))
+        );
+    }
+
+    #[test]
+    fn test_migrate_version_2_order() {
+        let (env, client, _registry, admin, rid) = setup();
+        let token = Address::generate(&env);
+
+        // Simulate an order written by a version-2 deployment.
+        env.as_contract(&client.address, || {
+            let storage = env.storage();
+            storage.instance().set(&DataKey::SchemaVersion, &2u32);
+            storage.instance().set(&DataKey::Count, &1u64);
+            storage.persistent().set(
+                &DataKey::Order(1),
+                &OrderV2 {
+                    id: 1,
+                    restaurant_id: rid,
+                    customer: Address::generate(&env),
+                    items: vec![&env, make_item(&env, 1, 2, 5_000_000)],
+                    token: token.clone(),
+                    total_amount: 10_000_000,
+                    status: OrderStatus::Pending,
+                    created_at: 0,
+                    updated_at: 0,
+                    notes: String::from_str(&env, ""),
+                    scheduled_for: Some(HOUR),
+                },
+            );
+        });
+
+        assert_eq!(client.migrate(&admin, &10, &Address::generate(&env)), 1);
+        assert_eq!(client.schema_version(), SCHEMA_VERSION);
+        let order = client.get_order(&1);
+        assert_eq!(order.token, token);
+        assert_eq!(order.scheduled_for, Some(HOUR));
+        assert_eq!(order.fulfilment.channel, FulfilmentChannel::Delivery);
+        assert_eq!(order.fulfilment.table_id, None);
+        assert_eq!(client.migrate(&admin, &10, &token), 0);
+    }
+
+    #[test]
+    fn test_order_limits_enforced() {
+        let (env, client, _registry, admin, rid) = setup();
+        let customer = Address::generate(&env);
+        let place = |items: Vec<OrderItem>| {
+            client.try_place_order(
+                &customer,
+                &rid,
+                &items,
+                &String::from_str(&env, ""),
+                &None,
+                &pickup(),
+            )
+        };
+
+        assert_eq!(client.order_limits(), OrderLimits::DEFAULT);
+        let limits = OrderLimits {
+            max_items: 2,
+            max_quantity: 5,
+            max_total: 20_000_000,
+        };
+        client.set_order_limits(&admin, &limits);
+        assert_last_event(&env, &client.address, OrderLimitsSet { limits });
+
+        let item = make_item(&env, 1, 1, 5_000_000);
+        assert_eq!(
+            place(vec![&env, item.clone(), item.clone(), item.clone()]),
+            Err(Ok(Error::TooManyItems))
+        );
+        assert_eq!(
+            place(vec![&env, make_item(&env, 1, 6, 5_000_000)]),
+            Err(Ok(Error::QuantityTooLarge))
+        );
+        assert_eq!(
+            place(vec![&env, make_item(&env, 1, 5, 5_000_000)]),
+            Err(Ok(Error::TotalTooLarge))
+        );
+        assert_eq!(
+            place(vec![&env, make_item(&env, 1, 4, 5_000_000)]),
+            Ok(Ok(1))
+        );
+
+        assert_eq!(
+            client.try_set_order_limits(
+                &admin,
+                &OrderLimits {
+                    max_total: 0,
+                    ..limits
+                }
+            ),
+            Err(Ok(Error::InvalidOrderLimits))
+        );
+        assert_eq!(
+            client.try_set_order_limits(&Address::generate(&env), &limits),
+            Err(Ok(Error::NotAdmin))
+        );
+    }
+
+    #[test]
+    fn test_order_total_overflow() {
+        let (env, client, registry, _admin, rid) = setup();
+        let owner = registry.get_restaurant(&rid).owner;
+        let token = registry.get_menu_item(&rid, &1).token;
+        let item_id = registry.add_menu_item(
+            &owner,
+            &rid,
+            &String::from_str(&env, "Gold Leaf Platter"),
+            &i128::MAX,
+            &token,
+        );
+
+        assert_eq!(
+            client.try_place_order(
+                &Address::generate(&env),
+                &rid,
+                &vec![&env, make_item(&env, item_id, 2, i128::MAX)],
+                &String::from_str(&env, ""),
+                &None,
+                &pickup(),
+            ),
+            Err(Ok(Error::Overflow))
+        );
+    }
+
+    #[test]
+    fn test_migrate_version_3_order() {
+        let (env, client, _registry, admin, rid) = setup();
+        let token = Address::generate(&env);
+
+        // Simulate an order written by a version-3 deployment.
+        env.as_contract(&client.address, || {
+            let storage = env.storage();
+            storage.instance().set(&DataKey::SchemaVersion, &3u32);
+            storage.instance().set(&DataKey::Count, &1u64);
+            storage.persistent().set(
+                &DataKey::Order(1),
+                &OrderV3 {
+                    id: 1,
+                    restaurant_id: rid,
+                    customer: Address::generate(&env),
+                    items: vec![&env, make_item(&env, 1, 2, 5_000_000)],
+                    token: token.clone(),
+                    total_amount: 10_000_000,
+                    status: OrderStatus::Pending,
+                    created_at: 0,
+                    updated_at: 0,
+                    notes: String::from_str(&env, ""),
+                    scheduled_for: None,
+                    fulfilment: pickup(),
+                },
+            );
+        });
+
+        assert_eq!(client.migrate(&admin, &10, &token), 1);
+        assert_eq!(client.schema_version(), SCHEMA_VERSION);
+        let order = client.get_order(&1);
+        assert_eq!(order.revision, 0);
+        assert_eq!(order.fulfilment, pickup());
+        assert_eq!(client.migrate(&admin, &10, &token), 0);
+    }
 }
@@ -0,0 +1,43 @@
+//! # Order limits
+//!
+//! Bounds on the size of a single order, set by the admin. They keep one
+//! `place_order` call within the resource budget and keep absurd totals out
+//! of storage.
+
+use soroban_sdk::{contracttype, Env};
+
+use crate::DataKey;
+
+/// Upper bounds enforced by `place_order`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrderLimits {
+    /// Most line items one order may hold.
+    pub max_items: u32,
+    /// Most portions one line item may order.
+    pub max_quantity: u32,
+    /// Highest order total, in the token's smallest unit.
+    pub max_total: i128,
+}
+
+impl OrderLimits {
+    /// 50 line items of at most 100 portions, totalling at most 10^15
+    /// (100 million XLM in stroops).
+    pub const DEFAULT: OrderLimits = OrderLimits {
+        max_items: 50,
+        max_quantity: 100,
+        max_total: 1_000_000_000_000_000,
+    };
+
+    /// Whether every bound is positive.
+    pub(crate) fn is_valid(&self) -> bool {
+        self.max_items > 0 && self.max_quantity > 0 && self.max_total > 0
+    }
+}
+
+pub(crate) fn limits(env: &Env) -> OrderLimits {
+    env.storage()
+        .instance()
+        .get(&DataKey::OrderLimits)
+        .unwrap_or(OrderLimits::DEFAULT)
+}
@@ -0,0 +1,38 @@
+//! Client interface for the Payment contract.
+//!
+//! Only the entry points the Order contract calls are declared here. The
+//! mirrored types must keep the same name and field layout as their
+//! counterparts in `payment` so that they decode identically.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env};
+
+/// Mirror of `payment::PaymentStatus`.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum PaymentStatus {
+    Escrowed,
+    Released,
+    Refunded,
+}
+
+/// Mirror of `payment::Payment`.
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub struct Payment {
+    pub order_id: u64,
+    pub order_revision: u32,
+    pub restaurant_id: u64,
+    pub payer: Address,
+    pub restaurant_wallet: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub fee_amount: i128,
+    pub status: PaymentStatus,
+    pub created_at: u64,
+    pub settled_at: u64,
+}
+
+#[contractclient(name = "PaymentClient")]
+pub trait PaymentInterface {
+    fn get_payment(env: Env, order_id: u64) -> Payment;
+}
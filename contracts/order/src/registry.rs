@@ -0,0 +1,87 @@
+//! Client interface for the Restaurant Registry contract.
+//!
+//! Only the entry points the Order contract calls are declared here. The
+//! mirrored types must keep the same name and field layout as their
+//! counterparts in `restaurant_registry` so that they decode identically.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, String};
+
+/// Mirror of `restaurant_registry::MenuItem`.
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub struct MenuItem {
+    pub id: u64,
+    pub restaurant_id: u64,
+    pub name: String,
+    pub price: i128,
+    pub token: Address,
+    pub is_available: bool,
+}
+
+/// Mirror of `restaurant_registry::Restaurant`.
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub struct Restaurant {
+    pub id: u64,
+    pub owner: Address,
+    pub brand_id: u64,
+    pub name: String,
+    pub slug: String,
+    pub is_active: bool,
+    pub created_at: u64,
+}
+
+/// Mirror of `restaurant_registry::Permission`.
+#[contracttype(export = false)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Permission {
+    ManageMenu,
+    ConfirmOrder,
+    PrepareOrder,
+    ReadyOrder,
+    DeliverOrder,
+    CancelOrder,
+    RefundPayment,
+}
+
+/// Mirror of `restaurant_registry::Table`.
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub struct Table {
+    pub id: u64,
+    pub restaurant_id: u64,
+    pub label: String,
+    pub is_active: bool,
+}
+
+/// Mirror of `restaurant_registry::VerificationLevel`.
+#[contracttype(export = false)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VerificationLevel {
+    Unverified = 0,
+    Identity = 1,
+    FoodLicense = 2,
+}
+
+/// Mirror of `restaurant_registry::RestaurantStatus`.
+#[contracttype(export = false)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RestaurantStatus {
+    Active,
+    Inactive,
+    Suspended,
+    Closed,
+}
+
+#[contractclient(name = "RegistryClient")]
+pub trait RestaurantRegistryInterface {
+    fn accepts_token(env: Env, restaurant_id: u64, token: Address) -> bool;
+    fn get_menu_item(env: Env, restaurant_id: u64, item_id: u64) -> MenuItem;
+    fn get_restaurant(env: Env, restaurant_id: u64) -> Restaurant;
+    fn get_status(env: Env, restaurant_id: u64) -> RestaurantStatus;
+    fn get_table(env: Env, restaurant_id: u64, table_id: u64) -> Table;
+    fn has_permission(env: Env, restaurant_id: u64, addr: Address, permission: Permission) -> bool;
+    fn is_open_at(env: Env, restaurant_id: u64, timestamp: u64) -> bool;
+    fn verification_level(env: Env, restaurant_id: u64) -> VerificationLevel;
+}
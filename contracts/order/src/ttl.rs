@@ -0,0 +1,49 @@
+//! # Storage TTL
+//!
+//! Every write extends the entries it touches according to the contract's
+//! [`TtlPolicy`], which the admin may tune. The policy and the extension
+//! logic live in the shared `storage-ttl` crate; this module binds them to
+//! the order records.
+
+use soroban_sdk::Env;
+pub use storage_ttl::{TtlPolicy, DAY_IN_LEDGERS};
+
+use crate::DataKey;
+
+pub(crate) fn policy(env: &Env) -> TtlPolicy {
+    storage_ttl::policy(env, &DataKey::TtlPolicy)
+}
+
+/// Extend the contract instance according to the policy.
+pub(crate) fn extend_instance(env: &Env) {
+    storage_ttl::extend_instance(env, &policy(env));
+}
+
+/// Extend a persistent entry according to the policy.
+pub(crate) fn extend_persistent(env: &Env, key: &DataKey) {
+    storage_ttl::extend_persistent(env, &policy(env), key);
+}
+
+/// Extend an order record, its status history, and record until when they
+/// live.
+///
+/// Returns the record's remaining TTL.
+pub(crate) fn extend_order(env: &Env, order_id: u64) -> u32 {
+    let live_until_key = DataKey::LiveUntil(order_id);
+    storage_ttl::extend_tracked(env, &policy(env), &live_until_key, |extend_to| {
+        let storage = env.storage().persistent();
+        storage.extend_ttl(&DataKey::Order(order_id), extend_to, extend_to);
+        let count_key = DataKey::HistoryCount(order_id);
+        if let Some(count) = storage.get::<_, u32>(&count_key) {
+            storage.extend_ttl(&count_key, extend_to, extend_to);
+            for index in 0..count {
+                storage.extend_ttl(&DataKey::History(order_id, index), extend_to, extend_to);
+            }
+        }
+    })
+}
+
+/// Remaining TTL recorded for an order record; `0` if none was recorded.
+pub(crate) fn remaining_ttl(env: &Env, order_id: u64) -> u32 {
+    storage_ttl::remaining_ttl(env, &DataKey::LiveUntil(order_id))
+}
@@ -0,0 +1,63 @@
+use soroban_sdk::contracterror;
+
+/// Error codes returned by the payment contract.
+///
+/// Codes are part of the contract's public interface: never renumber an
+/// existing variant, only append new ones.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    // Access control and lifecycle.
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    /// Caller is neither the admin nor the payment's restaurant wallet.
+    Unauthorized = 3,
+    /// Only the contract admin may perform this action.
+    NotAdmin = 4,
+    NoPendingAdmin = 5,
+
+    // Payments.
+    PaymentNotFound = 6,
+    /// A payment already exists for this order.
+    PaymentExists = 7,
+    /// Amount is zero or negative.
+    InvalidAmount = 8,
+    /// Fee exceeds [`crate::MAX_FEE_BPS`].
+    FeeTooHigh = 9,
+    /// Payment has already been released or refunded.
+    NotEscrowed = 10,
+
+    // Registry checks.
+    RestaurantNotFound = 11,
+    TokenNotAccepted = 12,
+    /// Restaurant is below the configured minimum verification level.
+    InsufficientVerification = 13,
+
+    // Storage TTL.
+    /// A TTL threshold exceeds its extension, or an extension is zero or
+    /// above the network maximum.
+    InvalidTtlPolicy = 14,
+
+    // Restaurant status.
+    /// Restaurant has been closed for good in the registry.
+    RestaurantClosed = 15,
+    /// Restaurant is suspended by the registry admin.
+    RestaurantSuspended = 16,
+
+    // Order checks.
+    /// No Order contract has been set.
+    OrderContractNotSet = 17,
+    /// The Order contract has no such order.
+    OrderNotFound = 18,
+    /// Payer is not the order's customer.
+    NotOrderCustomer = 19,
+    /// Token differs from the order's token.
+    TokenMismatch = 20,
+    /// Amount differs from the order's total.
+    AmountMismatch = 21,
+    /// The order has been modified since the payment was escrowed.
+    RevisionMismatch = 22,
+    /// The order has not been delivered, so its payment cannot be released.
+    OrderNotDelivered = 23,
+}
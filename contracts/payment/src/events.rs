@@ -0,0 +1,212 @@
+//! # Events
+//!
+//! Every state change publishes exactly one event. Its topics are
+//! `(action, subject, EVENT_VERSION)` and its data is one of the structs
+//! below, encoded as a map keyed by field name. Indexers should match on
+//! all three topics and decode the data as the struct for that version.
+//!
+//! | Action     | Subject    | Data                       |
+//! |------------|------------|----------------------------|
+//! | `init`     | `contract` | [`Initialized`]            |
+//! | `escrowed` | `pay`      | [`PaymentEscrowed`]        |
+//! | `released` | `pay`      | [`PaymentReleased`]        |
+//! | `refunded` | `pay`      | [`PaymentRefunded`]        |
+//! | `setfee`   | `pay`      | [`FeeSet`]                 |
+//! | `minverif` | `pay`      | [`MinVerificationSet`]     |
+//! | `order`    | `contract` | [`OrderContractSet`]       |
+//! | `ttl`      | `policy`   | [`TtlPolicySet`]           |
+//! | `propose`  | `admin`    | [`AdminTransferProposed`]  |
+//! | `accept`   | `admin`    | [`AdminTransferAccepted`]  |
+//! | `cancel`   | `admin`    | [`AdminTransferCancelled`] |
+//! | `upgrade`  | `contract` | [`ContractUpgraded`]       |
+//! | `migrate`  | `contract` | [`ContractMigrated`]       |
+
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Symbol};
+
+use crate::registry::VerificationLevel;
+use crate::TtlPolicy;
+
+#[cfg(test)]
+pub(crate) use contract_events::testutils::assert_last_event;
+pub(crate) use contract_events::Event;
+pub use contract_events::EVENT_VERSION;
+
+/// The contract was initialised.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Initialized {
+    pub admin: Address,
+    pub treasury: Address,
+    pub fee_bps: u32,
+    pub registry: Address,
+}
+
+impl Event for Initialized {
+    const ACTION: Symbol = symbol_short!("init");
+    const SUBJECT: Symbol = symbol_short!("contract");
+}
+
+/// A customer escrowed the payment for an order.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaymentEscrowed {
+    pub order_id: u64,
+    pub restaurant_id: u64,
+    pub payer: Address,
+    pub token: Address,
+    pub amount: i128,
+    /// Platform fee that will be withheld on release.
+    pub fee_amount: i128,
+}
+
+impl Event for PaymentEscrowed {
+    const ACTION: Symbol = symbol_short!("escrowed");
+    const SUBJECT: Symbol = symbol_short!("pay");
+}
+
+/// Escrowed funds were paid out to the restaurant.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaymentReleased {
+    pub order_id: u64,
+    pub restaurant_id: u64,
+    pub restaurant_wallet: Address,
+    /// Amount paid to the restaurant wallet.
+    pub net_amount: i128,
+    /// Amount paid to the treasury.
+    pub fee_amount: i128,
+}
+
+impl Event for PaymentReleased {
+    const ACTION: Symbol = symbol_short!("released");
+    const SUBJECT: Symbol = symbol_short!("pay");
+}
+
+/// Escrowed funds were returned to the customer.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaymentRefunded {
+    pub order_id: u64,
+    pub restaurant_id: u64,
+    pub payer: Address,
+    pub amount: i128,
+}
+
+impl Event for PaymentRefunded {
+    const ACTION: Symbol = symbol_short!("refunded");
+    const SUBJECT: Symbol = symbol_short!("pay");
+}
+
+/// The platform fee changed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeSet {
+    pub fee_bps: u32,
+}
+
+impl Event for FeeSet {
+    const ACTION: Symbol = symbol_short!("setfee");
+    const SUBJECT: Symbol = symbol_short!("pay");
+}
+
+/// The minimum verification level for receiving payments changed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinVerificationSet {
+    pub level: VerificationLevel,
+}
+
+impl Event for MinVerificationSet {
+    const ACTION: Symbol = symbol_short!("minverif");
+    const SUBJECT: Symbol = symbol_short!("pay");
+}
+
+/// The admin set the Order contract escrows are checked against.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderContractSet {
+    pub order_contract: Address,
+}
+
+impl Event for OrderContractSet {
+    const ACTION: Symbol = symbol_short!("order");
+    const SUBJECT: Symbol = symbol_short!("contract");
+}
+
+/// The TTL policy was replaced.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TtlPolicySet {
+    pub policy: TtlPolicy,
+}
+
+impl Event for TtlPolicySet {
+    const ACTION: Symbol = symbol_short!("ttl");
+    const SUBJECT: Symbol = symbol_short!("policy");
+}
+
+/// The admin proposed a successor.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminTransferProposed {
+    pub admin: Address,
+    pub new_admin: Address,
+}
+
+impl Event for AdminTransferProposed {
+    const ACTION: Symbol = symbol_short!("propose");
+    const SUBJECT: Symbol = symbol_short!("admin");
+}
+
+/// A proposed admin accepted the role.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminTransferAccepted {
+    pub previous_admin: Address,
+    pub new_admin: Address,
+}
+
+impl Event for AdminTransferAccepted {
+    const ACTION: Symbol = symbol_short!("accept");
+    const SUBJECT: Symbol = symbol_short!("admin");
+}
+
+/// The admin withdrew a pending proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminTransferCancelled {
+    pub admin: Address,
+}
+
+impl Event for AdminTransferCancelled {
+    const ACTION: Symbol = symbol_short!("cancel");
+    const SUBJECT: Symbol = symbol_short!("admin");
+}
+
+/// The contract code was replaced.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractUpgraded {
+    pub new_wasm_hash: BytesN<32>,
+}
+
+impl Event for ContractUpgraded {
+    const ACTION: Symbol = symbol_short!("upgrade");
+    const SUBJECT: Symbol = symbol_short!("contract");
+}
+
+/// A batch of legacy payments was migrated.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractMigrated {
+    /// Stored layout version after this batch; it only changes with the
+    /// final batch.
+    pub schema_version: u32,
+    /// Number of payments rewritten by this batch.
+    pub migrated: u32,
+}
+
+impl Event for ContractMigrated {
+    const ACTION: Symbol = symbol_short!("migrate");
+    const SUBJECT: Symbol = symbol_short!("contract");
+}
@@ -12,19 +12,74 @@
 //!     │                          │──── transfer to wallet ───►│
 //!     │                          │
 //!     │     OR (dispute / cancel)│
-//!     │◄── refund_payment() ─────│  (admin only)
+//!     │◄── refund_payment() ─────│  (admin or restaurant)
 //! ```
 //!
 //! Supports any SEP-41 token (XLM native wrapper, USDC, etc.).
 //!
 //! ## Roles
 //! - **Admin** – can release or refund any payment; set fee bps.
-//! - **Restaurant wallet** – may call `release_payment` for their own orders.
+//! - **Restaurant wallet** – the payout wallet from the restaurant's registry
+//!   profile, captured at escrow time; may release or refund their own
+//!   orders' payments.
+//! - **Restaurant owner / staff** – the owner, and staff granted
+//!   `RefundPayment` in the registry, may refund their restaurant's payments.
 //! - **Customer** – escrows funds; cannot self-release (prevents fraud).
+//!
+//! ## Order checks
+//! Escrows are checked against the Order contract set with
+//! `set_order_contract`: the order must exist, the payer must be its
+//! customer, and the token and amount must match the order; the order also
+//! names the restaurant paid. Each payment records the order revision it
+//! covers, and is only released once the order is delivered and while it
+//! is still at that revision.
+//!
+//! ## Restaurant status
+//! Escrows for restaurants the registry reports as suspended or closed are
+//! refused.
+//!
+//! ## Verification
+//! The admin may require a minimum registry [`VerificationLevel`]; escrows
+//! for restaurants below it are refused.
+//!
+//! ## Events
+//! Every state change publishes a typed, versioned event; see [`events`].
+//!
+//! ## Storage TTL
+//! Writes extend entries according to an admin-set [`TtlPolicy`]. Anyone can
+//! keep an escrow alive with `bump_payment`, and `payment_ttl` reports how
+//! long its record has left.
+//!
+//! ## Upgrades
+//! The admin can replace the contract code with `upgrade`. Stored payments
+//! carry a [`SCHEMA_VERSION`]; after an upgrade that changes their layout,
+//! `migrate` rewrites legacy payments in batches.
 
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, Address, Env};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, token, Address, BytesN, Env, Map, Symbol, TryFromVal, Val,
+};
+
+mod error;
+pub mod events;
+pub mod orders;
+pub mod registry;
+mod ttl;
+
+pub use error::Error;
+use events::{
+    AdminTransferAccepted, AdminTransferCancelled, AdminTransferProposed, ContractMigrated,
+    ContractUpgraded, Event, FeeSet, Initialized, MinVerificationSet, OrderContractSet,
+    PaymentEscrowed, PaymentRefunded, PaymentReleased, TtlPolicySet,
+};
+use orders::{OrderClient, OrderStatus, OrderSummary};
+use registry::{Permission, RegistryClient, RestaurantStatus, VerificationLevel};
+use ttl::{extend_instance, extend_payment, extend_persistent};
+pub use ttl::{TtlPolicy, DAY_IN_LEDGERS};
+
+/// Highest platform fee, in basis points (10 %).
+pub const MAX_FEE_BPS: u32 = 1_000;
 
 // ---------------------------------------------------------------------------
 // Types
@@ -48,9 +103,13 @@ pub enum PaymentStatus {
 pub struct Payment {
     /// Matches the order ID from the Order contract.
     pub order_id: u64,
+    /// Revision of the order the payment covers.
+    pub order_revision: u32,
+    /// Restaurant the order was placed with (registry ID).
+    pub restaurant_id: u64,
     /// Customer who escrowed the funds.
     pub payer: Address,
-    /// Restaurant's receiving wallet.
+    /// Restaurant's receiving wallet, taken from its registry profile.
     pub restaurant_wallet: Address,
     /// SEP-41 token contract address (use XLM native wrapper or USDC).
     pub token: Address,
@@ -70,11 +129,76 @@ pub struct Payment {
 #[contracttype]
 pub enum DataKey {
     Admin,
+    /// Proposed next admin awaiting `accept_admin_transfer`.
+    PendingAdmin,
+    /// Address of the Restaurant Registry contract.
+    Registry,
+    /// Address of the Order contract escrows are checked against.
+    OrderContract,
+    /// Minimum verification level a restaurant needs to be paid.
+    MinVerification,
     /// Treasury address that receives platform fees.
     Treasury,
     /// Fee in basis points (100 bps = 1 %). Default: 100 (1 %).
     FeeBps,
     Payment(u64),
+    /// Layout version of the stored records.
+    SchemaVersion,
+    /// Order ID after the highest one `migrate` has examined, while a
+    /// migration is in progress.
+    MigrationCursor,
+    /// TTL policy set by the admin.
+    TtlPolicy,
+    /// Ledger until which a payment record is known to live.
+    LiveUntil(u64),
+    /// Number of a restaurant's payments held in escrow.
+    EscrowCount(u64),
+}
+
+// ---------------------------------------------------------------------------
+// Legacy layouts
+// ---------------------------------------------------------------------------
+
+/// Layout version of the records written by this code.
+///
+/// - **1** – original layout: `Payment` without `restaurant_id`.
+/// - **2** – `Payment` without `order_revision`.
+/// - **3** – current layout.
+pub const SCHEMA_VERSION: u32 = 3;
+
+/// Order revision recorded on migrated payments that no longer cover their
+/// order. Orders never reach it, so such payments can only be refunded.
+pub const STALE_REVISION: u32 = u32::MAX;
+
+/// `Payment` as stored by schema version 1.
+#[contracttype]
+#[derive(Clone)]
+struct PaymentV1 {
+    order_id: u64,
+    payer: Address,
+    restaurant_wallet: Address,
+    token: Address,
+    amount: i128,
+    fee_amount: i128,
+    status: PaymentStatus,
+    created_at: u64,
+    settled_at: u64,
+}
+
+/// `Payment` as stored by schema version 2.
+#[contracttype]
+#[derive(Clone)]
+struct PaymentV2 {
+    order_id: u64,
+    restaurant_id: u64,
+    payer: Address,
+    restaurant_wallet: Address,
+    token: Address,
+    amount: i128,
+    fee_amount: i128,
+    status: PaymentStatus,
+    created_at: u64,
+    settled_at: u64,
 }
 
 // ---------------------------------------------------------------------------
@@ -96,18 +220,37 @@ impl PaymentContract {
     /// - `admin`    – full-control address (platform operator).
     /// - `treasury` – wallet that receives platform fees.
     /// - `fee_bps`  – platform fee in basis points (e.g. 100 = 1 %).
-    pub fn initialize(env: Env, admin: Address, treasury: Address, fee_bps: u32) {
+    /// - `registry` – Restaurant Registry contract holding payout wallets.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        treasury: Address,
+        fee_bps: u32,
+        registry: Address,
+    ) -> Result<(), Error> {
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            return Err(Error::AlreadyInitialized);
         }
-        if fee_bps > 1_000 {
-            // cap at 10 %
-            panic!("fee cannot exceed 1000 bps");
+        if fee_bps > MAX_FEE_BPS {
+            return Err(Error::FeeTooHigh);
         }
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Treasury, &treasury);
         env.storage().instance().set(&DataKey::FeeBps, &fee_bps);
-        env.storage().instance().extend_ttl(17_280, 17_280);
+        env.storage().instance().set(&DataKey::Registry, &registry);
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        extend_instance(&env);
+
+        Initialized {
+            admin,
+            treasury,
+            fee_bps,
+            registry,
+        }
+        .publish(&env);
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
@@ -119,28 +262,65 @@ impl PaymentContract {
     /// The customer must approve this contract to spend `amount` of `token`
     /// before calling this function (standard SEP-41 allowance flow).
     ///
+    /// The order must exist in the Order contract, `payer` must be its
+    /// customer, and `token` and `amount` must match it. The payment goes to
+    /// the order's restaurant, whose receiving wallet is looked up from its
+    /// registry profile, and the token must be one the restaurant accepts. The
+    /// restaurant must not be suspended or closed, and must meet the
+    /// configured minimum verification level.
+    ///
     /// # Arguments
-    /// - `payer`              – customer wallet (must sign).
-    /// - `order_id`           – ID from the Order contract.
-    /// - `restaurant_wallet`  – receiving wallet of the restaurant.
-    /// - `token`              – SEP-41 token contract address.
-    /// - `amount`             – gross amount **before** platform fee deduction.
+    /// - `payer`          – customer wallet (must sign).
+    /// - `order_id`       – ID from the Order contract.
+    /// - `token`          – SEP-41 token contract address.
+    /// - `amount`         – gross amount **before** platform fee deduction;
+    ///                      must equal the order's total.
     pub fn escrow_payment(
         env: Env,
         payer: Address,
         order_id: u64,
-        restaurant_wallet: Address,
         token_address: Address,
         amount: i128,
-    ) {
+    ) -> Result<(), Error> {
         payer.require_auth();
 
         if env.storage().persistent().has(&DataKey::Payment(order_id)) {
-            panic!("payment already exists for this order");
+            return Err(Error::PaymentExists);
         }
         if amount <= 0 {
-            panic!("amount must be positive");
+            return Err(Error::InvalidAmount);
+        }
+
+        let order = Self::order_summary(&env, order_id)?;
+        if order.customer != payer {
+            return Err(Error::NotOrderCustomer);
+        }
+        if order.token != token_address {
+            return Err(Error::TokenMismatch);
+        }
+        if order.total_amount != amount {
+            return Err(Error::AmountMismatch);
+        }
+        let restaurant_id = order.restaurant_id;
+
+        let registry = Self::registry(&env)?;
+        Self::check_restaurant(&registry, restaurant_id)?;
+        let level = match registry.try_verification_level(&restaurant_id) {
+            Ok(Ok(level)) => level,
+            _ => return Err(Error::RestaurantNotFound),
+        };
+        if level < Self::min_verification(env.clone()) {
+            return Err(Error::InsufficientVerification);
+        }
+        match registry.try_accepts_token(&restaurant_id, &token_address) {
+            Ok(Ok(true)) => {}
+            Ok(Ok(false)) => return Err(Error::TokenNotAccepted),
+            _ => return Err(Error::RestaurantNotFound),
         }
+        let restaurant_wallet = match registry.try_get_payout_wallet(&restaurant_id) {
+            Ok(Ok(wallet)) => wallet,
+            _ => return Err(Error::RestaurantNotFound),
+        };
 
         let fee_bps: u32 = env.storage().instance().get(&DataKey::FeeBps).unwrap_or(0);
         let fee_amount: i128 = (amount * fee_bps as i128) / 10_000;
@@ -152,8 +332,10 @@ impl PaymentContract {
         let now = env.ledger().timestamp();
         let payment = Payment {
             order_id,
+            order_revision: order.revision,
+            restaurant_id,
             payer: payer.clone(),
-            restaurant_wallet: restaurant_wallet.clone(),
+            restaurant_wallet,
             token: token_address.clone(),
             amount,
             fee_amount,
@@ -162,24 +344,25 @@ impl PaymentContract {
             settled_at: 0,
         };
 
-        let ttl: u32 = 2_073_600;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Payment(order_id), &payment);
-        env.storage()
-            .persistent()
-            .extend_ttl(&DataKey::Payment(order_id), ttl, ttl);
+        Self::save_payment(&env, &payment);
+        Self::count_escrow(&env, restaurant_id, true);
 
-        env.storage().instance().extend_ttl(17_280, 17_280);
+        extend_instance(&env);
 
-        env.events().publish(
-            (symbol_short!("escrowed"), symbol_short!("pay")),
-            (order_id, payer, amount),
-        );
+        PaymentEscrowed {
+            order_id,
+            restaurant_id,
+            payer,
+            token: token_address,
+            amount,
+            fee_amount,
+        }
+        .publish(&env);
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
-    // Release / Refund (admin or restaurant wallet)
+    // Release / Refund (admin or restaurant)
     // -----------------------------------------------------------------------
 
     /// Release escrowed funds to the restaurant.
@@ -187,22 +370,33 @@ impl PaymentContract {
     /// Callable by the admin or the restaurant wallet recorded in the payment.
     /// The platform fee is sent to the treasury; the remainder goes to the
     /// restaurant wallet.
-    pub fn release_payment(env: Env, caller: Address, order_id: u64) {
+    ///
+    /// Fails with [`Error::RevisionMismatch`] if the order has been modified
+    /// since the payment was escrowed; such payments can only be refunded.
+    /// Fails with [`Error::OrderNotDelivered`] until the order is delivered,
+    /// so the payment of a cancelled order cannot be released either.
+    pub fn release_payment(env: Env, caller: Address, order_id: u64) -> Result<(), Error> {
         caller.require_auth();
 
         let mut payment: Payment = env
             .storage()
             .persistent()
             .get(&DataKey::Payment(order_id))
-            .unwrap_or_else(|| panic!("payment not found"));
+            .ok_or(Error::PaymentNotFound)?;
 
         if payment.status != PaymentStatus::Escrowed {
-            panic!("payment is not in escrow");
+            return Err(Error::NotEscrowed);
         }
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if caller != admin && caller != payment.restaurant_wallet {
-            panic!("unauthorized");
+        if caller != Self::admin(env.clone())? && caller != payment.restaurant_wallet {
+            return Err(Error::Unauthorized);
+        }
+        let order = Self::order_summary(&env, order_id)?;
+        if order.revision != payment.order_revision {
+            return Err(Error::RevisionMismatch);
+        }
+        if order.status != OrderStatus::Delivered {
+            return Err(Error::OrderNotDelivered);
         }
 
         let token_client = token::Client::new(&env, &payment.token);
@@ -217,7 +411,11 @@ impl PaymentContract {
 
         // Send fee to treasury.
         if payment.fee_amount > 0 {
-            let treasury: Address = env.storage().instance().get(&DataKey::Treasury).unwrap();
+            let treasury: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::Treasury)
+                .ok_or(Error::NotInitialized)?;
             token_client.transfer(
                 &env.current_contract_address(),
                 &treasury,
@@ -228,35 +426,40 @@ impl PaymentContract {
         payment.status = PaymentStatus::Released;
         payment.settled_at = env.ledger().timestamp();
 
-        let ttl: u32 = 2_073_600;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Payment(order_id), &payment);
-        env.storage()
-            .persistent()
-            .extend_ttl(&DataKey::Payment(order_id), ttl, ttl);
+        Self::save_payment(&env, &payment);
+        Self::count_escrow(&env, payment.restaurant_id, false);
 
-        env.events().publish(
-            (symbol_short!("released"), symbol_short!("pay")),
-            (order_id, net_amount),
-        );
+        PaymentReleased {
+            order_id,
+            restaurant_id: payment.restaurant_id,
+            restaurant_wallet: payment.restaurant_wallet,
+            net_amount,
+            fee_amount: payment.fee_amount,
+        }
+        .publish(&env);
+        Ok(())
     }
 
-    /// Refund the escrowed amount in full to the customer (admin only).
+    /// Refund the escrowed amount in full to the customer.
     ///
-    /// Used when an order is cancelled or disputed.
-    pub fn refund_payment(env: Env, caller: Address, order_id: u64) {
+    /// Callable by the admin, the restaurant wallet recorded in the payment,
+    /// the restaurant's owner, or its staff holding `RefundPayment`. Used
+    /// when an order is cancelled or disputed.
+    pub fn refund_payment(env: Env, caller: Address, order_id: u64) -> Result<(), Error> {
         caller.require_auth();
-        Self::assert_admin_or_panic(&env, &caller);
 
         let mut payment: Payment = env
             .storage()
             .persistent()
             .get(&DataKey::Payment(order_id))
-            .unwrap_or_else(|| panic!("payment not found"));
+            .ok_or(Error::PaymentNotFound)?;
 
         if payment.status != PaymentStatus::Escrowed {
-            panic!("payment is not in escrow");
+            return Err(Error::NotEscrowed);
+        }
+
+        if caller != Self::admin(env.clone())? && caller != payment.restaurant_wallet {
+            Self::assert_can_refund(&env, &caller, payment.restaurant_id)?;
         }
 
         let token_client = token::Client::new(&env, &payment.token);
@@ -271,18 +474,17 @@ impl PaymentContract {
         payment.status = PaymentStatus::Refunded;
         payment.settled_at = env.ledger().timestamp();
 
-        let ttl: u32 = 2_073_600;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Payment(order_id), &payment);
-        env.storage()
-            .persistent()
-            .extend_ttl(&DataKey::Payment(order_id), ttl, ttl);
+        Self::save_payment(&env, &payment);
+        Self::count_escrow(&env, payment.restaurant_id, false);
 
-        env.events().publish(
-            (symbol_short!("refunded"), symbol_short!("pay")),
-            (order_id, payment.amount),
-        );
+        PaymentRefunded {
+            order_id,
+            restaurant_id: payment.restaurant_id,
+            payer: payment.payer,
+            amount: payment.amount,
+        }
+        .publish(&env);
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
@@ -290,22 +492,254 @@ impl PaymentContract {
     // -----------------------------------------------------------------------
 
     /// Update the platform fee (admin only).
-    pub fn set_fee_bps(env: Env, caller: Address, fee_bps: u32) {
+    pub fn set_fee_bps(env: Env, caller: Address, fee_bps: u32) -> Result<(), Error> {
         caller.require_auth();
-        Self::assert_admin_or_panic(&env, &caller);
-        if fee_bps > 1_000 {
-            panic!("fee cannot exceed 1000 bps");
+        Self::assert_admin(&env, &caller)?;
+        if fee_bps > MAX_FEE_BPS {
+            return Err(Error::FeeTooHigh);
         }
         env.storage().instance().set(&DataKey::FeeBps, &fee_bps);
-        env.storage().instance().extend_ttl(17_280, 17_280);
+        extend_instance(&env);
+
+        FeeSet { fee_bps }.publish(&env);
+        Ok(())
+    }
+
+    /// Set the verification level restaurants need to receive payments
+    /// (admin only). Defaults to `Unverified`, i.e. no requirement.
+    pub fn set_min_verification(
+        env: Env,
+        caller: Address,
+        level: VerificationLevel,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::MinVerification, &level);
+        extend_instance(&env);
+
+        MinVerificationSet { level }.publish(&env);
+        Ok(())
+    }
+
+    /// Set the Order contract that escrows are checked against (admin
+    /// only). Until it is set, `escrow_payment` is refused.
+    pub fn set_order_contract(
+        env: Env,
+        caller: Address,
+        order_contract: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::OrderContract, &order_contract);
+        extend_instance(&env);
+
+        OrderContractSet { order_contract }.publish(&env);
+        Ok(())
     }
 
-    /// Transfer the admin role to a new address.
-    pub fn transfer_admin(env: Env, caller: Address, new_admin: Address) {
+    /// Propose `new_admin` as the next contract admin (admin only).
+    ///
+    /// The role only moves once `new_admin` calls `accept_admin_transfer`,
+    /// so a mistyped address cannot lock the contract. Replaces any pending
+    /// proposal.
+    pub fn propose_admin_transfer(
+        env: Env,
+        caller: Address,
+        new_admin: Address,
+    ) -> Result<(), Error> {
         caller.require_auth();
-        Self::assert_admin_or_panic(&env, &caller);
+        Self::assert_admin(&env, &caller)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &new_admin);
+        extend_instance(&env);
+
+        AdminTransferProposed {
+            admin: caller,
+            new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Accept a pending admin proposal and become the contract admin.
+    pub fn accept_admin_transfer(env: Env, new_admin: Address) -> Result<(), Error> {
+        new_admin.require_auth();
+
+        let pending: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(Error::NoPendingAdmin)?;
+        if pending != new_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        let previous = Self::admin(env.clone())?;
         env.storage().instance().set(&DataKey::Admin, &new_admin);
-        env.storage().instance().extend_ttl(17_280, 17_280);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        extend_instance(&env);
+
+        AdminTransferAccepted {
+            previous_admin: previous,
+            new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Withdraw a pending admin proposal (admin only).
+    pub fn cancel_admin_transfer(env: Env, caller: Address) -> Result<(), Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
+        if !env.storage().instance().has(&DataKey::PendingAdmin) {
+            return Err(Error::NoPendingAdmin);
+        }
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        AdminTransferCancelled { admin: caller }.publish(&env);
+        Ok(())
+    }
+
+    /// Replace the TTL policy (admin only).
+    ///
+    /// # Errors
+    /// [`Error::InvalidTtlPolicy`] if a threshold exceeds its extension, an
+    /// extension is zero, or an extension exceeds the network's maximum TTL.
+    pub fn set_ttl_policy(env: Env, caller: Address, policy: TtlPolicy) -> Result<(), Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
+        if !policy.is_valid(env.storage().max_ttl()) {
+            return Err(Error::InvalidTtlPolicy);
+        }
+        env.storage().instance().set(&DataKey::TtlPolicy, &policy);
+        extend_instance(&env);
+
+        TtlPolicySet { policy }.publish(&env);
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Storage TTL
+    // -----------------------------------------------------------------------
+
+    /// Extend a payment record and the contract instance. Anyone may call
+    /// this, e.g. to keep a long-lived escrow from being archived.
+    ///
+    /// # Returns
+    /// The payment record's remaining TTL, in ledgers.
+    pub fn bump_payment(env: Env, order_id: u64) -> Result<u32, Error> {
+        Self::get_payment(env.clone(), order_id)?;
+        let remaining = extend_payment(&env, order_id);
+        extend_instance(&env);
+        Ok(remaining)
+    }
+
+    /// Return the remaining TTL of a payment record, in ledgers, as last
+    /// recorded by this contract.
+    ///
+    /// Records not extended since TTL tracking was introduced report `0`
+    /// until they are next written or bumped.
+    pub fn payment_ttl(env: Env, order_id: u64) -> Result<u32, Error> {
+        Self::get_payment(env.clone(), order_id)?;
+        Ok(ttl::remaining_ttl(&env, order_id))
+    }
+
+    /// Return the TTL policy in force.
+    pub fn ttl_policy(env: Env) -> TtlPolicy {
+        ttl::policy(&env)
+    }
+
+    // -----------------------------------------------------------------------
+    // Upgrades
+    // -----------------------------------------------------------------------
+
+    /// Replace the contract code with the uploaded Wasm `new_wasm_hash`
+    /// (admin only).
+    ///
+    /// Storage is left untouched; call `migrate` afterwards if the new code
+    /// raises [`SCHEMA_VERSION`].
+    pub fn upgrade(env: Env, caller: Address, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        ContractUpgraded { new_wasm_hash }.publish(&env);
+        Ok(())
+    }
+
+    /// Rewrite the payments of the next `limit` order IDs from a legacy
+    /// layout into the current one (admin only).
+    ///
+    /// Legacy payments take their restaurant and order revision from the
+    /// Order contract. A payment whose amount or token no longer matches
+    /// its order, or whose order the Order contract does not know, records
+    /// [`STALE_REVISION`] and can only be refunded. Version 1 payments for
+    /// unknown orders record restaurant `0`. Orders without a payment or
+    /// whose payment is already current are skipped.
+    ///
+    /// Payments are keyed by order ID, so each call walks the Order
+    /// contract's IDs onwards from the migration cursor; the admin calls
+    /// this with batches small enough to fit the transaction budget until
+    /// `schema_version` reports [`SCHEMA_VERSION`]. The batch that passes
+    /// the last order records [`SCHEMA_VERSION`] as the stored version and
+    /// clears the cursor. Does nothing once the data is current.
+    ///
+    /// # Returns
+    /// The number of payments rewritten by this call.
+    ///
+    /// # Errors
+    /// [`Error::OrderContractNotSet`] if no Order contract is configured, or
+    /// it could not be queried.
+    pub fn migrate(env: Env, caller: Address, limit: u32) -> Result<u32, Error> {
+        caller.require_auth();
+        Self::assert_admin(&env, &caller)?;
+
+        if Self::schema_version(env.clone()) == SCHEMA_VERSION {
+            return Ok(0);
+        }
+
+        let count = match Self::order_client(&env)?.try_get_count() {
+            Ok(Ok(count)) => count,
+            _ => return Err(Error::OrderContractNotSet),
+        };
+        let instance = env.storage().instance();
+        let start: u64 = instance.get(&DataKey::MigrationCursor).unwrap_or(1);
+        let end = start.saturating_add(limit.into()).min(count + 1);
+
+        let mut migrated = 0u32;
+        for order_id in start..end {
+            if Self::migrate_payment(&env, order_id) {
+                migrated += 1;
+            }
+        }
+
+        if end > count {
+            instance.set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+            instance.remove(&DataKey::MigrationCursor);
+        } else {
+            instance.set(&DataKey::MigrationCursor, &end);
+        }
+        extend_instance(&env);
+
+        ContractMigrated {
+            schema_version: Self::schema_version(env.clone()),
+            migrated,
+        }
+        .publish(&env);
+        Ok(migrated)
+    }
+
+    /// Return the order ID after the highest one `migrate` has examined, or
+    /// `None` if no migration is in progress.
+    pub fn migration_cursor(env: Env) -> Option<u64> {
+        env.storage().instance().get(&DataKey::MigrationCursor)
     }
 
     // -----------------------------------------------------------------------
@@ -313,11 +747,21 @@ impl PaymentContract {
     // -----------------------------------------------------------------------
 
     /// Fetch a payment record.
-    pub fn get_payment(env: Env, order_id: u64) -> Payment {
+    pub fn get_payment(env: Env, order_id: u64) -> Result<Payment, Error> {
         env.storage()
             .persistent()
             .get(&DataKey::Payment(order_id))
-            .unwrap_or_else(|| panic!("payment not found"))
+            .ok_or(Error::PaymentNotFound)
+    }
+
+    /// Number of a restaurant's payments still held in escrow.
+    ///
+    /// The registry checks this before letting a restaurant close.
+    pub fn escrowed_count(env: Env, restaurant_id: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EscrowCount(restaurant_id))
+            .unwrap_or(0)
     }
 
     /// Current platform fee in basis points.
@@ -325,15 +769,187 @@ impl PaymentContract {
         env.storage().instance().get(&DataKey::FeeBps).unwrap_or(0)
     }
 
+    /// Return the Order contract escrows are checked against, if set.
+    pub fn order_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::OrderContract)
+    }
+
+    /// Minimum verification level required to receive payments.
+    pub fn min_verification(env: Env) -> VerificationLevel {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinVerification)
+            .unwrap_or(VerificationLevel::Unverified)
+    }
+
+    /// Return the admin address.
+    pub fn admin(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)
+    }
+
+    /// Return the proposed next admin, if a handover is in progress.
+    pub fn pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Return the layout version of the stored data.
+    ///
+    /// Deployments that predate versioning report `1`.
+    pub fn schema_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(1)
+    }
+
     // -----------------------------------------------------------------------
     // Helpers
     // -----------------------------------------------------------------------
 
-    fn assert_admin_or_panic(env: &Env, caller: &Address) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if caller != &admin {
-            panic!("unauthorized: admin only");
+    /// Rewrite one legacy payment. Returns `false` if there is nothing to
+    /// do.
+    fn migrate_payment(env: &Env, order_id: u64) -> bool {
+        let key = DataKey::Payment(order_id);
+        let Some(fields) = env.storage().persistent().get::<_, Map<Symbol, Val>>(&key) else {
+            return false;
+        };
+        if fields.contains_key(Symbol::new(env, "order_revision")) {
+            return false;
+        }
+        let order = Self::order_summary(env, order_id).ok();
+
+        let (mut payment, newly_counted) = if fields.contains_key(Symbol::new(env, "restaurant_id"))
+        {
+            let Ok(legacy) = PaymentV2::try_from_val(env, fields.as_val()) else {
+                return false;
+            };
+            let payment = Payment {
+                order_id: legacy.order_id,
+                order_revision: STALE_REVISION,
+                restaurant_id: legacy.restaurant_id,
+                payer: legacy.payer,
+                restaurant_wallet: legacy.restaurant_wallet,
+                token: legacy.token,
+                amount: legacy.amount,
+                fee_amount: legacy.fee_amount,
+                status: legacy.status,
+                created_at: legacy.created_at,
+                settled_at: legacy.settled_at,
+            };
+            // Version 2 already kept escrow counts.
+            (payment, false)
+        } else {
+            let Ok(legacy) = PaymentV1::try_from_val(env, fields.as_val()) else {
+                return false;
+            };
+            let payment = Payment {
+                order_id: legacy.order_id,
+                order_revision: STALE_REVISION,
+                restaurant_id: order.as_ref().map_or(0, |order| order.restaurant_id),
+                payer: legacy.payer,
+                restaurant_wallet: legacy.restaurant_wallet,
+                token: legacy.token,
+                amount: legacy.amount,
+                fee_amount: legacy.fee_amount,
+                status: legacy.status,
+                created_at: legacy.created_at,
+                settled_at: legacy.settled_at,
+            };
+            (payment, true)
+        };
+        // Only an escrow that still covers the whole order may be released.
+        if let Some(order) = order {
+            if order.total_amount == payment.amount && order.token == payment.token {
+                payment.order_revision = order.revision;
+            }
+        }
+
+        Self::save_payment(env, &payment);
+        if newly_counted && payment.status == PaymentStatus::Escrowed {
+            Self::count_escrow(env, payment.restaurant_id, true);
+        }
+        true
+    }
+
+    /// Fail unless the registry knows the restaurant and it can still take
+    /// payments.
+    fn check_restaurant(registry: &RegistryClient, restaurant_id: u64) -> Result<(), Error> {
+        match registry.try_get_status(&restaurant_id) {
+            Ok(Ok(RestaurantStatus::Suspended)) => Err(Error::RestaurantSuspended),
+            Ok(Ok(RestaurantStatus::Closed)) => Err(Error::RestaurantClosed),
+            Ok(Ok(_)) => Ok(()),
+            _ => Err(Error::RestaurantNotFound),
+        }
+    }
+
+    fn save_payment(env: &Env, payment: &Payment) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Payment(payment.order_id), payment);
+        extend_payment(env, payment.order_id);
+    }
+
+    /// Add one to, or take one from, a restaurant's escrow count.
+    fn count_escrow(env: &Env, restaurant_id: u64, escrowed: bool) {
+        let key = DataKey::EscrowCount(restaurant_id);
+        let count = Self::escrowed_count(env.clone(), restaurant_id);
+        let count = if escrowed {
+            count + 1
+        } else {
+            count.saturating_sub(1)
+        };
+        env.storage().persistent().set(&key, &count);
+        extend_persistent(env, &key);
+    }
+
+    /// Look an order up in the configured Order contract.
+    fn order_summary(env: &Env, order_id: u64) -> Result<OrderSummary, Error> {
+        match Self::order_client(env)?.try_get_order_summary(&order_id) {
+            Ok(Ok(order)) => Ok(order),
+            _ => Err(Error::OrderNotFound),
+        }
+    }
+
+    fn order_client(env: &Env) -> Result<OrderClient<'_>, Error> {
+        let address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::OrderContract)
+            .ok_or(Error::OrderContractNotSet)?;
+        Ok(OrderClient::new(env, &address))
+    }
+
+    fn registry(env: &Env) -> Result<RegistryClient<'_>, Error> {
+        let address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Registry)
+            .ok_or(Error::NotInitialized)?;
+        Ok(RegistryClient::new(env, &address))
+    }
+
+    /// Allow the restaurant's owner, or staff holding `RefundPayment` there.
+    fn assert_can_refund(env: &Env, caller: &Address, restaurant_id: u64) -> Result<(), Error> {
+        let registry = Self::registry(env)?;
+        if let Ok(Ok(restaurant)) = registry.try_get_restaurant(&restaurant_id) {
+            if &restaurant.owner == caller {
+                return Ok(());
+            }
+        }
+        match registry.try_has_permission(&restaurant_id, caller, &Permission::RefundPayment) {
+            Ok(Ok(true)) => Ok(()),
+            _ => Err(Error::Unauthorized),
+        }
+    }
+
+    fn assert_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+        if caller != &Self::admin(env.clone())? {
+            return Err(Error::NotAdmin);
         }
+        Ok(())
     }
 }
 
@@ -344,59 +960,152 @@ impl PaymentContract {
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::testutils::Address as _;
-    use soroban_sdk::{token, Env};
-
-    /// Helper: create a token contract and mint `amount` to `recipient`.
-    fn create_token<'a>(
-        env: &'a Env,
-        admin: &'a Address,
-    ) -> (Address, token::StellarAssetClient<'a>) {
-        let token_addr = env
-            .register_stellar_asset_contract_v2(admin.clone())
-            .address();
-        let sac = token::StellarAssetClient::new(env, &token_addr);
-        (token_addr, sac)
-    }
-
+    use crate::events::assert_last_event;
+    use order::{
+        Fulfilment, FulfilmentChannel, ItemChange, OrderContract, OrderContractClient, OrderItem,
+        OrderStatus,
+    };
+    use restaurant_registry::{
+        ProfileUpdate, RestaurantRegistry, RestaurantRegistryClient, StaffRole, SuspensionReason,
+    };
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{token, vec, BytesN, Env, String};
+
+    /// Price of the single menu item every test orders.
+    const PRICE: i128 = 10_000_000;
+
+    /// Deploy a registry, an order contract and a payment contract wired to
+    /// each other, with one restaurant selling one item priced at [`PRICE`]
+    /// in a fresh Stellar asset.
     fn setup() -> (
         Env,
         PaymentContractClient<'static>,
+        RestaurantRegistryClient<'static>,
+        OrderContractClient<'static>,
         Address,
         Address,
+        u64,
         Address,
     ) {
         let env = Env::default();
         env.mock_all_auths();
-        let cid = env.register(PaymentContract, ());
-        let client = PaymentContractClient::new(&env, &cid);
         let admin = Address::generate(&env);
         let treasury = Address::generate(&env);
-        client.initialize(&admin, &treasury, &100u32); // 1 % fee
-        (env, client, admin, treasury, cid)
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+
+        let registry_id = env.register(RestaurantRegistry, ());
+        let registry = RestaurantRegistryClient::new(&env, &registry_id);
+        registry.initialize(&admin);
+        let owner = Address::generate(&env);
+        let restaurant_id = registry.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "mamas-kitchen"),
+        );
+        registry.add_menu_item(
+            &owner,
+            &restaurant_id,
+            &String::from_str(&env, "Jollof Rice"),
+            &PRICE,
+            &token,
+        );
+
+        let orders = OrderContractClient::new(&env, &env.register(OrderContract, ()));
+        orders.initialize(&admin, &registry_id);
+
+        let cid = env.register(PaymentContract, ());
+        let client = PaymentContractClient::new(&env, &cid);
+        client.initialize(&admin, &treasury, &100u32, &registry_id); // 1 % fee
+        client.set_order_contract(&admin, &orders.address);
+        (
+            env,
+            client,
+            registry,
+            orders,
+            admin,
+            treasury,
+            restaurant_id,
+            token,
+        )
+    }
+
+    /// Place an order for `quantity` portions of the menu item, and mint
+    /// the customer enough to pay for it.
+    fn place(
+        env: &Env,
+        orders: &OrderContractClient,
+        customer: &Address,
+        restaurant_id: u64,
+        token: &Address,
+        quantity: u32,
+    ) -> u64 {
+        let item = OrderItem {
+            menu_item_id: 1,
+            name: String::from_str(env, ""),
+            quantity,
+            unit_price: PRICE,
+        };
+        token::StellarAssetClient::new(env, token).mint(customer, &(PRICE * quantity as i128));
+        orders.place_order(
+            customer,
+            &restaurant_id,
+            &vec![env, item],
+            &String::from_str(env, ""),
+            &None,
+            &Fulfilment {
+                channel: FulfilmentChannel::Pickup,
+                table_id: None,
+            },
+        )
     }
 
     #[test]
     fn test_escrow_and_release() {
-        let (env, client, admin, treasury, _contract_id) = setup();
-        let token_admin = Address::generate(&env);
+        let (env, client, registry, orders, admin, treasury, rid, token_addr) = setup();
         let payer = Address::generate(&env);
-        let restaurant = Address::generate(&env);
-
-        let (token_addr, sac) = create_token(&env, &token_admin);
-        // Mint 100 XLM (stroops) to payer.
-        sac.mint(&payer, &100_000_000);
+        let restaurant = registry.get_payout_wallet(&rid);
 
         let amount: i128 = 50_000_000; // 5 XLM
-        client.escrow_payment(&payer, &1, &restaurant, &token_addr, &amount);
+        let id = place(&env, &orders, &payer, rid, &token_addr, 5);
+        client.escrow_payment(&payer, &id, &token_addr, &amount);
+        assert_last_event(
+            &env,
+            &client.address,
+            PaymentEscrowed {
+                order_id: id,
+                restaurant_id: rid,
+                payer,
+                token: token_addr.clone(),
+                amount,
+                fee_amount: 500_000,
+            },
+        );
 
-        let payment = client.get_payment(&1);
+        let payment = client.get_payment(&id);
         assert_eq!(payment.status, PaymentStatus::Escrowed);
         assert_eq!(payment.amount, amount);
-
-        client.release_payment(&admin, &1);
-        let payment = client.get_payment(&1);
+        assert_eq!(payment.order_revision, 0);
+        assert_eq!(payment.restaurant_wallet, restaurant);
+        assert_eq!(client.escrowed_count(&rid), 1);
+
+        orders.set_status(&admin, &id, &OrderStatus::Delivered, &None);
+        client.release_payment(&admin, &id);
+        assert_last_event(
+            &env,
+            &client.address,
+            PaymentReleased {
+                order_id: id,
+                restaurant_id: rid,
+                restaurant_wallet: restaurant.clone(),
+                net_amount: 49_500_000,
+                fee_amount: 500_000,
+            },
+        );
+        let payment = client.get_payment(&id);
         assert_eq!(payment.status, PaymentStatus::Released);
+        assert_eq!(client.escrowed_count(&rid), 0);
 
         let token_client = token::Client::new(&env, &token_addr);
         // Restaurant receives 99 % of 5 XLM = 4.95 XLM = 49_500_000 stroops.
@@ -407,33 +1116,465 @@ mod test {
 
     #[test]
     fn test_refund() {
-        let (env, client, admin, _treasury, _contract_id) = setup();
-        let token_admin = Address::generate(&env);
+        let (env, client, _registry, orders, admin, _treasury, rid, token_addr) = setup();
         let payer = Address::generate(&env);
-        let restaurant = Address::generate(&env);
-
-        let (token_addr, sac) = create_token(&env, &token_admin);
-        sac.mint(&payer, &50_000_000);
 
-        client.escrow_payment(&payer, &2, &restaurant, &token_addr, &50_000_000);
-        client.refund_payment(&admin, &2);
+        let id = place(&env, &orders, &payer, rid, &token_addr, 5);
+        client.escrow_payment(&payer, &id, &token_addr, &50_000_000);
+        assert_eq!(client.escrowed_count(&rid), 1);
+        client.refund_payment(&admin, &id);
+        assert_last_event(
+            &env,
+            &client.address,
+            PaymentRefunded {
+                order_id: id,
+                restaurant_id: rid,
+                payer: payer.clone(),
+                amount: 50_000_000,
+            },
+        );
+        assert_eq!(client.escrowed_count(&rid), 0);
 
         let token_client = token::Client::new(&env, &token_addr);
         assert_eq!(token_client.balance(&payer), 50_000_000);
     }
 
     #[test]
-    #[should_panic(expected = "payment already exists for this order")]
-    fn test_double_escrow_panics() {
-        let (env, client, _admin, _treasury, _cid) = setup();
-        let token_admin = Address::generate(&env);
+    fn test_refund_requires_refund_permission() {
+        let (env, client, registry, orders, _admin, _treasury, rid, token_addr) = setup();
+        let payer = Address::generate(&env);
+        let owner = registry.get_restaurant(&rid).owner;
+        let manager = Address::generate(&env);
+        let cashier = Address::generate(&env);
+        registry.grant_role(&owner, &rid, &manager, &StaffRole::Manager);
+        registry.grant_role(&owner, &rid, &cashier, &StaffRole::Cashier);
+
+        let id = place(&env, &orders, &payer, rid, &token_addr, 5);
+        client.escrow_payment(&payer, &id, &token_addr, &50_000_000);
+
+        assert_eq!(
+            client.try_refund_payment(&cashier, &id),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(
+            client.try_refund_payment(&Address::generate(&env), &id),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.refund_payment(&manager, &id);
+        assert_eq!(client.get_payment(&id).status, PaymentStatus::Refunded);
+    }
+
+    #[test]
+    fn test_double_escrow_rejected() {
+        let (env, client, _registry, orders, _admin, _treasury, rid, token_addr) = setup();
+        let payer = Address::generate(&env);
+
+        let id = place(&env, &orders, &payer, rid, &token_addr, 2);
+        client.escrow_payment(&payer, &id, &token_addr, &20_000_000);
+        assert_eq!(
+            client.try_escrow_payment(&payer, &id, &token_addr, &20_000_000),
+            Err(Ok(Error::PaymentExists))
+        );
+    }
+
+    #[test]
+    fn test_escrow_checked_against_order() {
+        let (env, client, registry, orders, admin, _treasury, rid, token_addr) = setup();
+        let payer = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        let id = place(&env, &orders, &payer, rid, &token_addr, 1);
+        token::StellarAssetClient::new(&env, &token_addr).mint(&stranger, &PRICE);
+
+        assert_eq!(
+            client.try_escrow_payment(&stranger, &id, &token_addr, &PRICE),
+            Err(Ok(Error::NotOrderCustomer))
+        );
+        assert_eq!(
+            client.try_escrow_payment(&payer, &id, &Address::generate(&env), &PRICE),
+            Err(Ok(Error::TokenMismatch))
+        );
+        assert_eq!(
+            client.try_escrow_payment(&payer, &id, &token_addr, &1),
+            Err(Ok(Error::AmountMismatch))
+        );
+        // Order IDs are sequential, but unplaced ones cannot be paid for.
+        assert_eq!(
+            client.try_escrow_payment(&payer, &(id + 1), &token_addr, &PRICE),
+            Err(Ok(Error::OrderNotFound))
+        );
+        assert_eq!(client.escrowed_count(&rid), 0);
+
+        client.escrow_payment(&payer, &id, &token_addr, &PRICE);
+        assert_eq!(client.escrowed_count(&rid), 1);
+
+        let unlinked = PaymentContractClient::new(&env, &env.register(PaymentContract, ()));
+        unlinked.initialize(&admin, &admin, &0, &registry.address);
+        assert_eq!(unlinked.order_contract(), None);
+        assert_eq!(
+            unlinked.try_escrow_payment(&payer, &id, &token_addr, &PRICE),
+            Err(Ok(Error::OrderContractNotSet))
+        );
+        assert_eq!(
+            unlinked.try_set_order_contract(&payer, &orders.address),
+            Err(Ok(Error::NotAdmin))
+        );
+    }
+
+    #[test]
+    fn test_release_refused_after_order_modified() {
+        let (env, client, _registry, orders, admin, _treasury, rid, token_addr) = setup();
         let payer = Address::generate(&env);
-        let restaurant = Address::generate(&env);
 
-        let (token_addr, sac) = create_token(&env, &token_admin);
-        sac.mint(&payer, &100_000_000);
+        // The order contract is not linked to this payment contract, so it
+        // lets the customer modify the order after paying.
+        let id = place(&env, &orders, &payer, rid, &token_addr, 1);
+        client.escrow_payment(&payer, &id, &token_addr, &PRICE);
+        let item = OrderItem {
+            menu_item_id: 1,
+            name: String::from_str(&env, ""),
+            quantity: 1,
+            unit_price: PRICE,
+        };
+        orders.modify_order(&payer, &id, &vec![&env, ItemChange::Add(item)]);
+
+        assert_eq!(
+            client.try_release_payment(&admin, &id),
+            Err(Ok(Error::RevisionMismatch))
+        );
+        client.refund_payment(&admin, &id);
+        assert_eq!(client.get_payment(&id).status, PaymentStatus::Refunded);
+    }
 
-        client.escrow_payment(&payer, &3, &restaurant, &token_addr, &20_000_000);
-        client.escrow_payment(&payer, &3, &restaurant, &token_addr, &20_000_000);
+    #[test]
+    fn test_release_refused_until_delivered() {
+        let (env, client, registry, orders, _admin, _treasury, rid, token_addr) = setup();
+        let payer = Address::generate(&env);
+        let wallet = registry.get_payout_wallet(&rid);
+
+        let id = place(&env, &orders, &payer, rid, &token_addr, 1);
+        client.escrow_payment(&payer, &id, &token_addr, &PRICE);
+        assert_eq!(
+            client.try_release_payment(&wallet, &id),
+            Err(Ok(Error::OrderNotDelivered))
+        );
+
+        // The restaurant cancels the order; its escrow can then only be
+        // refunded.
+        let owner = registry.get_restaurant(&rid).owner;
+        orders.cancel_order(&owner, &id, &None);
+        assert_eq!(
+            client.try_release_payment(&wallet, &id),
+            Err(Ok(Error::OrderNotDelivered))
+        );
+        client.refund_payment(&wallet, &id);
+        assert_eq!(client.get_payment(&id).status, PaymentStatus::Refunded);
+    }
+
+    #[test]
+    fn test_escrow_pays_profile_wallet() {
+        let (env, client, registry, orders, admin, _treasury, rid, token_addr) = setup();
+        let payer = Address::generate(&env);
+        let wallet = Address::generate(&env);
+
+        registry.update_profile(
+            &admin,
+            &rid,
+            &0,
+            &ProfileUpdate {
+                payout_wallet: wallet.clone(),
+                accepted_tokens: vec![&env, token_addr.clone()],
+                cuisine_tags: vec![&env],
+                geohash: String::from_str(&env, ""),
+                contact_hash: None,
+            },
+        );
+
+        let id = place(&env, &orders, &payer, rid, &token_addr, 1);
+        client.escrow_payment(&payer, &id, &token_addr, &PRICE);
+        orders.set_status(&admin, &id, &OrderStatus::Delivered, &None);
+        client.release_payment(&wallet, &id);
+
+        let token_client = token::Client::new(&env, &token_addr);
+        assert_eq!(token_client.balance(&wallet), 9_900_000);
+    }
+
+    #[test]
+    fn test_unaccepted_token_rejected() {
+        let (env, client, registry, orders, admin, _treasury, rid, token_addr) = setup();
+        let payer = Address::generate(&env);
+
+        // The restaurant stops accepting the order's token after it was placed.
+        let id = place(&env, &orders, &payer, rid, &token_addr, 1);
+        registry.update_profile(
+            &admin,
+            &rid,
+            &0,
+            &ProfileUpdate {
+                payout_wallet: registry.get_payout_wallet(&rid),
+                accepted_tokens: vec![&env, Address::generate(&env)],
+                cuisine_tags: vec![&env],
+                geohash: String::from_str(&env, ""),
+                contact_hash: None,
+            },
+        );
+
+        assert_eq!(
+            client.try_escrow_payment(&payer, &id, &token_addr, &PRICE),
+            Err(Ok(Error::TokenNotAccepted))
+        );
+    }
+
+    #[test]
+    fn test_suspended_restaurant_rejected() {
+        let (env, client, registry, orders, admin, _treasury, rid, token_addr) = setup();
+        let payer = Address::generate(&env);
+        let id = place(&env, &orders, &payer, rid, &token_addr, 1);
+
+        registry.suspend_restaurant(&admin, &rid, &SuspensionReason::FoodSafety, &None);
+        assert_eq!(
+            client.try_escrow_payment(&payer, &id, &token_addr, &PRICE),
+            Err(Ok(Error::RestaurantSuspended))
+        );
+
+        registry.lift_suspension(&admin, &rid);
+        client.escrow_payment(&payer, &id, &token_addr, &PRICE);
+        assert_eq!(client.escrowed_count(&rid), 1);
+    }
+
+    #[test]
+    fn test_closed_restaurant_rejected() {
+        let (env, client, registry, orders, admin, _treasury, rid, token_addr) = setup();
+        let payer = Address::generate(&env);
+        let id = place(&env, &orders, &payer, rid, &token_addr, 1);
+        orders.cancel_order(&payer, &id, &None);
+
+        registry.set_order_contract(&admin, &orders.address);
+        registry.set_payment_contract(&admin, &client.address);
+        registry.close_restaurant(&admin, &rid);
+
+        assert_eq!(
+            client.try_escrow_payment(&payer, &id, &token_addr, &PRICE),
+            Err(Ok(Error::RestaurantClosed))
+        );
+        assert_eq!(client.escrowed_count(&rid), 0);
+    }
+
+    #[test]
+    fn test_min_verification_enforced() {
+        let (env, client, registry, orders, admin, _treasury, rid, token_addr) = setup();
+        let payer = Address::generate(&env);
+        let attester = Address::generate(&env);
+
+        let id = place(&env, &orders, &payer, rid, &token_addr, 1);
+        registry.add_attester(&admin, &attester);
+        registry.attest(
+            &attester,
+            &rid,
+            &restaurant_registry::VerificationLevel::Identity,
+            &BytesN::from_array(&env, &[1; 32]),
+            &1_000,
+        );
+
+        client.set_min_verification(&admin, &VerificationLevel::FoodLicense);
+        assert_eq!(
+            client.try_escrow_payment(&payer, &id, &token_addr, &PRICE),
+            Err(Ok(Error::InsufficientVerification))
+        );
+    }
+
+    #[test]
+    fn test_admin_transfer() {
+        let (env, client, _registry, _orders, admin, _treasury, _rid, _token) = setup();
+        let new_admin = Address::generate(&env);
+
+        client.propose_admin_transfer(&admin, &new_admin);
+        assert_eq!(client.admin(), admin);
+        client.accept_admin_transfer(&new_admin);
+        assert_eq!(client.admin(), new_admin);
+        assert_eq!(client.pending_admin(), None);
+
+        // The old admin has lost its powers.
+        assert_eq!(
+            client.try_set_fee_bps(&admin, &50),
+            Err(Ok(Error::NotAdmin))
+        );
+        client.set_fee_bps(&new_admin, &50);
+        assert_last_event(&env, &client.address, FeeSet { fee_bps: 50 });
+        assert_eq!(client.fee_bps(), 50);
+    }
+
+    #[test]
+    fn test_migrate_version_1_payment() {
+        let (env, client, _registry, orders, admin, _treasury, rid, token) = setup();
+        let payer = Address::generate(&env);
+        let wallet = Address::generate(&env);
+        for _ in 0..3 {
+            place(&env, &orders, &payer, rid, &token, 1);
+        }
+
+        // Simulate payments for orders 1 and 3 written by a version-1
+        // deployment.
+        env.as_contract(&client.address, || {
+            let storage = env.storage();
+            storage.instance().remove(&DataKey::SchemaVersion);
+            for order_id in [1, 3] {
+                storage.persistent().set(
+                    &DataKey::Payment(order_id),
+                    &PaymentV1 {
+                        order_id,
+                        payer: payer.clone(),
+                        restaurant_wallet: wallet.clone(),
+                        token: token.clone(),
+                        amount: PRICE,
+                        fee_amount: 0,
+                        status: PaymentStatus::Escrowed,
+                        created_at: 0,
+                        settled_at: 0,
+                    },
+                );
+            }
+        });
+        assert_eq!(client.schema_version(), 1);
+
+        // A batch short of the last order advances the cursor but leaves the
+        // version alone.
+        assert_eq!(client.migrate(&admin, &2), 1);
+        assert_last_event(
+            &env,
+            &client.address,
+            ContractMigrated {
+                schema_version: 1,
+                migrated: 1,
+            },
+        );
+        assert_eq!(client.schema_version(), 1);
+        assert_eq!(client.migration_cursor(), Some(3));
+        let payment = client.get_payment(&1);
+        assert_eq!(payment.restaurant_id, rid);
+        assert_eq!(payment.order_revision, 0);
+        assert_eq!(payment.restaurant_wallet, wallet);
+        assert_eq!(payment.status, PaymentStatus::Escrowed);
+
+        assert_eq!(client.migrate(&admin, &2), 1);
+        assert_eq!(client.schema_version(), SCHEMA_VERSION);
+        assert_eq!(client.migration_cursor(), None);
+        assert_eq!(client.escrowed_count(&rid), 2);
+
+        // Migrating current data is a no-op.
+        assert_eq!(client.migrate(&admin, &10), 0);
+        assert_eq!(client.escrowed_count(&rid), 2);
+        assert_eq!(
+            client.try_upgrade(&payer, &BytesN::from_array(&env, &[0; 32])),
+            Err(Ok(Error::NotAdmin))
+        );
+    }
+
+    #[test]
+    fn test_migrate_version_2_payment() {
+        let (env, client, _registry, orders, admin, _treasury, rid, token) = setup();
+        let payer = Address::generate(&env);
+        let id = place(&env, &orders, &payer, rid, &token, 1);
+        let item = OrderItem {
+            menu_item_id: 1,
+            name: String::from_str(&env, ""),
+            quantity: 1,
+            unit_price: PRICE,
+        };
+        orders.modify_order(&payer, &id, &vec![&env, ItemChange::Add(item)]);
+
+        // Simulate a payment written by a version-2 deployment, which kept
+        // escrow counts but not order revisions.
+        env.as_contract(&client.address, || {
+            let storage = env.storage();
+            storage.instance().set(&DataKey::SchemaVersion, &2u32);
+            storage.persistent().set(&DataKey::EscrowCount(rid), &1u32);
+            storage.persistent().set(
+                &DataKey::Payment(id),
+                &PaymentV2 {
+                    order_id: id,
+                    restaurant_id: rid,
+                    payer: payer.clone(),
+                    restaurant_wallet: payer.clone(),
+                    token: token.clone(),
+                    amount: 2 * PRICE,
+                    fee_amount: 0,
+                    status: PaymentStatus::Escrowed,
+                    created_at: 0,
+                    settled_at: 0,
+                },
+            );
+        });
+
+        assert_eq!(client.migrate(&admin, &10), 1);
+        assert_eq!(client.schema_version(), SCHEMA_VERSION);
+        // The payment covers the order's current revision.
+        assert_eq!(client.get_payment(&id).order_revision, 1);
+        assert_eq!(client.escrowed_count(&rid), 1);
+    }
+
+    #[test]
+    fn test_migrate_stale_payment_only_refunded() {
+        let (env, client, _registry, orders, admin, _treasury, rid, token) = setup();
+        let payer = Address::generate(&env);
+        let id = place(&env, &orders, &payer, rid, &token, 1);
+        token::StellarAssetClient::new(&env, &token).mint(&client.address, &PRICE);
+
+        // The order was modified after a version-2 deployment escrowed it, so
+        // the escrow no longer covers the order.
+        let item = OrderItem {
+            menu_item_id: 1,
+            name: String::from_str(&env, ""),
+            quantity: 1,
+            unit_price: PRICE,
+        };
+        orders.modify_order(&payer, &id, &vec![&env, ItemChange::Add(item)]);
+        env.as_contract(&client.address, || {
+            let storage = env.storage();
+            storage.instance().set(&DataKey::SchemaVersion, &2u32);
+            storage.persistent().set(&DataKey::EscrowCount(rid), &1u32);
+            storage.persistent().set(
+                &DataKey::Payment(id),
+                &PaymentV2 {
+                    order_id: id,
+                    restaurant_id: rid,
+                    payer: payer.clone(),
+                    restaurant_wallet: payer.clone(),
+                    token: token.clone(),
+                    amount: PRICE,
+                    fee_amount: 0,
+                    status: PaymentStatus::Escrowed,
+                    created_at: 0,
+                    settled_at: 0,
+                },
+            );
+        });
+
+        assert_eq!(client.migrate(&admin, &10), 1);
+        assert_eq!(client.get_payment(&id).order_revision, STALE_REVISION);
+        assert_eq!(
+            client.try_release_payment(&admin, &id),
+            Err(Ok(Error::RevisionMismatch))
+        );
+        client.refund_payment(&admin, &id);
+        assert_eq!(token::Client::new(&env, &token).balance(&payer), 2 * PRICE);
+    }
+
+    #[test]
+    fn test_bump_payment_reports_ttl() {
+        let (env, client, _registry, orders, _admin, _treasury, rid, token_addr) = setup();
+        let payer = Address::generate(&env);
+
+        let id = place(&env, &orders, &payer, rid, &token_addr, 5);
+        client.escrow_payment(&payer, &id, &token_addr, &50_000_000);
+
+        let full = TtlPolicy::DEFAULT.persistent_extend_to;
+        assert_eq!(client.payment_ttl(&id), full);
+        env.ledger().with_mut(|l| l.sequence_number += 1_000);
+        assert_eq!(client.payment_ttl(&id), full - 1_000);
+        assert_eq!(client.bump_payment(&id), full);
+        assert_eq!(
+            client.try_bump_payment(&(id + 1)),
+            Err(Ok(Error::PaymentNotFound))
+        );
     }
 }
@@ -0,0 +1,39 @@
+//! Client interface for the Order contract.
+//!
+//! Only the entry points the Payment contract calls are declared here. The
+//! mirrored types must keep the same name and field layout as their
+//! counterparts in `order` so that they decode identically.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env};
+
+/// Mirror of `order::OrderStatus`.
+#[contracttype(export = false)]
+#[derive(Clone, PartialEq, Debug)]
+pub enum OrderStatus {
+    Pending,
+    Confirmed,
+    Preparing,
+    Ready,
+    Delivered,
+    Cancelled,
+}
+
+/// Mirror of `order::OrderSummary`.
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub struct OrderSummary {
+    pub id: u64,
+    pub restaurant_id: u64,
+    pub customer: Address,
+    pub token: Address,
+    pub total_amount: i128,
+    pub status: OrderStatus,
+    pub updated_at: u64,
+    pub revision: u32,
+}
+
+#[contractclient(name = "OrderClient")]
+pub trait OrderInterface {
+    fn get_order_summary(env: Env, order_id: u64) -> OrderSummary;
+    fn get_count(env: Env) -> u64;
+}
@@ -0,0 +1,62 @@
+//! Client interface for the Restaurant Registry contract.
+//!
+//! Only the entry points the Payment contract calls are declared here. The
+//! mirrored types must match their counterparts in `restaurant_registry`.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, String};
+
+/// Mirror of `restaurant_registry::VerificationLevel`.
+#[contracttype(export = false)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VerificationLevel {
+    Unverified = 0,
+    Identity = 1,
+    FoodLicense = 2,
+}
+
+/// Mirror of `restaurant_registry::Restaurant`.
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub struct Restaurant {
+    pub id: u64,
+    pub owner: Address,
+    pub brand_id: u64,
+    pub name: String,
+    pub slug: String,
+    pub is_active: bool,
+    pub created_at: u64,
+}
+
+/// Mirror of `restaurant_registry::Permission`.
+#[contracttype(export = false)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Permission {
+    ManageMenu,
+    ConfirmOrder,
+    PrepareOrder,
+    ReadyOrder,
+    DeliverOrder,
+    CancelOrder,
+    RefundPayment,
+}
+
+/// Mirror of `restaurant_registry::RestaurantStatus`.
+#[contracttype(export = false)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RestaurantStatus {
+    Active,
+    Inactive,
+    Suspended,
+    Closed,
+}
+
+#[contractclient(name = "RegistryClient")]
+pub trait RestaurantRegistryInterface {
+    fn get_restaurant(env: Env, restaurant_id: u64) -> Restaurant;
+    fn get_status(env: Env, restaurant_id: u64) -> RestaurantStatus;
+    fn has_permission(env: Env, restaurant_id: u64, addr: Address, permission: Permission) -> bool;
+    fn get_payout_wallet(env: Env, restaurant_id: u64) -> Address;
+    fn accepts_token(env: Env, restaurant_id: u64, token: Address) -> bool;
+    fn verification_level(env: Env, restaurant_id: u64) -> VerificationLevel;
+}
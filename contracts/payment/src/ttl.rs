@@ -0,0 +1,42 @@
+//! # Storage TTL
+//!
+//! Every write extends the entries it touches according to the contract's
+//! [`TtlPolicy`], which the admin may tune. The policy and the extension
+//! logic live in the shared `storage-ttl` crate; this module binds them to
+//! the payment records.
+
+use soroban_sdk::Env;
+pub use storage_ttl::{TtlPolicy, DAY_IN_LEDGERS};
+
+use crate::DataKey;
+
+pub(crate) fn policy(env: &Env) -> TtlPolicy {
+    storage_ttl::policy(env, &DataKey::TtlPolicy)
+}
+
+/// Extend the contract instance according to the policy.
+pub(crate) fn extend_instance(env: &Env) {
+    storage_ttl::extend_instance(env, &policy(env));
+}
+
+/// Extend a persistent entry according to the policy.
+pub(crate) fn extend_persistent(env: &Env, key: &DataKey) {
+    storage_ttl::extend_persistent(env, &policy(env), key);
+}
+
+/// Extend a payment record and record until when it lives.
+///
+/// Returns the record's remaining TTL.
+pub(crate) fn extend_payment(env: &Env, order_id: u64) -> u32 {
+    let live_until_key = DataKey::LiveUntil(order_id);
+    storage_ttl::extend_tracked(env, &policy(env), &live_until_key, |extend_to| {
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::Payment(order_id), extend_to, extend_to);
+    })
+}
+
+/// Remaining TTL recorded for a payment record; `0` if none was recorded.
+pub(crate) fn remaining_ttl(env: &Env, order_id: u64) -> u32 {
+    storage_ttl::remaining_ttl(env, &DataKey::LiveUntil(order_id))
+}
@@ -0,0 +1,237 @@
+//! # Brands
+//!
+//! A brand groups the branches (locations) of a multi-location restaurant
+//! business under one owner. Each branch is a regular `Restaurant` record
+//! whose `brand_id` points back at the brand.
+
+use soroban_sdk::{contractimpl, contracttype, vec, Address, Env, String, Vec};
+
+use crate::events::{BranchDetached, BrandCreated, Event};
+use crate::{
+    append_to_list, create_restaurant, extend_instance, extend_persistent, remove_from_list,
+    validate_name, DataKey, Error, Restaurant, RestaurantRegistry, RestaurantRegistryArgs,
+    RestaurantRegistryClient,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+/// Parent entity of a group of restaurant branches.
+#[contracttype]
+#[derive(Clone)]
+pub struct Brand {
+    /// Auto-incrementing unique identifier (starts at 1).
+    pub id: u64,
+    /// Wallet that created the brand and may add branches to it.
+    pub owner: Address,
+    /// Human-readable brand name.
+    pub name: String,
+    /// Ledger timestamp of creation.
+    pub created_at: u64,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contractimpl]
+impl RestaurantRegistry {
+    /// Create a new brand. The caller becomes its owner.
+    ///
+    /// # Returns
+    /// The newly assigned brand ID (starts at 1).
+    ///
+    /// # Errors
+    /// A naming-rule error if `name` is invalid.
+    pub fn create_brand(env: Env, owner: Address, name: String) -> Result<u64, Error> {
+        owner.require_auth();
+        validate_name(&name)?;
+
+        let count: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::BrandCount)
+            .unwrap_or(0);
+        let brand_id = count + 1;
+
+        let brand = Brand {
+            id: brand_id,
+            owner: owner.clone(),
+            name: name.clone(),
+            created_at: env.ledger().timestamp(),
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Brand(brand_id), &brand);
+        extend_persistent(&env, &DataKey::Brand(brand_id));
+        append_to_list(&env, DataKey::OwnerBrands(owner.clone()), brand_id);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::BrandCount, &brand_id);
+        extend_instance(&env);
+
+        BrandCreated {
+            brand_id,
+            owner,
+            name,
+        }
+        .publish(&env);
+
+        Ok(brand_id)
+    }
+
+    /// Register a new branch (location) under a brand.
+    ///
+    /// Only the brand owner may add branches; they become the owner of the
+    /// new restaurant.
+    ///
+    /// # Returns
+    /// The newly assigned restaurant ID.
+    ///
+    /// # Errors
+    /// As for [`RestaurantRegistry::register_restaurant`].
+    pub fn add_branch(
+        env: Env,
+        owner: Address,
+        brand_id: u64,
+        name: String,
+        slug: String,
+    ) -> Result<u64, Error> {
+        owner.require_auth();
+
+        let brand = load_brand(&env, brand_id)?;
+        if brand.owner != owner {
+            return Err(Error::Unauthorized);
+        }
+
+        create_restaurant(&env, &owner, brand_id, name, slug)
+    }
+
+    /// Fetch a brand by its numeric ID.
+    pub fn get_brand(env: Env, brand_id: u64) -> Result<Brand, Error> {
+        load_brand(&env, brand_id)
+    }
+
+    /// Return the restaurant IDs of every branch of a brand.
+    pub fn get_brand_restaurants(env: Env, brand_id: u64) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BrandRestaurants(brand_id))
+            .unwrap_or_else(|| vec![&env])
+    }
+
+    /// Return the IDs of every brand created by `owner`.
+    pub fn get_owner_brands(env: Env, owner: Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OwnerBrands(owner))
+            .unwrap_or_else(|| vec![&env])
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Make a branch standalone: clear its `brand_id` and drop it from the
+/// brand's branch list. The caller saves `restaurant`.
+pub(crate) fn detach_from_brand(env: &Env, restaurant: &mut Restaurant) {
+    if restaurant.brand_id != 0 {
+        remove_from_list(
+            env,
+            DataKey::BrandRestaurants(restaurant.brand_id),
+            restaurant.id,
+        );
+        BranchDetached {
+            brand_id: restaurant.brand_id,
+            restaurant_id: restaurant.id,
+        }
+        .publish(env);
+        restaurant.brand_id = 0;
+    }
+}
+
+fn load_brand(env: &Env, brand_id: u64) -> Result<Brand, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Brand(brand_id))
+        .ok_or(Error::BrandNotFound)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::{Error, RestaurantRegistry, RestaurantRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{vec, Address, Env, String};
+
+    fn setup() -> (Env, RestaurantRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(RestaurantRegistry, ());
+        let client = RestaurantRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+        (env, client)
+    }
+
+    #[test]
+    fn test_brand_with_branches() {
+        let (env, client) = setup();
+        let owner = Address::generate(&env);
+
+        let brand_id = client.create_brand(&owner, &String::from_str(&env, "Mama's"));
+        let lekki = client.add_branch(
+            &owner,
+            &brand_id,
+            &String::from_str(&env, "Mama's Lekki"),
+            &String::from_str(&env, "mamas-lekki"),
+        );
+        let ikeja = client.add_branch(
+            &owner,
+            &brand_id,
+            &String::from_str(&env, "Mama's Ikeja"),
+            &String::from_str(&env, "mamas-ikeja"),
+        );
+
+        assert_eq!(client.get_brand(&brand_id).owner, owner);
+        assert_eq!(client.get_owner_brands(&owner), vec![&env, brand_id]);
+        assert_eq!(
+            client.get_brand_restaurants(&brand_id),
+            vec![&env, lekki, ikeja]
+        );
+        assert_eq!(
+            client.get_owner_restaurants(&owner),
+            vec![&env, lekki, ikeja]
+        );
+
+        // Branches are independent restaurants.
+        client.set_active(&owner, &ikeja, &false);
+        assert!(client.get_restaurant(&lekki).is_active);
+        assert!(!client.get_restaurant(&ikeja).is_active);
+        assert_eq!(client.get_restaurant(&ikeja).brand_id, brand_id);
+    }
+
+    #[test]
+    fn test_only_brand_owner_adds_branches() {
+        let (env, client) = setup();
+        let owner = Address::generate(&env);
+        let stranger = Address::generate(&env);
+
+        let brand_id = client.create_brand(&owner, &String::from_str(&env, "Mama's"));
+        assert_eq!(
+            client.try_add_branch(
+                &stranger,
+                &brand_id,
+                &String::from_str(&env, "Fake Mama's"),
+                &String::from_str(&env, "fake-mamas"),
+            ),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+}
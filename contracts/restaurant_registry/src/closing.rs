@@ -0,0 +1,378 @@
+//! # Closing restaurants
+//!
+//! Deactivation is reversible; closing is not. `close_restaurant` refuses
+//! while the Order contract reports open orders or the Payment contract
+//! still holds escrowed payments for the restaurant (both are set by the
+//! admin). Escrow counts are only trusted from a Payment contract that
+//! checks escrows against the same Order contract, so payments for orders
+//! that do not exist cannot hold a restaurant open. Closing then releases
+//! the restaurant's slug and removes it from its owner's, brand's and
+//! group's lists, so the slug can be registered again.
+//!
+//! The `Restaurant` record stays behind, together with a [`Tombstone`]
+//! recording who closed it and when. A closed restaurant reports
+//! [`crate::RestaurantStatus::Closed`] and accepts no further changes.
+
+use soroban_sdk::{contractimpl, contracttype, Address, Env, String};
+
+use crate::events::{Event, PaymentContractSet, RestaurantClosed};
+use crate::payments::PaymentClient;
+use crate::reviews::order_client;
+use crate::{
+    assert_admin, assert_owner_or_admin, extend_instance, load_restaurant, release_slug,
+    remove_from_list, save_restaurant, ttl::save, DataKey, Error, RestaurantRegistry,
+    RestaurantRegistryArgs, RestaurantRegistryClient,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+/// Audit record left behind by a closed restaurant.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tombstone {
+    /// Owner at the time of closing.
+    pub owner: Address,
+    /// Slug released by closing.
+    pub slug: String,
+    /// Owner or admin who closed the restaurant.
+    pub closed_by: Address,
+    /// Ledger timestamp of the closure.
+    pub closed_at: u64,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contractimpl]
+impl RestaurantRegistry {
+    /// Set the Payment contract that closures are checked against (admin
+    /// only).
+    pub fn set_payment_contract(
+        env: Env,
+        admin: Address,
+        payment_contract: Address,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        assert_admin(&env, &admin)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::PaymentContract, &payment_contract);
+        extend_instance(&env);
+
+        PaymentContractSet { payment_contract }.publish(&env);
+        Ok(())
+    }
+
+    /// Close a restaurant for good.
+    ///
+    /// Callable by the restaurant's owner **or** the contract admin.
+    ///
+    /// # Errors
+    /// - [`Error::RestaurantClosed`] if the restaurant is already closed.
+    /// - [`Error::OrderContractNotSet`] / [`Error::PaymentContractNotSet`]
+    ///   if the Order or Payment contract is not configured or cannot be
+    ///   queried.
+    /// - [`Error::PaymentContractUnlinked`] if the Payment contract does not
+    ///   check escrows against the registry's Order contract.
+    /// - [`Error::HasOpenOrders`] if the restaurant has open orders.
+    /// - [`Error::HasEscrowedPayments`] if the Payment contract still holds
+    ///   escrow for the restaurant.
+    pub fn close_restaurant(env: Env, caller: Address, restaurant_id: u64) -> Result<(), Error> {
+        caller.require_auth();
+
+        let mut restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_owner_or_admin(&env, &caller, &restaurant)?;
+
+        let orders = order_client(&env)?;
+        let payments = payment_client(&env)?;
+        match payments.try_order_contract() {
+            Ok(Ok(Some(address))) if address == orders.address => {}
+            Ok(Ok(_)) => return Err(Error::PaymentContractUnlinked),
+            _ => return Err(Error::PaymentContractNotSet),
+        }
+        match orders.try_get_open_orders(&restaurant_id) {
+            Ok(Ok(ids)) if ids.is_empty() => {}
+            Ok(Ok(_)) => return Err(Error::HasOpenOrders),
+            _ => return Err(Error::OrderContractNotSet),
+        }
+        match payments.try_escrowed_count(&restaurant_id) {
+            Ok(Ok(0)) => {}
+            Ok(Ok(_)) => return Err(Error::HasEscrowedPayments),
+            _ => return Err(Error::PaymentContractNotSet),
+        }
+
+        restaurant.is_active = false;
+        save_restaurant(&env, &restaurant);
+
+        let storage = env.storage().persistent();
+        release_slug(&env, &restaurant.slug);
+        remove_from_list(
+            &env,
+            DataKey::OwnerRestaurants(restaurant.owner.clone()),
+            restaurant_id,
+        );
+        if restaurant.brand_id != 0 {
+            remove_from_list(
+                &env,
+                DataKey::BrandRestaurants(restaurant.brand_id),
+                restaurant_id,
+            );
+        }
+        storage.remove(&DataKey::PendingOwner(restaurant_id));
+        if let Some(group_id) = Self::get_restaurant_group(env.clone(), restaurant_id) {
+            storage.remove(&DataKey::RestaurantGroup(restaurant_id));
+            remove_from_list(&env, DataKey::GroupMembers(group_id), restaurant_id);
+        }
+
+        let tombstone = Tombstone {
+            owner: restaurant.owner,
+            slug: restaurant.slug,
+            closed_by: caller.clone(),
+            closed_at: env.ledger().timestamp(),
+        };
+        save(&env, &DataKey::Tombstone(restaurant_id), &tombstone);
+
+        RestaurantClosed {
+            restaurant_id,
+            closed_by: caller,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Return the tombstone of a closed restaurant; `None` while it is open.
+    pub fn get_tombstone(env: Env, restaurant_id: u64) -> Option<Tombstone> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Tombstone(restaurant_id))
+    }
+
+    /// Return the Payment contract closures are checked against, if set.
+    pub fn payment_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PaymentContract)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Fail if the restaurant has been closed.
+pub(crate) fn assert_not_closed(env: &Env, restaurant_id: u64) -> Result<(), Error> {
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::Tombstone(restaurant_id))
+    {
+        return Err(Error::RestaurantClosed);
+    }
+    Ok(())
+}
+
+/// Whether the restaurant has been closed, for `get_status`.
+pub(crate) fn is_closed(env: &Env, restaurant_id: u64) -> bool {
+    assert_not_closed(env, restaurant_id).is_err()
+}
+
+fn payment_client(env: &Env) -> Result<PaymentClient<'_>, Error> {
+    let address: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::PaymentContract)
+        .ok_or(Error::PaymentContractNotSet)?;
+    Ok(PaymentClient::new(env, &address))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::events::{assert_last_event, RestaurantClosed};
+    use crate::{Error, RestaurantRegistry, RestaurantRegistryClient, RestaurantStatus};
+    use order::{Fulfilment, FulfilmentChannel, OrderContract, OrderContractClient, OrderItem};
+    use payment::{PaymentContract, PaymentContractClient};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{token, vec, Address, Env, String};
+
+    /// Deploy a registry linked to order and payment contracts, with one
+    /// restaurant selling one item priced in a fresh token.
+    fn setup() -> (
+        Env,
+        RestaurantRegistryClient<'static>,
+        OrderContractClient<'static>,
+        PaymentContractClient<'static>,
+        Address,
+        Address,
+        Address,
+    ) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        let token = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+
+        let registry_id = env.register(RestaurantRegistry, ());
+        let registry = RestaurantRegistryClient::new(&env, &registry_id);
+        registry.initialize(&admin);
+        registry.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "mamas-kitchen"),
+        );
+        registry.add_menu_item(
+            &owner,
+            &1,
+            &String::from_str(&env, "Jollof Rice"),
+            &5_000_000,
+            &token,
+        );
+
+        let orders = OrderContractClient::new(&env, &env.register(OrderContract, ()));
+        orders.initialize(&admin, &registry_id);
+        let payments = PaymentContractClient::new(&env, &env.register(PaymentContract, ()));
+        payments.initialize(&admin, &Address::generate(&env), &0, &registry_id);
+        payments.set_order_contract(&admin, &orders.address);
+        registry.set_order_contract(&admin, &orders.address);
+        registry.set_payment_contract(&admin, &payments.address);
+
+        (env, registry, orders, payments, admin, owner, token)
+    }
+
+    #[test]
+    fn test_close_restaurant() {
+        let (env, registry, _orders, _payments, _admin, owner, _token) = setup();
+
+        registry.close_restaurant(&owner, &1);
+        assert_last_event(
+            &env,
+            &registry.address,
+            RestaurantClosed {
+                restaurant_id: 1,
+                closed_by: owner.clone(),
+            },
+        );
+        assert_eq!(registry.get_status(&1), RestaurantStatus::Closed);
+        assert!(!registry.is_open_at(&1, &0));
+        assert_eq!(registry.get_owner_restaurants(&owner), vec![&env]);
+        let tombstone = registry.get_tombstone(&1).unwrap();
+        assert_eq!(tombstone.owner, owner);
+        assert_eq!(tombstone.closed_by, owner);
+
+        // The record stays for audit, but accepts no changes.
+        assert!(!registry.get_restaurant(&1).is_active);
+        assert_eq!(
+            registry.try_set_active(&owner, &1, &true),
+            Err(Ok(Error::RestaurantClosed))
+        );
+        assert_eq!(
+            registry.try_close_restaurant(&owner, &1),
+            Err(Ok(Error::RestaurantClosed))
+        );
+
+        // The slug is free again.
+        let id = registry.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "mamas-kitchen"),
+        );
+        assert_eq!(registry.get_owner_restaurants(&owner), vec![&env, id]);
+    }
+
+    #[test]
+    fn test_open_orders_and_escrow_block_closing() {
+        let (env, registry, orders, payments, admin, owner, token) = setup();
+        let customer = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&customer, &5_000_000);
+
+        let item = OrderItem {
+            menu_item_id: 1,
+            name: String::from_str(&env, ""),
+            quantity: 1,
+            unit_price: 5_000_000,
+        };
+        let order_id = orders.place_order(
+            &customer,
+            &1,
+            &vec![&env, item],
+            &String::from_str(&env, ""),
+            &None,
+            &Fulfilment {
+                channel: FulfilmentChannel::Pickup,
+                table_id: None,
+            },
+        );
+        payments.escrow_payment(&customer, &order_id, &token, &5_000_000);
+        assert_eq!(
+            registry.try_close_restaurant(&owner, &1),
+            Err(Ok(Error::HasOpenOrders))
+        );
+
+        orders.set_status(&admin, &order_id, &order::OrderStatus::Delivered, &None);
+        assert_eq!(
+            registry.try_close_restaurant(&owner, &1),
+            Err(Ok(Error::HasEscrowedPayments))
+        );
+
+        payments.release_payment(&admin, &order_id);
+        assert_eq!(
+            registry.try_close_restaurant(&Address::generate(&env), &1),
+            Err(Ok(Error::Unauthorized))
+        );
+        registry.close_restaurant(&admin, &1);
+        assert_eq!(registry.get_tombstone(&1).unwrap().closed_by, admin);
+    }
+
+    #[test]
+    fn test_close_branch_without_payment_contract() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+
+        let registry_id = env.register(RestaurantRegistry, ());
+        let registry = RestaurantRegistryClient::new(&env, &registry_id);
+        registry.initialize(&admin);
+        let brand_id = registry.create_brand(&owner, &String::from_str(&env, "Mama's"));
+        let id = registry.add_branch(
+            &owner,
+            &brand_id,
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "mamas-kitchen"),
+        );
+        let orders = OrderContractClient::new(&env, &env.register(OrderContract, ()));
+        orders.initialize(&admin, &registry_id);
+        registry.set_order_contract(&admin, &orders.address);
+
+        assert_eq!(
+            registry.try_close_restaurant(&owner, &id),
+            Err(Ok(Error::PaymentContractNotSet))
+        );
+
+        let payments = PaymentContractClient::new(&env, &env.register(PaymentContract, ()));
+        payments.initialize(&admin, &Address::generate(&env), &0, &registry_id);
+        registry.set_payment_contract(&admin, &payments.address);
+        // Its escrow counts only count once it checks against our orders.
+        assert_eq!(
+            registry.try_close_restaurant(&owner, &id),
+            Err(Ok(Error::PaymentContractUnlinked))
+        );
+        payments.set_order_contract(&admin, &Address::generate(&env));
+        assert_eq!(
+            registry.try_close_restaurant(&owner, &id),
+            Err(Ok(Error::PaymentContractUnlinked))
+        );
+
+        payments.set_order_contract(&admin, &orders.address);
+        registry.close_restaurant(&owner, &id);
+        assert_eq!(registry.get_brand_restaurants(&brand_id), vec![&env]);
+    }
+}
@@ -0,0 +1,208 @@
+use soroban_sdk::contracterror;
+
+/// Error codes returned by the registry.
+///
+/// Codes are part of the contract's public interface: never renumber an
+/// existing variant, only append new ones. The contract spec allows at most
+/// 50 variants per enum, so the opening-hours and review entry points report
+/// their own [`HoursError`] and [`ReviewError`]. All three enums share one
+/// code space.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Name is empty.
+    NameEmpty = 1,
+    /// Name is longer than [`crate::MAX_NAME_LEN`] bytes.
+    NameTooLong = 2,
+    /// Slug is shorter than [`crate::MIN_SLUG_LEN`] bytes.
+    SlugTooShort = 3,
+    /// Slug is longer than [`crate::MAX_SLUG_LEN`] bytes.
+    SlugTooLong = 4,
+    /// Slug contains a character outside `[a-z0-9-]`.
+    SlugInvalidCharacter = 5,
+    /// Slug starts or ends with a hyphen, or contains two in a row.
+    SlugInvalidHyphen = 6,
+    /// Slug is reserved for platform use.
+    SlugReserved = 7,
+    /// Slug is already held by another restaurant.
+    SlugTaken = 8,
+
+    // Access control and lifecycle.
+    AlreadyInitialized = 9,
+    NotInitialized = 10,
+    /// Caller is neither the owner, nor permitted staff, nor the admin.
+    Unauthorized = 11,
+    /// Only the contract admin may perform this action.
+    NotAdmin = 12,
+    NoPendingAdmin = 13,
+
+    // Lookups.
+    RestaurantNotFound = 14,
+    SlugNotFound = 15,
+    MenuItemNotFound = 16,
+    BrandNotFound = 17,
+
+    // Menu and staff.
+    /// Menu item price is zero or negative.
+    InvalidPrice = 18,
+    /// Staff role or group admin seat already granted.
+    RoleAlreadyGranted = 19,
+    /// Staff role or group admin seat not held.
+    RoleNotGranted = 20,
+
+    // Ownership transfer.
+    NoPendingTransfer = 21,
+    /// Proposed new owner already owns the restaurant.
+    AlreadyOwner = 22,
+    RecoveryDelayNotElapsed = 23,
+
+    // Profile.
+    /// `expected_version` does not match the stored profile.
+    ProfileVersionMismatch = 24,
+    TooManyAcceptedTokens = 25,
+    TooManyCuisineTags = 26,
+
+    // Codes 27–31 belong to [`HoursError`].
+
+    // Verification.
+    /// Caller is not an appointed attester.
+    NotAttester = 32,
+    InvalidVerificationLevel = 33,
+    /// Attestation expiry is not in the future.
+    ExpiryInPast = 34,
+    AttestationNotFound = 35,
+
+    // Suspension.
+    /// Suspension end time is not in the future.
+    SuspensionEndInPast = 36,
+    NotSuspended = 37,
+    AppealAlreadyFiled = 38,
+
+    // Storage TTL.
+    /// A TTL threshold exceeds its extension, or an extension is zero or
+    /// above the network maximum.
+    InvalidTtlPolicy = 39,
+
+    // Cross-contract.
+    /// No Order contract has been set, or it could not be queried.
+    OrderContractNotSet = 40,
+
+    // Codes 41–46 belong to [`ReviewError`].
+
+    // Tables.
+    TableNotFound = 47,
+
+    // Groups.
+    /// Group does not exist, or the restaurant belongs to no group.
+    GroupNotFound = 48,
+    /// Restaurant already belongs to a group.
+    AlreadyInGroup = 49,
+    /// Fee exceeds [`crate::MAX_FEE_BPS`].
+    InvalidFeeBps = 50,
+
+    // Closing.
+    /// No Payment contract has been set, or it could not be queried.
+    PaymentContractNotSet = 51,
+    /// Restaurant still has orders that are neither delivered nor cancelled.
+    HasOpenOrders = 52,
+    /// Payment contract still holds escrowed payments for the restaurant.
+    HasEscrowedPayments = 53,
+    /// Restaurant has been closed for good and accepts no changes.
+    RestaurantClosed = 54,
+
+    // Menu tokens.
+    /// Token is not among the restaurant profile's `accepted_tokens`.
+    TokenNotAccepted = 55,
+
+    // Profile location.
+    /// Geohash is longer than [`crate::MAX_GEOHASH_LEN`] or contains a
+    /// character outside the geohash base32 alphabet.
+    InvalidGeohash = 56,
+
+    // Closing, continued.
+    /// Payment contract does not check escrows against the registry's Order
+    /// contract, so its escrow counts cannot be trusted.
+    PaymentContractUnlinked = 57,
+    // Code 58 is `Internal` in the narrower enums below.
+}
+
+/// Error codes returned by `set_opening_hours`.
+///
+/// The access-control and lookup variants repeat the codes of their
+/// [`Error`] namesakes.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum HoursError {
+    NotInitialized = 10,
+    Unauthorized = 11,
+    RestaurantNotFound = 14,
+    /// UTC offset is outside UTC-12 to UTC+14.
+    InvalidUtcOffset = 27,
+    /// More than [`crate::MAX_OPENING_PERIODS`] weekly opening periods.
+    TooManyOpeningPeriods = 28,
+    /// More than [`crate::MAX_CLOSURES`] one-off closures.
+    TooManyClosures = 29,
+    /// An opening period has an out-of-range day or minute.
+    InvalidOpeningPeriod = 30,
+    /// A closure does not end after it starts.
+    InvalidClosure = 31,
+    RestaurantClosed = 54,
+    /// A shared helper failed with an [`Error`] that has no counterpart
+    /// here.
+    Internal = 58,
+}
+
+impl From<Error> for HoursError {
+    /// Map the errors of the shared access-control helpers, which are the
+    /// only [`Error`]s the opening-hours entry points expect to meet.
+    fn from(error: Error) -> Self {
+        match error {
+            Error::NotInitialized => Self::NotInitialized,
+            Error::Unauthorized => Self::Unauthorized,
+            Error::RestaurantNotFound => Self::RestaurantNotFound,
+            Error::RestaurantClosed => Self::RestaurantClosed,
+            _ => Self::Internal,
+        }
+    }
+}
+
+/// Error codes returned by the review entry points.
+///
+/// The access-control and lookup variants repeat the codes of their
+/// [`Error`] namesakes.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReviewError {
+    /// Caller did not place the order, or may not respond for the restaurant.
+    Unauthorized = 11,
+    RestaurantNotFound = 14,
+    /// No Order contract has been set, or it could not be queried.
+    OrderContractNotSet = 40,
+    OrderNotFound = 41,
+    /// Only delivered orders can be reviewed.
+    OrderNotDelivered = 42,
+    /// Rating is outside [`crate::MIN_RATING`]`..=`[`crate::MAX_RATING`].
+    InvalidRating = 43,
+    AlreadyReviewed = 44,
+    ReviewNotFound = 45,
+    AlreadyResponded = 46,
+    /// A shared helper failed with an [`Error`] that has no counterpart
+    /// here.
+    Internal = 58,
+}
+
+impl From<Error> for ReviewError {
+    /// Map the errors of the shared lookup helpers, which are the only
+    /// [`Error`]s the review entry points expect to meet.
+    fn from(error: Error) -> Self {
+        match error {
+            Error::Unauthorized => Self::Unauthorized,
+            Error::RestaurantNotFound => Self::RestaurantNotFound,
+            Error::OrderContractNotSet => Self::OrderContractNotSet,
+            _ => Self::Internal,
+        }
+    }
+}
@@ -0,0 +1,720 @@
+//! # Events
+//!
+//! Every state change publishes at least one event. Its topics are
+//! `(action, subject, EVENT_VERSION)` and its data is one of the structs
+//! below, encoded as a map keyed by field name. Indexers should match on
+//! all three topics and decode the data as the struct for that version.
+//!
+//! Calls with side effects on other records publish an event for each of
+//! them before their own: `accept_owner_transfer`, for example, publishes
+//! [`BranchDetached`] for a branch, [`ProfileUpdated`] for a restaurant with
+//! a stored profile, one [`RoleRevoked`] per role it clears and
+//! [`GroupMemberRemoved`] for a group member, then [`OwnerTransferred`].
+//!
+//! | Action      | Subject    | Data                        |
+//! |-------------|------------|-----------------------------|
+//! | `init`      | `contract` | [`Initialized`]             |
+//! | `register`  | `rest`     | [`RestaurantRegistered`]    |
+//! | `update`    | `rest`     | [`RestaurantUpdated`]       |
+//! | `setactive` | `rest`     | [`RestaurantActiveSet`]     |
+//! | `close`     | `rest`     | [`RestaurantClosed`]        |
+//! | `register`  | `brand`    | [`BrandCreated`]            |
+//! | `detach`    | `brand`    | [`BranchDetached`]          |
+//! | `add`       | `menu`     | [`MenuItemAdded`]           |
+//! | `update`    | `menu`     | [`MenuItemUpdated`]         |
+//! | `avail`     | `menu`     | [`MenuItemAvailabilitySet`] |
+//! | `add`       | `table`    | [`TableAdded`]              |
+//! | `update`    | `table`    | [`TableUpdated`]            |
+//! | `setactive` | `table`    | [`TableActiveSet`]          |
+//! | `grant`     | `staff`    | [`RoleGranted`]             |
+//! | `revoke`    | `staff`    | [`RoleRevoked`]             |
+//! | `propose`   | `owner`    | [`OwnerTransferProposed`]   |
+//! | `recover`   | `owner`    | [`OwnerRecoveryStarted`]    |
+//! | `transfer`  | `owner`    | [`OwnerTransferred`]        |
+//! | `cancel`    | `owner`    | [`OwnerTransferCancelled`]  |
+//! | `update`    | `profile`  | [`ProfileUpdated`]          |
+//! | `sethours`  | `rest`     | [`OpeningHoursSet`]         |
+//! | `clrhours`  | `rest`     | [`OpeningHoursCleared`]     |
+//! | `suspend`   | `rest`     | [`RestaurantSuspended`]     |
+//! | `unsuspend` | `rest`     | [`SuspensionLifted`]        |
+//! | `appeal`    | `rest`     | [`SuspensionAppealed`]      |
+//! | `add`       | `attester` | [`AttesterAdded`]           |
+//! | `remove`    | `attester` | [`AttesterRemoved`]         |
+//! | `attest`    | `rest`     | [`RestaurantAttested`]      |
+//! | `revoke`    | `rest`     | [`AttestationRevoked`]      |
+//! | `set`       | `orders`   | [`OrderContractSet`]        |
+//! | `set`       | `payments` | [`PaymentContractSet`]      |
+//! | `submit`    | `review`   | [`ReviewSubmitted`]         |
+//! | `respond`   | `review`   | [`ReviewResponded`]         |
+//! | `create`    | `group`    | [`GroupCreated`]            |
+//! | `addadmin`  | `group`    | [`GroupAdminAdded`]         |
+//! | `rmadmin`   | `group`    | [`GroupAdminRemoved`]       |
+//! | `join`      | `group`    | [`GroupMemberAdded`]        |
+//! | `leave`     | `group`    | [`GroupMemberRemoved`]      |
+//! | `defaults`  | `group`    | [`GroupDefaultsSet`]        |
+//! | `settings`  | `rest`     | [`RestaurantSettingsSet`]   |
+//! | `grant`     | `grpstaff` | [`GroupRoleGranted`]        |
+//! | `revoke`    | `grpstaff` | [`GroupRoleRevoked`]        |
+//! | `propose`   | `admin`    | [`AdminTransferProposed`]   |
+//! | `accept`    | `admin`    | [`AdminTransferAccepted`]   |
+//! | `cancel`    | `admin`    | [`AdminTransferCancelled`]  |
+//! | `ttl`       | `policy`   | [`TtlPolicySet`]            |
+//! | `upgrade`   | `contract` | [`ContractUpgraded`]        |
+//! | `migrate`   | `contract` | [`ContractMigrated`]        |
+
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, String, Symbol};
+
+use crate::{Settings, StaffRole, SuspensionReason, TtlPolicy, VerificationLevel};
+
+#[cfg(test)]
+pub(crate) use contract_events::testutils::{assert_event_published, assert_last_event};
+pub(crate) use contract_events::Event;
+pub use contract_events::EVENT_VERSION;
+
+/// The contract was initialised.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Initialized {
+    pub admin: Address,
+}
+
+impl Event for Initialized {
+    const ACTION: Symbol = symbol_short!("init");
+    const SUBJECT: Symbol = symbol_short!("contract");
+}
+
+/// A restaurant was registered, standalone or as a brand's branch.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestaurantRegistered {
+    pub restaurant_id: u64,
+    pub owner: Address,
+    /// Brand the restaurant is a branch of; 0 if standalone.
+    pub brand_id: u64,
+    pub name: String,
+    pub slug: String,
+}
+
+impl Event for RestaurantRegistered {
+    const ACTION: Symbol = symbol_short!("register");
+    const SUBJECT: Symbol = symbol_short!("rest");
+}
+
+/// A restaurant's name or slug changed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestaurantUpdated {
+    pub restaurant_id: u64,
+    pub name: String,
+    pub slug: String,
+}
+
+impl Event for RestaurantUpdated {
+    const ACTION: Symbol = symbol_short!("update");
+    const SUBJECT: Symbol = symbol_short!("rest");
+}
+
+/// A restaurant was activated or deactivated.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestaurantActiveSet {
+    pub restaurant_id: u64,
+    pub active: bool,
+}
+
+impl Event for RestaurantActiveSet {
+    const ACTION: Symbol = symbol_short!("setactive");
+    const SUBJECT: Symbol = symbol_short!("rest");
+}
+
+/// A restaurant was closed for good.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestaurantClosed {
+    pub restaurant_id: u64,
+    pub closed_by: Address,
+}
+
+impl Event for RestaurantClosed {
+    const ACTION: Symbol = symbol_short!("close");
+    const SUBJECT: Symbol = symbol_short!("rest");
+}
+
+/// A brand was created.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BrandCreated {
+    pub brand_id: u64,
+    pub owner: Address,
+    pub name: String,
+}
+
+impl Event for BrandCreated {
+    const ACTION: Symbol = symbol_short!("register");
+    const SUBJECT: Symbol = symbol_short!("brand");
+}
+
+/// A branch left its brand and became standalone.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BranchDetached {
+    pub brand_id: u64,
+    pub restaurant_id: u64,
+}
+
+impl Event for BranchDetached {
+    const ACTION: Symbol = symbol_short!("detach");
+    const SUBJECT: Symbol = symbol_short!("brand");
+}
+
+/// A menu item was added.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MenuItemAdded {
+    pub restaurant_id: u64,
+    pub item_id: u64,
+    pub name: String,
+    pub price: i128,
+    pub token: Address,
+}
+
+impl Event for MenuItemAdded {
+    const ACTION: Symbol = symbol_short!("add");
+    const SUBJECT: Symbol = symbol_short!("menu");
+}
+
+/// A menu item's name, price or token changed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MenuItemUpdated {
+    pub restaurant_id: u64,
+    pub item_id: u64,
+    pub name: String,
+    pub price: i128,
+    pub token: Address,
+}
+
+impl Event for MenuItemUpdated {
+    const ACTION: Symbol = symbol_short!("update");
+    const SUBJECT: Symbol = symbol_short!("menu");
+}
+
+/// A menu item was marked available or unavailable.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MenuItemAvailabilitySet {
+    pub restaurant_id: u64,
+    pub item_id: u64,
+    pub available: bool,
+}
+
+impl Event for MenuItemAvailabilitySet {
+    const ACTION: Symbol = symbol_short!("avail");
+    const SUBJECT: Symbol = symbol_short!("menu");
+}
+
+/// A table was registered.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableAdded {
+    pub restaurant_id: u64,
+    pub table_id: u64,
+    pub label: String,
+}
+
+impl Event for TableAdded {
+    const ACTION: Symbol = symbol_short!("add");
+    const SUBJECT: Symbol = symbol_short!("table");
+}
+
+/// A table was renamed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableUpdated {
+    pub restaurant_id: u64,
+    pub table_id: u64,
+    pub label: String,
+}
+
+impl Event for TableUpdated {
+    const ACTION: Symbol = symbol_short!("update");
+    const SUBJECT: Symbol = symbol_short!("table");
+}
+
+/// A table was opened or closed for dine-in orders.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableActiveSet {
+    pub restaurant_id: u64,
+    pub table_id: u64,
+    pub active: bool,
+}
+
+impl Event for TableActiveSet {
+    const ACTION: Symbol = symbol_short!("setactive");
+    const SUBJECT: Symbol = symbol_short!("table");
+}
+
+/// A staff role was granted at a restaurant.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoleGranted {
+    pub restaurant_id: u64,
+    pub staff: Address,
+    pub role: StaffRole,
+}
+
+impl Event for RoleGranted {
+    const ACTION: Symbol = symbol_short!("grant");
+    const SUBJECT: Symbol = symbol_short!("staff");
+}
+
+/// A staff role was revoked at a restaurant.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoleRevoked {
+    pub restaurant_id: u64,
+    pub staff: Address,
+    pub role: StaffRole,
+}
+
+impl Event for RoleRevoked {
+    const ACTION: Symbol = symbol_short!("revoke");
+    const SUBJECT: Symbol = symbol_short!("staff");
+}
+
+/// An owner proposed handing a restaurant over.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnerTransferProposed {
+    pub restaurant_id: u64,
+    pub owner: Address,
+    pub new_owner: Address,
+}
+
+impl Event for OwnerTransferProposed {
+    const ACTION: Symbol = symbol_short!("propose");
+    const SUBJECT: Symbol = symbol_short!("owner");
+}
+
+/// The admin started recovering a restaurant for a new owner.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnerRecoveryStarted {
+    pub restaurant_id: u64,
+    pub new_owner: Address,
+    pub accept_after: u64,
+}
+
+impl Event for OwnerRecoveryStarted {
+    const ACTION: Symbol = symbol_short!("recover");
+    const SUBJECT: Symbol = symbol_short!("owner");
+}
+
+/// A restaurant changed owner.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnerTransferred {
+    pub restaurant_id: u64,
+    pub previous_owner: Address,
+    pub new_owner: Address,
+}
+
+impl Event for OwnerTransferred {
+    const ACTION: Symbol = symbol_short!("transfer");
+    const SUBJECT: Symbol = symbol_short!("owner");
+}
+
+/// A pending ownership transfer was withdrawn.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnerTransferCancelled {
+    pub restaurant_id: u64,
+    pub cancelled_by: Address,
+    pub new_owner: Address,
+}
+
+impl Event for OwnerTransferCancelled {
+    const ACTION: Symbol = symbol_short!("cancel");
+    const SUBJECT: Symbol = symbol_short!("owner");
+}
+
+/// A restaurant's profile was replaced.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProfileUpdated {
+    pub restaurant_id: u64,
+    pub version: u32,
+}
+
+impl Event for ProfileUpdated {
+    const ACTION: Symbol = symbol_short!("update");
+    const SUBJECT: Symbol = symbol_short!("profile");
+}
+
+/// A restaurant published its opening hours.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OpeningHoursSet {
+    pub restaurant_id: u64,
+}
+
+impl Event for OpeningHoursSet {
+    const ACTION: Symbol = symbol_short!("sethours");
+    const SUBJECT: Symbol = symbol_short!("rest");
+}
+
+/// A restaurant withdrew its opening hours.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OpeningHoursCleared {
+    pub restaurant_id: u64,
+}
+
+impl Event for OpeningHoursCleared {
+    const ACTION: Symbol = symbol_short!("clrhours");
+    const SUBJECT: Symbol = symbol_short!("rest");
+}
+
+/// The admin suspended a restaurant.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestaurantSuspended {
+    pub restaurant_id: u64,
+    pub reason: SuspensionReason,
+    pub until: Option<u64>,
+}
+
+impl Event for RestaurantSuspended {
+    const ACTION: Symbol = symbol_short!("suspend");
+    const SUBJECT: Symbol = symbol_short!("rest");
+}
+
+/// The admin lifted a suspension.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SuspensionLifted {
+    pub restaurant_id: u64,
+}
+
+impl Event for SuspensionLifted {
+    const ACTION: Symbol = symbol_short!("unsuspend");
+    const SUBJECT: Symbol = symbol_short!("rest");
+}
+
+/// An owner appealed a suspension.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SuspensionAppealed {
+    pub restaurant_id: u64,
+    pub evidence_hash: BytesN<32>,
+}
+
+impl Event for SuspensionAppealed {
+    const ACTION: Symbol = symbol_short!("appeal");
+    const SUBJECT: Symbol = symbol_short!("rest");
+}
+
+/// The admin appointed an attester.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttesterAdded {
+    pub attester: Address,
+}
+
+impl Event for AttesterAdded {
+    const ACTION: Symbol = symbol_short!("add");
+    const SUBJECT: Symbol = symbol_short!("attester");
+}
+
+/// The admin removed an attester.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttesterRemoved {
+    pub attester: Address,
+}
+
+impl Event for AttesterRemoved {
+    const ACTION: Symbol = symbol_short!("remove");
+    const SUBJECT: Symbol = symbol_short!("attester");
+}
+
+/// An attester recorded a verification.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestaurantAttested {
+    pub restaurant_id: u64,
+    pub level: VerificationLevel,
+    pub attester: Address,
+    pub expires_at: u64,
+}
+
+impl Event for RestaurantAttested {
+    const ACTION: Symbol = symbol_short!("attest");
+    const SUBJECT: Symbol = symbol_short!("rest");
+}
+
+/// A verification was revoked.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttestationRevoked {
+    pub restaurant_id: u64,
+    pub level: VerificationLevel,
+    pub revoked_by: Address,
+}
+
+impl Event for AttestationRevoked {
+    const ACTION: Symbol = symbol_short!("revoke");
+    const SUBJECT: Symbol = symbol_short!("rest");
+}
+
+/// The admin set the Order contract.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderContractSet {
+    pub order_contract: Address,
+}
+
+impl Event for OrderContractSet {
+    const ACTION: Symbol = symbol_short!("set");
+    const SUBJECT: Symbol = symbol_short!("orders");
+}
+
+/// The admin set the Payment contract.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaymentContractSet {
+    pub payment_contract: Address,
+}
+
+impl Event for PaymentContractSet {
+    const ACTION: Symbol = symbol_short!("set");
+    const SUBJECT: Symbol = symbol_short!("payments");
+}
+
+/// A customer reviewed a delivered order.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReviewSubmitted {
+    pub restaurant_id: u64,
+    pub order_id: u64,
+    pub customer: Address,
+    pub rating: u32,
+}
+
+impl Event for ReviewSubmitted {
+    const ACTION: Symbol = symbol_short!("submit");
+    const SUBJECT: Symbol = symbol_short!("review");
+}
+
+/// A restaurant responded to a review.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReviewResponded {
+    pub restaurant_id: u64,
+    pub order_id: u64,
+    pub responder: Address,
+}
+
+impl Event for ReviewResponded {
+    const ACTION: Symbol = symbol_short!("respond");
+    const SUBJECT: Symbol = symbol_short!("review");
+}
+
+/// A group was created.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupCreated {
+    pub group_id: u64,
+    pub owner: Address,
+    pub name: String,
+}
+
+impl Event for GroupCreated {
+    const ACTION: Symbol = symbol_short!("create");
+    const SUBJECT: Symbol = symbol_short!("group");
+}
+
+/// A group admin was added.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupAdminAdded {
+    pub group_id: u64,
+    pub admin: Address,
+}
+
+impl Event for GroupAdminAdded {
+    const ACTION: Symbol = symbol_short!("addadmin");
+    const SUBJECT: Symbol = symbol_short!("group");
+}
+
+/// A group admin was removed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupAdminRemoved {
+    pub group_id: u64,
+    pub admin: Address,
+}
+
+impl Event for GroupAdminRemoved {
+    const ACTION: Symbol = symbol_short!("rmadmin");
+    const SUBJECT: Symbol = symbol_short!("group");
+}
+
+/// A restaurant joined a group.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupMemberAdded {
+    pub group_id: u64,
+    pub restaurant_id: u64,
+}
+
+impl Event for GroupMemberAdded {
+    const ACTION: Symbol = symbol_short!("join");
+    const SUBJECT: Symbol = symbol_short!("group");
+}
+
+/// A restaurant left a group.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupMemberRemoved {
+    pub group_id: u64,
+    pub restaurant_id: u64,
+}
+
+impl Event for GroupMemberRemoved {
+    const ACTION: Symbol = symbol_short!("leave");
+    const SUBJECT: Symbol = symbol_short!("group");
+}
+
+/// A group's default settings changed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupDefaultsSet {
+    pub group_id: u64,
+    pub defaults: Settings,
+}
+
+impl Event for GroupDefaultsSet {
+    const ACTION: Symbol = symbol_short!("defaults");
+    const SUBJECT: Symbol = symbol_short!("group");
+}
+
+/// A restaurant's own settings changed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestaurantSettingsSet {
+    pub restaurant_id: u64,
+    pub settings: Settings,
+}
+
+impl Event for RestaurantSettingsSet {
+    const ACTION: Symbol = symbol_short!("settings");
+    const SUBJECT: Symbol = symbol_short!("rest");
+}
+
+/// A staff role was granted at every member of a group.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupRoleGranted {
+    pub group_id: u64,
+    pub staff: Address,
+    pub role: StaffRole,
+}
+
+impl Event for GroupRoleGranted {
+    const ACTION: Symbol = symbol_short!("grant");
+    const SUBJECT: Symbol = symbol_short!("grpstaff");
+}
+
+/// A group-wide staff role was revoked.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupRoleRevoked {
+    pub group_id: u64,
+    pub staff: Address,
+    pub role: StaffRole,
+}
+
+impl Event for GroupRoleRevoked {
+    const ACTION: Symbol = symbol_short!("revoke");
+    const SUBJECT: Symbol = symbol_short!("grpstaff");
+}
+
+/// The admin proposed a successor.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminTransferProposed {
+    pub admin: Address,
+    pub new_admin: Address,
+}
+
+impl Event for AdminTransferProposed {
+    const ACTION: Symbol = symbol_short!("propose");
+    const SUBJECT: Symbol = symbol_short!("admin");
+}
+
+/// A proposed admin accepted the role.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminTransferAccepted {
+    pub previous_admin: Address,
+    pub new_admin: Address,
+}
+
+impl Event for AdminTransferAccepted {
+    const ACTION: Symbol = symbol_short!("accept");
+    const SUBJECT: Symbol = symbol_short!("admin");
+}
+
+/// The admin withdrew a pending proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminTransferCancelled {
+    pub admin: Address,
+}
+
+impl Event for AdminTransferCancelled {
+    const ACTION: Symbol = symbol_short!("cancel");
+    const SUBJECT: Symbol = symbol_short!("admin");
+}
+
+/// The TTL policy was replaced.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TtlPolicySet {
+    pub policy: TtlPolicy,
+}
+
+impl Event for TtlPolicySet {
+    const ACTION: Symbol = symbol_short!("ttl");
+    const SUBJECT: Symbol = symbol_short!("policy");
+}
+
+/// The contract code was replaced.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractUpgraded {
+    pub new_wasm_hash: BytesN<32>,
+}
+
+impl Event for ContractUpgraded {
+    const ACTION: Symbol = symbol_short!("upgrade");
+    const SUBJECT: Symbol = symbol_short!("contract");
+}
+
+/// A batch of legacy restaurants was migrated.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractMigrated {
+    /// Stored layout version after this batch; it only changes with the
+    /// final batch.
+    pub schema_version: u32,
+    /// Number of restaurants rewritten by this batch.
+    pub migrated: u32,
+}
+
+impl Event for ContractMigrated {
+    const ACTION: Symbol = symbol_short!("migrate");
+    const SUBJECT: Symbol = symbol_short!("contract");
+}
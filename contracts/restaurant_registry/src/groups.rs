@@ -0,0 +1,637 @@
+//! # Groups
+//!
+//! A group is a franchise or restaurant group that spans owners and brands.
+//! Its owner appoints group admins, who manage the member restaurants, the
+//! group-wide default [`Settings`] and staff roles that apply at every
+//! member. A restaurant joins with the consent of both a group admin and its
+//! own owner, and belongs to at most one group.
+//!
+//! Each restaurant may override any default with its own settings;
+//! `effective_settings` resolves the value that applies, field by field:
+//! the restaurant's own, else its group's default, else unset.
+//!
+//! Settings are informational: they record what was agreed for use off
+//! chain. In particular the Payment contract always charges its own
+//! platform fee, not `fee_bps`, since restaurant owners can set their own.
+
+use soroban_sdk::{contractimpl, contracttype, vec, Address, Env, String, Vec};
+
+use crate::closing::assert_not_closed;
+use crate::events::{
+    Event, GroupAdminAdded, GroupAdminRemoved, GroupCreated, GroupDefaultsSet, GroupMemberAdded,
+    GroupMemberRemoved, GroupRoleGranted, GroupRoleRevoked, RestaurantSettingsSet,
+};
+use crate::{
+    append_to_list, assert_owner_or_admin, extend_instance, load_restaurant, remove_from_list,
+    ttl::save, validate_name, DataKey, Error, RestaurantRegistry, RestaurantRegistryArgs,
+    RestaurantRegistryClient, StaffRole,
+};
+
+/// Highest fee a [`Settings`] may record, in basis points (10 %). Matches
+/// the Payment contract's cap on its platform fee.
+pub const MAX_FEE_BPS: u32 = 1_000;
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+/// Shared settings, set as group defaults or as restaurant overrides.
+///
+/// Every `None` field is unset and falls through to the next level.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Settings {
+    /// Platform fee agreed for the restaurant, in basis points. Not charged
+    /// by the Payment contract.
+    pub fee_bps: Option<u32>,
+    /// SEP-41 token of the loyalty program the restaurant takes part in.
+    pub loyalty_token: Option<Address>,
+}
+
+impl Settings {
+    fn unset() -> Self {
+        Settings {
+            fee_bps: None,
+            loyalty_token: None,
+        }
+    }
+
+    /// `self`, with every unset field taken from `defaults`.
+    fn or(self, defaults: Settings) -> Self {
+        Settings {
+            fee_bps: self.fee_bps.or(defaults.fee_bps),
+            loyalty_token: self.loyalty_token.or(defaults.loyalty_token),
+        }
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        if self.fee_bps.is_some_and(|bps| bps > MAX_FEE_BPS) {
+            return Err(Error::InvalidFeeBps);
+        }
+        Ok(())
+    }
+}
+
+/// A franchise or restaurant group.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Group {
+    /// Auto-incrementing unique identifier (starts at 1).
+    pub id: u64,
+    /// Wallet that created the group; the only one who appoints admins.
+    pub owner: Address,
+    pub name: String,
+    /// Group admins besides the owner.
+    pub admins: Vec<Address>,
+    /// Settings member restaurants inherit.
+    pub defaults: Settings,
+    /// Ledger timestamp of creation.
+    pub created_at: u64,
+}
+
+impl Group {
+    /// Whether `addr` is the group's owner or one of its admins.
+    fn is_admin(&self, addr: &Address) -> bool {
+        addr == &self.owner || self.admins.contains(addr)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contractimpl]
+impl RestaurantRegistry {
+    /// Create a new group. The caller becomes its owner.
+    ///
+    /// # Returns
+    /// The newly assigned group ID (starts at 1).
+    pub fn create_group(env: Env, owner: Address, name: String) -> Result<u64, Error> {
+        owner.require_auth();
+        validate_name(&name)?;
+
+        let count: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::GroupCount)
+            .unwrap_or(0);
+        let group_id = count + 1;
+
+        let group = Group {
+            id: group_id,
+            owner: owner.clone(),
+            name: name.clone(),
+            admins: vec![&env],
+            defaults: Settings::unset(),
+            created_at: env.ledger().timestamp(),
+        };
+        save(&env, &DataKey::Group(group_id), &group);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::GroupCount, &group_id);
+        extend_instance(&env);
+
+        GroupCreated {
+            group_id,
+            owner,
+            name,
+        }
+        .publish(&env);
+        Ok(group_id)
+    }
+
+    /// Appoint a group admin (group owner only).
+    ///
+    /// # Errors
+    /// [`Error::RoleAlreadyGranted`] if `admin` already administers the
+    /// group.
+    pub fn add_group_admin(
+        env: Env,
+        caller: Address,
+        group_id: u64,
+        admin: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let mut group = Self::get_group(env.clone(), group_id)?;
+        if caller != group.owner {
+            return Err(Error::Unauthorized);
+        }
+        if group.is_admin(&admin) {
+            return Err(Error::RoleAlreadyGranted);
+        }
+        group.admins.push_back(admin.clone());
+        save(&env, &DataKey::Group(group_id), &group);
+
+        GroupAdminAdded { group_id, admin }.publish(&env);
+        Ok(())
+    }
+
+    /// Dismiss a group admin (group owner only).
+    pub fn remove_group_admin(
+        env: Env,
+        caller: Address,
+        group_id: u64,
+        admin: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let mut group = Self::get_group(env.clone(), group_id)?;
+        if caller != group.owner {
+            return Err(Error::Unauthorized);
+        }
+        let index = group
+            .admins
+            .first_index_of(&admin)
+            .ok_or(Error::RoleNotGranted)?;
+        group.admins.remove(index);
+        save(&env, &DataKey::Group(group_id), &group);
+
+        GroupAdminRemoved { group_id, admin }.publish(&env);
+        Ok(())
+    }
+
+    /// Add a restaurant to a group.
+    ///
+    /// `caller` must be a group admin, and the restaurant's owner must also
+    /// authorise the call.
+    ///
+    /// # Errors
+    /// [`Error::AlreadyInGroup`] if the restaurant belongs to a group.
+    pub fn add_group_member(
+        env: Env,
+        caller: Address,
+        group_id: u64,
+        restaurant_id: u64,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let group = Self::get_group(env.clone(), group_id)?;
+        if !group.is_admin(&caller) {
+            return Err(Error::Unauthorized);
+        }
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_not_closed(&env, restaurant_id)?;
+        if restaurant.owner != caller {
+            restaurant.owner.require_auth();
+        }
+
+        let key = DataKey::RestaurantGroup(restaurant_id);
+        if env.storage().persistent().has(&key) {
+            return Err(Error::AlreadyInGroup);
+        }
+        save(&env, &key, &group_id);
+        append_to_list(&env, DataKey::GroupMembers(group_id), restaurant_id);
+
+        GroupMemberAdded {
+            group_id,
+            restaurant_id,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Take a restaurant out of its group.
+    ///
+    /// Callable by an admin of the group or the restaurant's owner.
+    ///
+    /// # Errors
+    /// [`Error::GroupNotFound`] if the restaurant belongs to no group.
+    pub fn remove_group_member(env: Env, caller: Address, restaurant_id: u64) -> Result<(), Error> {
+        caller.require_auth();
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        let key = DataKey::RestaurantGroup(restaurant_id);
+        let group_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::GroupNotFound)?;
+        let group = Self::get_group(env.clone(), group_id)?;
+        if caller != restaurant.owner && !group.is_admin(&caller) {
+            return Err(Error::Unauthorized);
+        }
+
+        leave_group(&env, group_id, restaurant_id);
+        Ok(())
+    }
+
+    /// Replace a group's default settings (group admins only).
+    ///
+    /// # Errors
+    /// [`Error::InvalidFeeBps`] if `fee_bps` exceeds [`MAX_FEE_BPS`].
+    pub fn set_group_defaults(
+        env: Env,
+        caller: Address,
+        group_id: u64,
+        defaults: Settings,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let mut group = Self::get_group(env.clone(), group_id)?;
+        if !group.is_admin(&caller) {
+            return Err(Error::Unauthorized);
+        }
+        defaults.validate()?;
+        group.defaults = defaults.clone();
+        save(&env, &DataKey::Group(group_id), &group);
+
+        GroupDefaultsSet { group_id, defaults }.publish(&env);
+        Ok(())
+    }
+
+    /// Replace a restaurant's own settings, which take precedence over its
+    /// group's defaults.
+    ///
+    /// Callable by the restaurant's owner **or** the contract admin.
+    ///
+    /// # Errors
+    /// [`Error::InvalidFeeBps`] if `fee_bps` exceeds [`MAX_FEE_BPS`].
+    pub fn set_restaurant_settings(
+        env: Env,
+        caller: Address,
+        restaurant_id: u64,
+        settings: Settings,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_owner_or_admin(&env, &caller, &restaurant)?;
+        settings.validate()?;
+        save(&env, &DataKey::RestaurantSettings(restaurant_id), &settings);
+
+        RestaurantSettingsSet {
+            restaurant_id,
+            settings,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Grant `role` to `staff` at every member of a group (group admins
+    /// only).
+    pub fn grant_group_role(
+        env: Env,
+        caller: Address,
+        group_id: u64,
+        staff: Address,
+        role: StaffRole,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let group = Self::get_group(env.clone(), group_id)?;
+        if !group.is_admin(&caller) {
+            return Err(Error::Unauthorized);
+        }
+        let mut roles = Self::get_group_staff_roles(env.clone(), group_id, staff.clone());
+        if roles.contains(role) {
+            return Err(Error::RoleAlreadyGranted);
+        }
+        roles.push_back(role);
+        save(
+            &env,
+            &DataKey::GroupStaffRoles(group_id, staff.clone()),
+            &roles,
+        );
+
+        GroupRoleGranted {
+            group_id,
+            staff,
+            role,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Revoke a group-wide `role` from `staff` (group admins only).
+    pub fn revoke_group_role(
+        env: Env,
+        caller: Address,
+        group_id: u64,
+        staff: Address,
+        role: StaffRole,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let group = Self::get_group(env.clone(), group_id)?;
+        if !group.is_admin(&caller) {
+            return Err(Error::Unauthorized);
+        }
+        let mut roles = Self::get_group_staff_roles(env.clone(), group_id, staff.clone());
+        let index = roles.first_index_of(role).ok_or(Error::RoleNotGranted)?;
+        roles.remove(index);
+
+        let key = DataKey::GroupStaffRoles(group_id, staff.clone());
+        if roles.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            save(&env, &key, &roles);
+        }
+
+        GroupRoleRevoked {
+            group_id,
+            staff,
+            role,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Reads (view)
+    // -----------------------------------------------------------------------
+
+    /// Fetch a group by its numeric ID.
+    pub fn get_group(env: Env, group_id: u64) -> Result<Group, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Group(group_id))
+            .ok_or(Error::GroupNotFound)
+    }
+
+    /// Return the IDs of a group's member restaurants, in joining order.
+    pub fn get_group_members(env: Env, group_id: u64) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::GroupMembers(group_id))
+            .unwrap_or_else(|| vec![&env])
+    }
+
+    /// Return the group a restaurant belongs to, if any.
+    pub fn get_restaurant_group(env: Env, restaurant_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RestaurantGroup(restaurant_id))
+    }
+
+    /// Return the roles `addr` holds at every member of a group.
+    pub fn get_group_staff_roles(env: Env, group_id: u64, addr: Address) -> Vec<StaffRole> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::GroupStaffRoles(group_id, addr))
+            .unwrap_or_else(|| vec![&env])
+    }
+
+    /// Return a restaurant's own settings, without its group's defaults.
+    pub fn get_restaurant_settings(env: Env, restaurant_id: u64) -> Settings {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RestaurantSettings(restaurant_id))
+            .unwrap_or_else(Settings::unset)
+    }
+
+    /// Resolve the settings that apply to a restaurant: its own where set,
+    /// else its group's defaults.
+    pub fn effective_settings(env: Env, restaurant_id: u64) -> Result<Settings, Error> {
+        load_restaurant(&env, restaurant_id)?;
+        let own = Self::get_restaurant_settings(env.clone(), restaurant_id);
+        Ok(
+            match Self::get_restaurant_group(env.clone(), restaurant_id) {
+                Some(group_id) => own.or(Self::get_group(env, group_id)?.defaults),
+                None => own,
+            },
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Take a restaurant out of `group_id`, the group it belongs to.
+pub(crate) fn leave_group(env: &Env, group_id: u64, restaurant_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::RestaurantGroup(restaurant_id));
+    remove_from_list(env, DataKey::GroupMembers(group_id), restaurant_id);
+
+    GroupMemberRemoved {
+        group_id,
+        restaurant_id,
+    }
+    .publish(env);
+}
+
+/// Roles `addr` holds at a restaurant through its group; empty if the
+/// restaurant is in no group.
+pub(crate) fn group_roles(env: &Env, restaurant_id: u64, addr: &Address) -> Vec<StaffRole> {
+    match RestaurantRegistry::get_restaurant_group(env.clone(), restaurant_id) {
+        Some(group_id) => {
+            RestaurantRegistry::get_group_staff_roles(env.clone(), group_id, addr.clone())
+        }
+        None => vec![env],
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::{Settings, MAX_FEE_BPS};
+    use crate::events::{assert_last_event, GroupMemberAdded, GroupMemberRemoved};
+    use crate::{Error, Permission, RestaurantRegistry, RestaurantRegistryClient, StaffRole};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{vec, Address, Env, String};
+
+    /// A registry with a group owned by `group_owner` and a restaurant owned
+    /// by someone else.
+    fn setup() -> (
+        Env,
+        RestaurantRegistryClient<'static>,
+        Address,
+        u64,
+        Address,
+        u64,
+    ) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(RestaurantRegistry, ());
+        let client = RestaurantRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let group_owner = Address::generate(&env);
+        let group_id = client.create_group(&group_owner, &String::from_str(&env, "Naija Eats"));
+
+        let owner = Address::generate(&env);
+        let id = client.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "mamas-kitchen"),
+        );
+        (env, client, group_owner, group_id, owner, id)
+    }
+
+    fn settings(fee_bps: Option<u32>, loyalty_token: Option<Address>) -> Settings {
+        Settings {
+            fee_bps,
+            loyalty_token,
+        }
+    }
+
+    #[test]
+    fn test_group_membership_and_admins() {
+        let (env, client, group_owner, group_id, owner, id) = setup();
+        let admin = Address::generate(&env);
+
+        client.add_group_admin(&group_owner, &group_id, &admin);
+        assert_eq!(
+            client.get_group(&group_id).admins,
+            vec![&env, admin.clone()]
+        );
+        assert_eq!(
+            client.try_add_group_admin(&admin, &group_id, &Address::generate(&env)),
+            Err(Ok(Error::Unauthorized))
+        );
+
+        client.add_group_member(&admin, &group_id, &id);
+        assert_last_event(
+            &env,
+            &client.address,
+            GroupMemberAdded {
+                group_id,
+                restaurant_id: id,
+            },
+        );
+        assert_eq!(client.get_restaurant_group(&id), Some(group_id));
+        assert_eq!(client.get_group_members(&group_id), vec![&env, id]);
+        let other_group = client.create_group(&owner, &String::from_str(&env, "Other"));
+        assert_eq!(
+            client.try_add_group_member(&owner, &other_group, &id),
+            Err(Ok(Error::AlreadyInGroup))
+        );
+
+        // The owner may leave; the admin may then no longer act for them.
+        client.remove_group_member(&owner, &id);
+        assert_last_event(
+            &env,
+            &client.address,
+            GroupMemberRemoved {
+                group_id,
+                restaurant_id: id,
+            },
+        );
+        assert_eq!(client.get_restaurant_group(&id), None);
+        assert_eq!(client.get_group_members(&group_id), vec![&env]);
+        assert_eq!(
+            client.try_remove_group_member(&admin, &id),
+            Err(Ok(Error::GroupNotFound))
+        );
+
+        client.remove_group_admin(&group_owner, &group_id, &admin);
+        assert_eq!(
+            client.try_add_group_member(&admin, &group_id, &id),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_effective_settings() {
+        let (env, client, group_owner, group_id, owner, id) = setup();
+        let loyalty = Address::generate(&env);
+
+        // Without a group, only the restaurant's own settings apply.
+        client.set_restaurant_settings(&owner, &id, &settings(Some(150), None));
+        assert_eq!(client.effective_settings(&id), settings(Some(150), None));
+
+        client.add_group_member(&group_owner, &group_id, &id);
+        client.set_group_defaults(
+            &group_owner,
+            &group_id,
+            &settings(Some(50), Some(loyalty.clone())),
+        );
+        assert_eq!(
+            client.effective_settings(&id),
+            settings(Some(150), Some(loyalty.clone()))
+        );
+
+        client.set_restaurant_settings(&owner, &id, &settings(None, None));
+        assert_eq!(
+            client.effective_settings(&id),
+            settings(Some(50), Some(loyalty))
+        );
+
+        assert_eq!(
+            client.try_set_group_defaults(
+                &group_owner,
+                &group_id,
+                &settings(Some(MAX_FEE_BPS + 1), None)
+            ),
+            Err(Ok(Error::InvalidFeeBps))
+        );
+        assert_eq!(
+            client.try_set_group_defaults(&owner, &group_id, &settings(None, None)),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_fee_cap_matches_payment() {
+        assert_eq!(MAX_FEE_BPS, payment::MAX_FEE_BPS);
+    }
+
+    #[test]
+    fn test_group_staff_roles_apply_to_members() {
+        let (env, client, group_owner, group_id, owner, id) = setup();
+        let staff = Address::generate(&env);
+        client.grant_group_role(&group_owner, &group_id, &staff, &StaffRole::Kitchen);
+
+        // Not yet a member: the group role does not reach the restaurant.
+        assert!(!client.has_role(&id, &staff, &StaffRole::Kitchen));
+
+        client.add_group_member(&group_owner, &group_id, &id);
+        assert!(client.has_role(&id, &staff, &StaffRole::Kitchen));
+        assert!(client.has_permission(&id, &staff, &Permission::PrepareOrder));
+        assert!(!client.has_permission(&id, &staff, &Permission::ManageMenu));
+        assert_eq!(client.get_staff_roles(&id, &staff), vec![&env]);
+
+        client.revoke_group_role(&group_owner, &group_id, &staff, &StaffRole::Kitchen);
+        assert!(!client.has_role(&id, &staff, &StaffRole::Kitchen));
+        assert_eq!(
+            client.try_revoke_group_role(&owner, &group_id, &staff, &StaffRole::Kitchen),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+}
@@ -0,0 +1,382 @@
+//! # Opening hours
+//!
+//! Owners publish a weekly schedule of opening periods in local time, a UTC
+//! offset, and one-off closures (holidays, refurbishments). `is_open_at`
+//! combines these with the restaurant's status; the Order contract uses
+//! it to refuse orders while a restaurant is closed.
+//!
+//! Restaurants without a published schedule are treated as always open.
+
+use soroban_sdk::{contractimpl, contracttype, Address, Env, Vec};
+
+use crate::events::{Event, OpeningHoursCleared, OpeningHoursSet};
+use crate::{
+    assert_owner_or_admin, extend_persistent, load_restaurant, DataKey, Error, HoursError,
+    RestaurantRegistry, RestaurantRegistryArgs, RestaurantRegistryClient, RestaurantStatus,
+};
+
+/// Maximum number of weekly opening periods in a schedule.
+pub const MAX_OPENING_PERIODS: u32 = 21;
+/// Maximum number of one-off closures in a schedule.
+pub const MAX_CLOSURES: u32 = 32;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+const MINUTES_PER_DAY: u32 = 1_440;
+/// Largest UTC offset in use anywhere (UTC+14), in seconds.
+const MAX_UTC_OFFSET: i32 = 14 * 3_600;
+/// Smallest UTC offset in use anywhere (UTC-12), in seconds.
+const MIN_UTC_OFFSET: i32 = -12 * 3_600;
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+/// A recurring weekly opening period, in the restaurant's local time.
+#[contracttype]
+#[derive(Clone)]
+pub struct OpeningPeriod {
+    /// Day the period starts on: 0 = Monday … 6 = Sunday.
+    pub day: u32,
+    /// Opening time in minutes after local midnight (0–1439).
+    pub open_minute: u32,
+    /// Closing time in minutes after local midnight (0–1440). A value not
+    /// greater than `open_minute` means the period runs past midnight and
+    /// closes on the following day.
+    pub close_minute: u32,
+}
+
+/// A one-off closure, as a `[start, end)` range of UTC timestamps.
+#[contracttype]
+#[derive(Clone)]
+pub struct Closure {
+    pub start: u64,
+    pub end: u64,
+}
+
+/// A restaurant's published opening-hours schedule.
+#[contracttype]
+#[derive(Clone)]
+pub struct OpeningHours {
+    /// Offset of local time from UTC, in seconds (e.g. 3600 for WAT).
+    pub utc_offset: i32,
+    /// Weekly opening periods. An empty list means never open.
+    pub periods: Vec<OpeningPeriod>,
+    /// Holiday and other one-off closures.
+    pub closures: Vec<Closure>,
+}
+
+impl OpeningHours {
+    fn is_open_at(&self, timestamp: u64) -> bool {
+        for closure in self.closures.iter() {
+            if closure.start <= timestamp && timestamp < closure.end {
+                return false;
+            }
+        }
+
+        let local = timestamp as i64 + self.utc_offset as i64;
+        let local_day = local.div_euclid(SECONDS_PER_DAY);
+        let minute = (local.rem_euclid(SECONDS_PER_DAY) / 60) as u32;
+        // 1970-01-01 was a Thursday (day 3 with Monday = 0).
+        let weekday = (local_day + 3).rem_euclid(7) as u32;
+
+        self.periods.iter().any(|period| {
+            if period.close_minute > period.open_minute {
+                period.day == weekday
+                    && period.open_minute <= minute
+                    && minute < period.close_minute
+            } else {
+                (period.day == weekday && minute >= period.open_minute)
+                    || ((period.day + 1) % 7 == weekday && minute < period.close_minute)
+            }
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contractimpl]
+impl RestaurantRegistry {
+    /// Publish or replace a restaurant's opening-hours schedule.
+    ///
+    /// Callable by the restaurant's owner **or** the contract admin.
+    pub fn set_opening_hours(
+        env: Env,
+        caller: Address,
+        restaurant_id: u64,
+        hours: OpeningHours,
+    ) -> Result<(), HoursError> {
+        caller.require_auth();
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_owner_or_admin(&env, &caller, &restaurant)?;
+
+        if !(MIN_UTC_OFFSET..=MAX_UTC_OFFSET).contains(&hours.utc_offset) {
+            return Err(HoursError::InvalidUtcOffset);
+        }
+        if hours.periods.len() > MAX_OPENING_PERIODS {
+            return Err(HoursError::TooManyOpeningPeriods);
+        }
+        if hours.closures.len() > MAX_CLOSURES {
+            return Err(HoursError::TooManyClosures);
+        }
+        for period in hours.periods.iter() {
+            if period.day > 6
+                || period.open_minute >= MINUTES_PER_DAY
+                || period.close_minute > MINUTES_PER_DAY
+            {
+                return Err(HoursError::InvalidOpeningPeriod);
+            }
+        }
+        for closure in hours.closures.iter() {
+            if closure.end <= closure.start {
+                return Err(HoursError::InvalidClosure);
+            }
+        }
+
+        let key = DataKey::OpeningHours(restaurant_id);
+        env.storage().persistent().set(&key, &hours);
+        extend_persistent(&env, &key);
+
+        OpeningHoursSet { restaurant_id }.publish(&env);
+        Ok(())
+    }
+
+    /// Remove a restaurant's schedule, making it open whenever it is active.
+    ///
+    /// Callable by the restaurant's owner **or** the contract admin.
+    pub fn clear_opening_hours(env: Env, caller: Address, restaurant_id: u64) -> Result<(), Error> {
+        caller.require_auth();
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_owner_or_admin(&env, &caller, &restaurant)?;
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::OpeningHours(restaurant_id));
+
+        OpeningHoursCleared { restaurant_id }.publish(&env);
+        Ok(())
+    }
+
+    /// Return a restaurant's published schedule, if any.
+    pub fn get_opening_hours(env: Env, restaurant_id: u64) -> Option<OpeningHours> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OpeningHours(restaurant_id))
+    }
+
+    /// Whether a restaurant accepts orders at `timestamp` (UTC seconds).
+    ///
+    /// Inactive and suspended restaurants are never open. Restaurants
+    /// without a schedule are open whenever they are active.
+    pub fn is_open_at(env: Env, restaurant_id: u64, timestamp: u64) -> Result<bool, Error> {
+        if Self::get_status(env.clone(), restaurant_id)? != RestaurantStatus::Active {
+            return Ok(false);
+        }
+        Ok(match Self::get_opening_hours(env, restaurant_id) {
+            None => true,
+            Some(hours) => hours.is_open_at(timestamp),
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::{Closure, OpeningHours, OpeningPeriod, MAX_CLOSURES};
+    use crate::{HoursError, RestaurantRegistry, RestaurantRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{vec, Address, Env, String};
+
+    /// Monday 2024-01-01 00:00:00 UTC.
+    const MONDAY: u64 = 1_704_067_200;
+    const HOUR: u64 = 3_600;
+    const DAY: u64 = 24 * HOUR;
+
+    fn setup() -> (Env, RestaurantRegistryClient<'static>, Address, u64) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(RestaurantRegistry, ());
+        let client = RestaurantRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&Address::generate(&env));
+        let id = client.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "mamas-kitchen"),
+        );
+        (env, client, owner, id)
+    }
+
+    fn period(day: u32, open_hour: u32, close_hour: u32) -> OpeningPeriod {
+        OpeningPeriod {
+            day,
+            open_minute: open_hour * 60,
+            close_minute: close_hour * 60,
+        }
+    }
+
+    #[test]
+    fn test_no_schedule_is_always_open() {
+        let (_env, client, owner, id) = setup();
+        assert!(client.is_open_at(&id, &MONDAY));
+
+        client.set_active(&owner, &id, &false);
+        assert!(!client.is_open_at(&id, &MONDAY));
+    }
+
+    #[test]
+    fn test_weekly_schedule_with_offset() {
+        let (env, client, owner, id) = setup();
+
+        // Monday 09:00–17:00 local time at UTC+1.
+        client.set_opening_hours(
+            &owner,
+            &id,
+            &OpeningHours {
+                utc_offset: 3_600,
+                periods: vec![&env, period(0, 9, 17)],
+                closures: vec![&env],
+            },
+        );
+
+        assert!(!client.is_open_at(&id, &(MONDAY + 7 * HOUR))); // 08:00 local
+        assert!(client.is_open_at(&id, &(MONDAY + 8 * HOUR))); // 09:00 local
+        assert!(client.is_open_at(&id, &(MONDAY + 15 * HOUR))); // 16:00 local
+        assert!(!client.is_open_at(&id, &(MONDAY + 16 * HOUR))); // 17:00 local
+        assert!(!client.is_open_at(&id, &(MONDAY + DAY + 10 * HOUR))); // Tuesday
+    }
+
+    #[test]
+    fn test_overnight_period() {
+        let (env, client, owner, id) = setup();
+
+        // Friday 18:00 until Saturday 02:00, UTC.
+        client.set_opening_hours(
+            &owner,
+            &id,
+            &OpeningHours {
+                utc_offset: 0,
+                periods: vec![&env, period(4, 18, 2)],
+                closures: vec![&env],
+            },
+        );
+
+        let friday = MONDAY + 4 * DAY;
+        assert!(!client.is_open_at(&id, &(friday + 17 * HOUR)));
+        assert!(client.is_open_at(&id, &(friday + 23 * HOUR)));
+        assert!(client.is_open_at(&id, &(friday + DAY + HOUR)));
+        assert!(!client.is_open_at(&id, &(friday + DAY + 3 * HOUR)));
+    }
+
+    #[test]
+    fn test_holiday_closure() {
+        let (env, client, owner, id) = setup();
+
+        client.set_opening_hours(
+            &owner,
+            &id,
+            &OpeningHours {
+                utc_offset: 0,
+                periods: vec![&env, period(0, 0, 0)], // all day Monday
+                closures: vec![
+                    &env,
+                    Closure {
+                        start: MONDAY,
+                        end: MONDAY + DAY,
+                    },
+                ],
+            },
+        );
+
+        assert!(!client.is_open_at(&id, &(MONDAY + 12 * HOUR)));
+        assert!(client.is_open_at(&id, &(MONDAY + 7 * DAY + 12 * HOUR)));
+
+        client.clear_opening_hours(&owner, &id);
+        assert!(client.get_opening_hours(&id).is_none());
+        assert!(client.is_open_at(&id, &(MONDAY + 12 * HOUR)));
+    }
+
+    #[test]
+    fn test_invalid_period_rejected() {
+        let (env, client, owner, id) = setup();
+        assert_eq!(
+            client.try_set_opening_hours(
+                &owner,
+                &id,
+                &OpeningHours {
+                    utc_offset: 0,
+                    periods: vec![&env, period(7, 9, 17)],
+                    closures: vec![&env],
+                },
+            ),
+            Err(Ok(HoursError::InvalidOpeningPeriod))
+        );
+    }
+
+    #[test]
+    fn test_utc_offset_bounds() {
+        let (env, client, owner, id) = setup();
+        let hours = |utc_offset: i32| OpeningHours {
+            utc_offset,
+            periods: vec![&env],
+            closures: vec![&env],
+        };
+
+        client.set_opening_hours(&owner, &id, &hours(14 * 3_600));
+        client.set_opening_hours(&owner, &id, &hours(-12 * 3_600));
+        for offset in [14 * 3_600 + 1, -12 * 3_600 - 1, -14 * 3_600] {
+            assert_eq!(
+                client.try_set_opening_hours(&owner, &id, &hours(offset)),
+                Err(Ok(HoursError::InvalidUtcOffset))
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_closures_rejected() {
+        let (env, client, owner, id) = setup();
+        let closure = Closure {
+            start: MONDAY,
+            end: MONDAY + DAY,
+        };
+        let mut closures = vec![&env];
+        for _ in 0..MAX_CLOSURES {
+            closures.push_back(closure.clone());
+        }
+        let mut hours = OpeningHours {
+            utc_offset: 0,
+            periods: vec![&env],
+            closures,
+        };
+        client.set_opening_hours(&owner, &id, &hours);
+
+        hours.closures.push_back(closure);
+        assert_eq!(
+            client.try_set_opening_hours(&owner, &id, &hours),
+            Err(Ok(HoursError::TooManyClosures))
+        );
+
+        hours.closures = vec![
+            &env,
+            Closure {
+                start: MONDAY,
+                end: MONDAY,
+            },
+        ];
+        assert_eq!(
+            client.try_set_opening_hours(&owner, &id, &hours),
+            Err(Ok(HoursError::InvalidClosure))
+        );
+        assert_eq!(
+            client.get_opening_hours(&id).unwrap().closures.len(),
+            MAX_CLOSURES
+        );
+    }
+}
@@ -7,11 +7,127 @@
 //! ## Roles
 //! - **Admin** – contract deployer; can deactivate any restaurant.
 //! - **Owner** – the wallet that registered a restaurant; can update its
-//!   own restaurant metadata and toggle its active flag.
+//!   own restaurant metadata, toggle its active flag and manage its menu.
+//!   An owner may hold any number of restaurants, and can hand one over to a
+//!   new wallet with a propose/accept transfer.
+//! - **Staff** – accounts granted a [`StaffRole`] at one restaurant by its
+//!   owner; each role carries a fixed set of [`Permission`]s.
+//!
+//! ## Profiles
+//! Besides the core `Restaurant` record, each restaurant has a versioned
+//! [`RestaurantProfile`] holding its payout wallet, accepted tokens and
+//! discovery metadata. The Payment contract pays out to the profile's wallet.
+//!
+//! ## Opening hours
+//! Owners may publish an [`OpeningHours`] schedule. `is_open_at` answers
+//! whether a restaurant takes orders at a given time, and the Order contract
+//! enforces it when orders are placed.
+//!
+//! ## Suspension
+//! Separately from the owner's `is_active` toggle, the admin can suspend a
+//! restaurant with a [`SuspensionReason`] and an optional end time. Owners
+//! cannot lift a suspension, only appeal it; `get_status` and `is_open_at`
+//! both reflect it.
+//!
+//! ## Verification
+//! Admin-appointed attesters record [`Attestation`]s such as identity or
+//! food-licence checks, each with an expiry. `verification_level` reports
+//! the highest level still in force; the Order and Payment contracts can be
+//! configured to require a minimum level.
+//!
+//! ## Naming rules
+//! Slugs are used as subdomains and must be lowercase `[a-z0-9-]`, between
+//! [`MIN_SLUG_LEN`] and [`MAX_SLUG_LEN`] bytes, and not a reserved word.
+//! Names are capped at [`MAX_NAME_LEN`] bytes. Violations are reported as
+//! specific [`Error`] codes.
+//!
+//! ## Brands and branches
+//! Multi-location groups create a [`Brand`] and register each location as a
+//! branch of it. Every branch is a full `Restaurant` with its own ID, slug,
+//! active flag and menu; the brand only groups them for listing.
+//!
+//! ## Tables
+//! Dine-in restaurants register each [`Table`] (or QR code) with a label and
+//! an active flag. The Order contract only accepts dine-in orders for active
+//! tables.
+//!
+//! ## Reviews
+//! Customers rate and review their own delivered orders, one [`Review`] per
+//! order, checked against the Order contract. Restaurants may respond once
+//! to each review, and [`RatingSummary`] aggregates ratings per restaurant.
+//!
+//! ## Groups
+//! A franchise or restaurant [`Group`] spans owners and brands. Group admins
+//! manage its member restaurants, staff roles that apply at every member and
+//! default [`Settings`] such as the fee agreement and loyalty program.
+//! Members may override each setting; `effective_settings` resolves them.
+//!
+//! ## Closing
+//! `close_restaurant` retires a restaurant for good once the Order and
+//! Payment contracts report no open orders or escrowed payments. Its slug
+//! and owner index are released, and a [`Tombstone`] is kept for audit.
+//!
+//! ## Events
+//! Every state change publishes a typed, versioned event; see [`events`].
+//!
+//! ## Storage TTL
+//! Writes extend entries according to an admin-set [`TtlPolicy`]. Anyone can
+//! keep a restaurant alive with `bump_restaurant`, and `restaurant_ttl`
+//! reports how long its record has left.
+//!
+//! ## Upgrades
+//! The admin can replace the contract code with `upgrade`. Stored records
+//! carry a [`SCHEMA_VERSION`]; after an upgrade that changes their layout,
+//! `migrate` rewrites legacy records in batches.
 
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, contracttype, vec, Address, Env, String, Vec};
+
+mod brands;
+mod closing;
+mod error;
+pub mod events;
+mod groups;
+mod hours;
+mod menu;
+pub mod orders;
+mod ownership;
+pub mod payments;
+mod profile;
+mod reviews;
+mod staff;
+mod suspension;
+mod tables;
+mod ttl;
+mod upgrade;
+mod validation;
+mod verification;
+
+use closing::assert_not_closed;
+use events::{
+    AdminTransferAccepted, AdminTransferCancelled, AdminTransferProposed, Event, Initialized,
+    RestaurantActiveSet, RestaurantRegistered, RestaurantUpdated,
+};
+use ttl::{extend_instance, extend_persistent, extend_restaurant};
+use validation::{validate_geohash, validate_name, validate_slug};
+
+pub use brands::Brand;
+pub use closing::Tombstone;
+pub use error::{Error, HoursError, ReviewError};
+pub use groups::{Group, Settings, MAX_FEE_BPS};
+pub use hours::{Closure, OpeningHours, OpeningPeriod, MAX_CLOSURES, MAX_OPENING_PERIODS};
+pub use menu::{MenuItem, MenuPage};
+pub use ownership::{OwnerTransfer, RECOVERY_DELAY};
+pub use profile::{ProfileUpdate, RestaurantProfile, MAX_ACCEPTED_TOKENS, MAX_CUISINE_TAGS};
+pub use reviews::{RatingSummary, Review, MAX_RATING, MIN_RATING};
+pub use staff::{Permission, StaffRole};
+pub use suspension::{RestaurantStatus, Suspension, SuspensionReason};
+pub use tables::Table;
+pub use ttl::{TtlPolicy, DAY_IN_LEDGERS};
+pub use upgrade::SCHEMA_VERSION;
+pub use validation::{MAX_GEOHASH_LEN, MAX_NAME_LEN, MAX_SLUG_LEN, MIN_SLUG_LEN};
+pub use verification::{Attestation, VerificationLevel};
 
 // ---------------------------------------------------------------------------
 // Storage types
@@ -25,9 +141,11 @@ pub struct Restaurant {
     pub id: u64,
     /// Stellar address of the restaurant owner.
     pub owner: Address,
+    /// Parent brand ID, or `0` for a standalone restaurant.
+    pub brand_id: u64,
     /// Human-readable restaurant name.
     pub name: String,
-    /// URL-safe slug used for subdomain routing.
+    /// URL-safe slug used for subdomain routing. Unique across the registry.
     pub slug: String,
     /// Whether the restaurant is accepting orders.
     pub is_active: bool,
@@ -35,17 +153,148 @@ pub struct Restaurant {
     pub created_at: u64,
 }
 
+/// Optional criteria for [`RestaurantRegistry::list_restaurants`].
+///
+/// Every `None` field matches all restaurants.
+#[contracttype]
+#[derive(Clone)]
+pub struct RestaurantFilter {
+    /// Only restaurants whose `is_active` flag equals this value.
+    pub is_active: Option<bool>,
+    /// Only restaurants owned by this address.
+    pub owner: Option<Address>,
+    /// Only restaurants with `created_at >= created_after`.
+    pub created_after: Option<u64>,
+    /// Only restaurants with `created_at < created_before`.
+    pub created_before: Option<u64>,
+}
+
+impl RestaurantFilter {
+    fn matches(&self, restaurant: &Restaurant) -> bool {
+        if let Some(active) = self.is_active {
+            if restaurant.is_active != active {
+                return false;
+            }
+        }
+        if let Some(owner) = &self.owner {
+            if &restaurant.owner != owner {
+                return false;
+            }
+        }
+        if let Some(after) = self.created_after {
+            if restaurant.created_at < after {
+                return false;
+            }
+        }
+        if let Some(before) = self.created_before {
+            if restaurant.created_at >= before {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// One page of [`RestaurantRegistry::list_restaurants`].
+#[contracttype]
+#[derive(Clone)]
+pub struct RestaurantPage {
+    /// Matching restaurants, in ID order.
+    pub restaurants: Vec<Restaurant>,
+    /// ID to pass as `start` to fetch the next page; `None` once the end of
+    /// the registry has been reached.
+    pub next: Option<u64>,
+}
+
+/// Maximum number of records returned by a single listing call.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Maximum number of records examined by a single filtered listing call,
+/// matching or not.
+pub const MAX_SCAN: u32 = 200;
+
 /// Storage key discriminants.
 #[contracttype]
 pub enum DataKey {
     /// Singleton: contract admin address.
     Admin,
+    /// Singleton: proposed next admin awaiting `accept_admin_transfer`.
+    PendingAdmin,
     /// Singleton: total number of registered restaurants.
     Count,
     /// Per-restaurant data keyed by numeric ID.
     Restaurant(u64),
-    /// Reverse lookup: owner address → restaurant ID.
-    OwnerToId(Address),
+    /// Reverse lookup: owner address → IDs of every restaurant they own.
+    OwnerRestaurants(Address),
+    /// Reverse lookup: slug → restaurant ID. Enforces slug uniqueness.
+    SlugToId(String),
+    /// Menu item keyed by (restaurant ID, item ID).
+    MenuItem(u64, u64),
+    /// Number of menu items ever added to a restaurant.
+    MenuCount(u64),
+    /// Singleton: total number of brands created.
+    BrandCount,
+    /// Per-brand data keyed by numeric ID.
+    Brand(u64),
+    /// IDs of the restaurants registered as branches of a brand.
+    BrandRestaurants(u64),
+    /// IDs of the brands created by an owner.
+    OwnerBrands(Address),
+    /// Roles held by a staff account at a restaurant.
+    StaffRoles(u64, Address),
+    /// Every address holding at least one role at a restaurant.
+    StaffMembers(u64),
+    /// Pending ownership transfer of a restaurant.
+    PendingOwner(u64),
+    /// Extended profile of a restaurant.
+    Profile(u64),
+    /// Published opening-hours schedule of a restaurant.
+    OpeningHours(u64),
+    /// Marks an address as an appointed attester.
+    Attester(Address),
+    /// Attestations recorded for a restaurant, at most one per level.
+    Attestations(u64),
+    /// Admin-imposed suspension of a restaurant.
+    Suspension(u64),
+    /// Singleton: layout version of the stored records.
+    SchemaVersion,
+    /// Singleton: next restaurant ID `migrate` will examine, while a
+    /// migration is in progress.
+    MigrationCursor,
+    /// Singleton: TTL policy set by the admin.
+    TtlPolicy,
+    /// Ledger until which a restaurant record is known to live.
+    LiveUntil(u64),
+    /// Singleton: Order contract that reviews are checked against.
+    OrderContract,
+    /// Review keyed by the order ID it reviews.
+    Review(u64),
+    /// Order ID of a restaurant's review, keyed by (restaurant ID, index
+    /// in order of submission). The count is the rating summary's
+    /// `review_count`.
+    RestaurantReview(u64, u32),
+    /// Aggregate of a restaurant's ratings.
+    RatingSummary(u64),
+    /// Table keyed by (restaurant ID, table ID).
+    Table(u64, u64),
+    /// Number of tables ever added to a restaurant.
+    TableCount(u64),
+    /// Singleton: total number of groups created.
+    GroupCount,
+    /// Per-group data keyed by numeric ID.
+    Group(u64),
+    /// IDs of a group's member restaurants.
+    GroupMembers(u64),
+    /// Group a restaurant belongs to.
+    RestaurantGroup(u64),
+    /// A restaurant's own settings, overriding its group's defaults.
+    RestaurantSettings(u64),
+    /// Roles held by a staff account at every member of a group.
+    GroupStaffRoles(u64, Address),
+    /// Singleton: Payment contract that closures are checked against.
+    PaymentContract,
+    /// Audit record of a closed restaurant.
+    Tombstone(u64),
 }
 
 // ---------------------------------------------------------------------------
@@ -63,149 +312,183 @@ impl RestaurantRegistry {
 
     /// Initialise the registry.  Must be called once by the deployer.
     ///
-    /// # Panics
-    /// Panics if the contract has already been initialised.
-    pub fn initialize(env: Env, admin: Address) {
+    /// # Errors
+    /// [`Error::AlreadyInitialized`] if the contract has already been
+    /// initialised.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            return Err(Error::AlreadyInitialized);
         }
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Count, &0u64);
-        env.storage().instance().extend_ttl(17_280, 17_280); // ~1 day
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        extend_instance(&env);
+
+        Initialized { admin }.publish(&env);
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
     // Writes
     // -----------------------------------------------------------------------
 
-    /// Register a new restaurant. The caller becomes the owner.
+    /// Register a new standalone restaurant. The caller becomes the owner.
+    ///
+    /// Use [`RestaurantRegistry::add_branch`] to register a location under a
+    /// brand instead.
     ///
     /// # Returns
     /// The newly assigned restaurant ID (starts at 1).
     ///
-    /// # Panics
-    /// - If the owner already has a registered restaurant.
-    pub fn register_restaurant(env: Env, owner: Address, name: String, slug: String) -> u64 {
+    /// # Errors
+    /// - A naming-rule error if `name` or `slug` is invalid.
+    /// - [`Error::SlugTaken`] if another restaurant holds the slug.
+    pub fn register_restaurant(
+        env: Env,
+        owner: Address,
+        name: String,
+        slug: String,
+    ) -> Result<u64, Error> {
         owner.require_auth();
-
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::OwnerToId(owner.clone()))
-        {
-            panic!("owner already has a restaurant");
-        }
-
-        let count: u64 = env.storage().instance().get(&DataKey::Count).unwrap_or(0);
-        let id: u64 = count + 1;
-
-        let restaurant = Restaurant {
-            id,
-            owner: owner.clone(),
-            name: name.clone(),
-            slug: slug.clone(),
-            is_active: true,
-            created_at: env.ledger().timestamp(),
-        };
-
-        let ttl: u32 = 2_073_600; // ~120 days on Stellar
-        env.storage()
-            .persistent()
-            .set(&DataKey::Restaurant(id), &restaurant);
-        env.storage()
-            .persistent()
-            .extend_ttl(&DataKey::Restaurant(id), ttl, ttl);
-
-        env.storage()
-            .persistent()
-            .set(&DataKey::OwnerToId(owner.clone()), &id);
-        env.storage()
-            .persistent()
-            .extend_ttl(&DataKey::OwnerToId(owner.clone()), ttl, ttl);
-
-        env.storage().instance().set(&DataKey::Count, &id);
-        env.storage().instance().extend_ttl(17_280, 17_280);
-
-        // Emit: (topic1, topic2) -> (id, owner)
-        env.events().publish(
-            (symbol_short!("register"), symbol_short!("rest")),
-            (id, owner, name),
-        );
-
-        id
+        create_restaurant(&env, &owner, 0, name, slug)
     }
 
     /// Update a restaurant's name and slug.
     ///
     /// Callable by the restaurant's own owner **or** the contract admin.
+    /// Renaming the slug releases the old one for other restaurants.
+    ///
+    /// # Errors
+    /// - A naming-rule error if `name` or `slug` is invalid.
+    /// - [`Error::SlugTaken`] if another restaurant holds the new slug.
     pub fn update_restaurant(
         env: Env,
         caller: Address,
         restaurant_id: u64,
         name: String,
         slug: String,
-    ) {
+    ) -> Result<(), Error> {
         caller.require_auth();
 
-        let mut restaurant: Restaurant = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Restaurant(restaurant_id))
-            .unwrap_or_else(|| panic!("restaurant not found"));
+        let mut restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_owner_or_admin(&env, &caller, &restaurant)?;
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if caller != restaurant.owner && caller != admin {
-            panic!("unauthorized");
+        validate_name(&name)?;
+        if slug != restaurant.slug {
+            validate_slug(&slug)?;
+            claim_slug(&env, &slug, restaurant_id)?;
+            release_slug(&env, &restaurant.slug);
         }
 
         restaurant.name = name.clone();
         restaurant.slug = slug;
 
-        let ttl: u32 = 2_073_600;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Restaurant(restaurant_id), &restaurant);
-        env.storage()
-            .persistent()
-            .extend_ttl(&DataKey::Restaurant(restaurant_id), ttl, ttl);
+        save_restaurant(&env, &restaurant);
 
-        env.events().publish(
-            (symbol_short!("update"), symbol_short!("rest")),
-            (restaurant_id, name),
-        );
+        RestaurantUpdated {
+            restaurant_id,
+            name,
+            slug: restaurant.slug,
+        }
+        .publish(&env);
+
+        Ok(())
     }
 
     /// Activate or deactivate a restaurant.
     ///
     /// Only the owner or admin may change the active flag.
-    pub fn set_active(env: Env, caller: Address, restaurant_id: u64, active: bool) {
+    pub fn set_active(
+        env: Env,
+        caller: Address,
+        restaurant_id: u64,
+        active: bool,
+    ) -> Result<(), Error> {
         caller.require_auth();
 
-        let mut restaurant: Restaurant = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Restaurant(restaurant_id))
-            .unwrap_or_else(|| panic!("restaurant not found"));
+        let mut restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_owner_or_admin(&env, &caller, &restaurant)?;
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if caller != restaurant.owner && caller != admin {
-            panic!("unauthorized");
+        restaurant.is_active = active;
+
+        save_restaurant(&env, &restaurant);
+
+        RestaurantActiveSet {
+            restaurant_id,
+            active,
         }
+        .publish(&env);
+        Ok(())
+    }
 
-        restaurant.is_active = active;
+    // -----------------------------------------------------------------------
+    // Admin
+    // -----------------------------------------------------------------------
 
-        let ttl: u32 = 2_073_600;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Restaurant(restaurant_id), &restaurant);
+    /// Propose `new_admin` as the next contract admin (admin only).
+    ///
+    /// The role only moves once `new_admin` calls `accept_admin_transfer`,
+    /// so a mistyped address cannot lock the contract. Replaces any pending
+    /// proposal.
+    pub fn propose_admin_transfer(
+        env: Env,
+        caller: Address,
+        new_admin: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        assert_admin(&env, &caller)?;
         env.storage()
-            .persistent()
-            .extend_ttl(&DataKey::Restaurant(restaurant_id), ttl, ttl);
+            .instance()
+            .set(&DataKey::PendingAdmin, &new_admin);
+        extend_instance(&env);
 
-        env.events().publish(
-            (symbol_short!("setactive"), symbol_short!("rest")),
-            (restaurant_id, active),
-        );
+        AdminTransferProposed {
+            admin: caller,
+            new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Accept a pending admin proposal and become the contract admin.
+    pub fn accept_admin_transfer(env: Env, new_admin: Address) -> Result<(), Error> {
+        new_admin.require_auth();
+
+        let pending: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(Error::NoPendingAdmin)?;
+        if pending != new_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        let previous = read_admin(&env)?;
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        extend_instance(&env);
+
+        AdminTransferAccepted {
+            previous_admin: previous,
+            new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Withdraw a pending admin proposal (admin only).
+    pub fn cancel_admin_transfer(env: Env, caller: Address) -> Result<(), Error> {
+        caller.require_auth();
+        assert_admin(&env, &caller)?;
+        if !env.storage().instance().has(&DataKey::PendingAdmin) {
+            return Err(Error::NoPendingAdmin);
+        }
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        AdminTransferCancelled { admin: caller }.publish(&env);
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
@@ -213,19 +496,61 @@ impl RestaurantRegistry {
     // -----------------------------------------------------------------------
 
     /// Fetch a restaurant by its numeric ID.
-    pub fn get_restaurant(env: Env, restaurant_id: u64) -> Restaurant {
-        env.storage()
+    pub fn get_restaurant(env: Env, restaurant_id: u64) -> Result<Restaurant, Error> {
+        load_restaurant(&env, restaurant_id)
+    }
+
+    /// Resolve a slug (e.g. a subdomain) to its restaurant.
+    pub fn get_restaurant_by_slug(env: Env, slug: String) -> Result<Restaurant, Error> {
+        let restaurant_id: u64 = env
+            .storage()
             .persistent()
-            .get(&DataKey::Restaurant(restaurant_id))
-            .unwrap_or_else(|| panic!("restaurant not found"))
+            .get(&DataKey::SlugToId(slug))
+            .ok_or(Error::SlugNotFound)?;
+        load_restaurant(&env, restaurant_id)
     }
 
-    /// Return the restaurant ID owned by `owner`.
-    pub fn get_owner_restaurant(env: Env, owner: Address) -> u64 {
+    /// Return the IDs of every restaurant owned by `owner`, in registration
+    /// order.
+    pub fn get_owner_restaurants(env: Env, owner: Address) -> Vec<u64> {
         env.storage()
             .persistent()
-            .get(&DataKey::OwnerToId(owner))
-            .unwrap_or_else(|| panic!("no restaurant for this owner"))
+            .get(&DataKey::OwnerRestaurants(owner))
+            .unwrap_or_else(|| vec![&env])
+    }
+
+    /// List restaurants in ID order.
+    ///
+    /// Returns up to `limit` restaurants (capped at [`MAX_PAGE_SIZE`]) that
+    /// match `filter`, starting from ID `start`. At most [`MAX_SCAN`]
+    /// restaurants are examined per call, so a sparse filter may return a
+    /// short or empty page before the end. To fetch the next page, call again
+    /// with `start` set to the returned `next`; `next` is `None` once the end
+    /// of the registry has been reached.
+    pub fn list_restaurants(
+        env: Env,
+        start: u64,
+        limit: u32,
+        filter: RestaurantFilter,
+    ) -> Result<RestaurantPage, Error> {
+        let count: u64 = env.storage().instance().get(&DataKey::Count).unwrap_or(0);
+        let limit = limit.min(MAX_PAGE_SIZE);
+
+        let mut restaurants = vec![&env];
+        let mut id = start.max(1);
+        let mut scanned = 0;
+        while id <= count && restaurants.len() < limit && scanned < MAX_SCAN {
+            let restaurant = load_restaurant(&env, id)?;
+            if filter.matches(&restaurant) {
+                restaurants.push_back(restaurant);
+            }
+            id += 1;
+            scanned += 1;
+        }
+        Ok(RestaurantPage {
+            restaurants,
+            next: (id <= count).then_some(id),
+        })
     }
 
     /// Total number of restaurants registered.
@@ -234,9 +559,149 @@ impl RestaurantRegistry {
     }
 
     /// Return the admin address.
-    pub fn admin(env: Env) -> Address {
-        env.storage().instance().get(&DataKey::Admin).unwrap()
+    pub fn admin(env: Env) -> Result<Address, Error> {
+        read_admin(&env)
+    }
+
+    /// Return the proposed next admin, if a handover is in progress.
+    pub fn pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Validate and store a new restaurant, and index it by owner, slug and
+/// (optionally) brand.
+pub(crate) fn create_restaurant(
+    env: &Env,
+    owner: &Address,
+    brand_id: u64,
+    name: String,
+    slug: String,
+) -> Result<u64, Error> {
+    validate_name(&name)?;
+    validate_slug(&slug)?;
+
+    let count: u64 = env.storage().instance().get(&DataKey::Count).unwrap_or(0);
+    let id: u64 = count + 1;
+
+    claim_slug(env, &slug, id)?;
+
+    let restaurant = Restaurant {
+        id,
+        owner: owner.clone(),
+        brand_id,
+        name: name.clone(),
+        slug,
+        is_active: true,
+        created_at: env.ledger().timestamp(),
+    };
+    save_restaurant(env, &restaurant);
+
+    append_to_list(env, DataKey::OwnerRestaurants(owner.clone()), id);
+    if brand_id != 0 {
+        append_to_list(env, DataKey::BrandRestaurants(brand_id), id);
+    }
+
+    env.storage().instance().set(&DataKey::Count, &id);
+    extend_instance(env);
+
+    RestaurantRegistered {
+        restaurant_id: id,
+        owner: owner.clone(),
+        brand_id,
+        name,
+        slug: restaurant.slug,
+    }
+    .publish(env);
+
+    Ok(id)
+}
+
+pub(crate) fn load_restaurant(env: &Env, restaurant_id: u64) -> Result<Restaurant, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Restaurant(restaurant_id))
+        .ok_or(Error::RestaurantNotFound)
+}
+
+pub(crate) fn save_restaurant(env: &Env, restaurant: &Restaurant) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Restaurant(restaurant.id), restaurant);
+    extend_restaurant(env, restaurant.id);
+}
+
+/// Point `slug` at `restaurant_id`, unless another restaurant holds it.
+fn claim_slug(env: &Env, slug: &String, restaurant_id: u64) -> Result<(), Error> {
+    let key = DataKey::SlugToId(slug.clone());
+    if env.storage().persistent().has(&key) {
+        return Err(Error::SlugTaken);
+    }
+    env.storage().persistent().set(&key, &restaurant_id);
+    extend_persistent(env, &key);
+    Ok(())
+}
+
+pub(crate) fn release_slug(env: &Env, slug: &String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::SlugToId(slug.clone()));
+}
+
+pub(crate) fn append_to_list(env: &Env, key: DataKey, id: u64) {
+    let mut list: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| vec![env]);
+    list.push_back(id);
+    env.storage().persistent().set(&key, &list);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn remove_from_list(env: &Env, key: DataKey, id: u64) {
+    let mut list: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| vec![env]);
+    if let Some(index) = list.first_index_of(id) {
+        list.remove(index);
+    }
+    env.storage().persistent().set(&key, &list);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn read_admin(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(Error::NotInitialized)
+}
+
+/// Fail unless `caller` is the contract admin.
+pub(crate) fn assert_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+    if caller != &read_admin(env)? {
+        return Err(Error::NotAdmin);
     }
+    Ok(())
+}
+
+/// Fail unless `caller` is the restaurant's owner or the contract admin.
+pub(crate) fn assert_owner_or_admin(
+    env: &Env,
+    caller: &Address,
+    restaurant: &Restaurant,
+) -> Result<(), Error> {
+    assert_not_closed(env, restaurant.id)?;
+    if caller != &restaurant.owner && caller != &read_admin(env)? {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
 }
 
 // ---------------------------------------------------------------------------
@@ -246,6 +711,7 @@ impl RestaurantRegistry {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::events::assert_last_event;
     use soroban_sdk::testutils::Address as _;
     use soroban_sdk::Env;
 
@@ -271,6 +737,17 @@ mod test {
             &String::from_str(&env, "mamas-kitchen"),
         );
         assert_eq!(id, 1);
+        assert_last_event(
+            &env,
+            &client.address,
+            RestaurantRegistered {
+                restaurant_id: id,
+                owner: owner.clone(),
+                brand_id: 0,
+                name: String::from_str(&env, "Mama's Kitchen"),
+                slug: String::from_str(&env, "mamas-kitchen"),
+            },
+        );
 
         let rest = client.get_restaurant(&id);
         assert_eq!(rest.owner, owner);
@@ -321,30 +798,305 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "already initialized")]
-    fn test_double_init_panics() {
+    fn test_get_restaurant_by_slug() {
         let (env, client) = setup();
         let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+
         client.initialize(&admin);
+        let id = client.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Old Name"),
+            &String::from_str(&env, "old-name"),
+        );
+        assert_eq!(
+            client
+                .get_restaurant_by_slug(&String::from_str(&env, "old-name"))
+                .id,
+            id
+        );
+
+        client.update_restaurant(
+            &owner,
+            &id,
+            &String::from_str(&env, "New Name"),
+            &String::from_str(&env, "new-name"),
+        );
+        assert_eq!(
+            client
+                .get_restaurant_by_slug(&String::from_str(&env, "new-name"))
+                .id,
+            id
+        );
+        assert!(client
+            .try_get_restaurant_by_slug(&String::from_str(&env, "old-name"))
+            .is_err());
+
+        // The released slug can be claimed by someone else.
+        let other = Address::generate(&env);
+        let other_id = client.register_restaurant(
+            &other,
+            &String::from_str(&env, "Old Name"),
+            &String::from_str(&env, "old-name"),
+        );
+        assert_eq!(
+            client
+                .get_restaurant_by_slug(&String::from_str(&env, "old-name"))
+                .id,
+            other_id
+        );
+    }
+
+    #[test]
+    fn test_duplicate_slug_rejected() {
+        let (env, client) = setup();
+        let admin = Address::generate(&env);
         client.initialize(&admin);
+        client.register_restaurant(
+            &Address::generate(&env),
+            &String::from_str(&env, "First"),
+            &String::from_str(&env, "shared"),
+        );
+        assert_eq!(
+            client.try_register_restaurant(
+                &Address::generate(&env),
+                &String::from_str(&env, "Second"),
+                &String::from_str(&env, "shared"),
+            ),
+            Err(Ok(Error::SlugTaken))
+        );
     }
 
     #[test]
-    #[should_panic(expected = "owner already has a restaurant")]
-    fn test_duplicate_owner_panics() {
+    fn test_update_to_taken_slug_rejected() {
         let (env, client) = setup();
         let admin = Address::generate(&env);
         let owner = Address::generate(&env);
         client.initialize(&admin);
         client.register_restaurant(
+            &Address::generate(&env),
+            &String::from_str(&env, "First"),
+            &String::from_str(&env, "first"),
+        );
+        let id = client.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Second"),
+            &String::from_str(&env, "second"),
+        );
+        assert_eq!(
+            client.try_update_restaurant(
+                &owner,
+                &id,
+                &String::from_str(&env, "Second"),
+                &String::from_str(&env, "first"),
+            ),
+            Err(Ok(Error::SlugTaken))
+        );
+    }
+
+    #[test]
+    fn test_invalid_slug_rejected() {
+        let (env, client) = setup();
+        client.initialize(&Address::generate(&env));
+        let result = client.try_register_restaurant(
+            &Address::generate(&env),
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "Mamas Kitchen"),
+        );
+        assert_eq!(result, Err(Ok(Error::SlugInvalidCharacter)));
+        assert_eq!(client.get_count(), 0);
+    }
+
+    #[test]
+    fn test_list_restaurants() {
+        use soroban_sdk::testutils::Ledger;
+
+        let (env, client) = setup();
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        let other = Address::generate(&env);
+        client.initialize(&admin);
+
+        let all = RestaurantFilter {
+            is_active: None,
+            owner: None,
+            created_after: None,
+            created_before: None,
+        };
+
+        env.ledger().set_timestamp(1_000);
+        let first = client.register_restaurant(
             &owner,
             &String::from_str(&env, "First"),
             &String::from_str(&env, "first"),
         );
-        client.register_restaurant(
+        let second = client.register_restaurant(
+            &other,
+            &String::from_str(&env, "Second"),
+            &String::from_str(&env, "second"),
+        );
+        env.ledger().set_timestamp(2_000);
+        let third = client.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Third"),
+            &String::from_str(&env, "third"),
+        );
+        client.set_active(&admin, &second, &false);
+
+        // Paging through everything.
+        let page = client.list_restaurants(&0, &2, &all);
+        assert_eq!(page.restaurants.len(), 2);
+        assert_eq!(page.restaurants.get(1).unwrap().id, second);
+        assert_eq!(page.next, Some(third));
+        let page = client.list_restaurants(&page.next.unwrap(), &2, &all);
+        assert_eq!(page.restaurants.len(), 1);
+        assert_eq!(page.restaurants.get(0).unwrap().id, third);
+        assert_eq!(page.next, None);
+        let page = client.list_restaurants(&(third + 1), &2, &all);
+        assert_eq!(page.restaurants.len(), 0);
+        assert_eq!(page.next, None);
+
+        // Filters.
+        let active = client
+            .list_restaurants(
+                &1,
+                &10,
+                &RestaurantFilter {
+                    is_active: Some(true),
+                    ..all.clone()
+                },
+            )
+            .restaurants;
+        assert_eq!(active.len(), 2);
+
+        let by_owner = client
+            .list_restaurants(
+                &1,
+                &10,
+                &RestaurantFilter {
+                    owner: Some(owner.clone()),
+                    ..all.clone()
+                },
+            )
+            .restaurants;
+        assert_eq!(by_owner.get(0).unwrap().id, first);
+        assert_eq!(by_owner.get(1).unwrap().id, third);
+
+        let window = client
+            .list_restaurants(
+                &1,
+                &10,
+                &RestaurantFilter {
+                    created_after: Some(1_500),
+                    created_before: Some(2_500),
+                    ..all
+                },
+            )
+            .restaurants;
+        assert_eq!(window.len(), 1);
+        assert_eq!(window.get(0).unwrap().id, third);
+    }
+
+    #[test]
+    fn test_list_restaurants_scan_cap() {
+        let (env, client) = setup();
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        client.initialize(&admin);
+        for i in 0..=MAX_SCAN {
+            let slug = [
+                b'r',
+                b'0' + (i / 100) as u8,
+                b'0' + (i / 10 % 10) as u8,
+                b'0' + (i % 10) as u8,
+            ];
+            client.register_restaurant(
+                &owner,
+                &String::from_str(&env, "Branch"),
+                &String::from_bytes(&env, &slug),
+            );
+        }
+
+        let nobody = RestaurantFilter {
+            is_active: None,
+            owner: Some(Address::generate(&env)),
+            created_after: None,
+            created_before: None,
+        };
+        let page = client.list_restaurants(&1, &10, &nobody);
+        assert_eq!(page.restaurants.len(), 0);
+        assert_eq!(page.next, Some(MAX_SCAN as u64 + 1));
+        let page = client.list_restaurants(&page.next.unwrap(), &10, &nobody);
+        assert_eq!(page.next, None);
+    }
+
+    #[test]
+    fn test_admin_transfer() {
+        let (env, client) = setup();
+        let admin = Address::generate(&env);
+        let new_admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        client.propose_admin_transfer(&admin, &new_admin);
+        assert_eq!(client.admin(), admin);
+        assert_eq!(client.pending_admin(), Some(new_admin.clone()));
+
+        client.accept_admin_transfer(&new_admin);
+        assert_eq!(client.admin(), new_admin);
+        assert_eq!(client.pending_admin(), None);
+    }
+
+    #[test]
+    fn test_admin_transfer_wrong_acceptor_rejected() {
+        let (env, client) = setup();
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        client.propose_admin_transfer(&admin, &Address::generate(&env));
+        assert_eq!(
+            client.try_accept_admin_transfer(&Address::generate(&env)),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_double_init_rejected() {
+        let (env, client) = setup();
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+        assert_last_event(
+            &env,
+            &client.address,
+            Initialized {
+                admin: admin.clone(),
+            },
+        );
+        assert_eq!(
+            client.try_initialize(&admin),
+            Err(Ok(Error::AlreadyInitialized))
+        );
+    }
+
+    #[test]
+    fn test_owner_can_register_several_restaurants() {
+        let (env, client) = setup();
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        client.initialize(&admin);
+        let first = client.register_restaurant(
+            &owner,
+            &String::from_str(&env, "First"),
+            &String::from_str(&env, "first"),
+        );
+        let second = client.register_restaurant(
             &owner,
             &String::from_str(&env, "Second"),
             &String::from_str(&env, "second"),
         );
+        assert_eq!(
+            client.get_owner_restaurants(&owner),
+            soroban_sdk::vec![&env, first, second]
+        );
+        assert_eq!(client.get_restaurant(&second).brand_id, 0);
     }
 }
@@ -0,0 +1,427 @@
+//! # Menu catalog
+//!
+//! Per-restaurant menu items managed by the restaurant owner, staff holding
+//! the `ManageMenu` permission, or the registry admin. The Order contract
+//! reads these records when an order is placed so that customers cannot
+//! choose their own prices.
+//!
+//! Item names follow the same rules as restaurant names, and prices must be
+//! in a token the restaurant's profile accepts.
+
+use soroban_sdk::{contractimpl, contracttype, vec, Address, Env, String, Vec};
+
+use crate::events::{Event, MenuItemAdded, MenuItemAvailabilitySet, MenuItemUpdated};
+use crate::staff::assert_can;
+use crate::{
+    extend_persistent, load_restaurant, validate_name, DataKey, Error, Permission,
+    RestaurantRegistry, RestaurantRegistryArgs, RestaurantRegistryClient, MAX_PAGE_SIZE,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+/// A single item on a restaurant's menu.
+#[contracttype]
+#[derive(Clone)]
+pub struct MenuItem {
+    /// Per-restaurant auto-incrementing identifier (starts at 1).
+    pub id: u64,
+    /// Restaurant that owns this item.
+    pub restaurant_id: u64,
+    /// Display name, snapshotted into orders.
+    pub name: String,
+    /// Price per unit in the token's smallest unit (stroops for XLM).
+    pub price: i128,
+    /// SEP-41 token the price is denominated in.
+    pub token: Address,
+    /// Whether the item can currently be ordered.
+    pub is_available: bool,
+}
+
+/// One page of [`RestaurantRegistry::get_menu`].
+#[contracttype]
+#[derive(Clone)]
+pub struct MenuPage {
+    /// Menu items, in ID order.
+    pub items: Vec<MenuItem>,
+    /// Item ID to pass as `start` to fetch the next page; `None` once the
+    /// end of the menu has been reached.
+    pub next: Option<u64>,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contractimpl]
+impl RestaurantRegistry {
+    /// Add an item to a restaurant's menu.
+    ///
+    /// Callable by the restaurant's owner, staff with `ManageMenu`, or the
+    /// contract admin.
+    ///
+    /// # Returns
+    /// The newly assigned menu item ID (starts at 1 per restaurant).
+    ///
+    /// # Errors
+    /// - [`Error::NameEmpty`] / [`Error::NameTooLong`] if `name` is empty or
+    ///   longer than [`crate::MAX_NAME_LEN`] bytes.
+    /// - [`Error::InvalidPrice`] if `price` is zero or negative.
+    /// - [`Error::TokenNotAccepted`] if the restaurant's profile does not
+    ///   accept `token`.
+    pub fn add_menu_item(
+        env: Env,
+        caller: Address,
+        restaurant_id: u64,
+        name: String,
+        price: i128,
+        token: Address,
+    ) -> Result<u64, Error> {
+        caller.require_auth();
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_can(&env, &caller, &restaurant, Permission::ManageMenu)?;
+
+        validate_item(&env, restaurant_id, &name, price, &token)?;
+
+        let count: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MenuCount(restaurant_id))
+            .unwrap_or(0);
+        let item_id = count + 1;
+
+        let item = MenuItem {
+            id: item_id,
+            restaurant_id,
+            name: name.clone(),
+            price,
+            token: token.clone(),
+            is_available: true,
+        };
+        save_menu_item(&env, &item);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::MenuCount(restaurant_id), &item_id);
+        extend_persistent(&env, &DataKey::MenuCount(restaurant_id));
+
+        MenuItemAdded {
+            restaurant_id,
+            item_id,
+            name,
+            price,
+            token,
+        }
+        .publish(&env);
+
+        Ok(item_id)
+    }
+
+    /// Change the name, price and token of an existing menu item.
+    ///
+    /// Callable by the restaurant's owner, staff with `ManageMenu`, or the
+    /// contract admin. Fails like [`Self::add_menu_item`].
+    pub fn update_menu_item(
+        env: Env,
+        caller: Address,
+        restaurant_id: u64,
+        item_id: u64,
+        name: String,
+        price: i128,
+        token: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_can(&env, &caller, &restaurant, Permission::ManageMenu)?;
+
+        validate_item(&env, restaurant_id, &name, price, &token)?;
+
+        let mut item = load_menu_item(&env, restaurant_id, item_id)?;
+        item.name = name;
+        item.price = price;
+        item.token = token;
+        save_menu_item(&env, &item);
+
+        MenuItemUpdated {
+            restaurant_id,
+            item_id,
+            name: item.name,
+            price,
+            token: item.token,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Mark a menu item as available or sold out.
+    ///
+    /// Callable by the restaurant's owner, staff with `ManageMenu`, or the
+    /// contract admin.
+    pub fn set_menu_item_available(
+        env: Env,
+        caller: Address,
+        restaurant_id: u64,
+        item_id: u64,
+        available: bool,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_can(&env, &caller, &restaurant, Permission::ManageMenu)?;
+
+        let mut item = load_menu_item(&env, restaurant_id, item_id)?;
+        item.is_available = available;
+        save_menu_item(&env, &item);
+
+        MenuItemAvailabilitySet {
+            restaurant_id,
+            item_id,
+            available,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Fetch a single menu item.
+    pub fn get_menu_item(env: Env, restaurant_id: u64, item_id: u64) -> Result<MenuItem, Error> {
+        load_menu_item(&env, restaurant_id, item_id)
+    }
+
+    /// List a restaurant's menu items in ID order, including unavailable
+    /// ones.
+    ///
+    /// Returns up to `limit` items (capped at [`MAX_PAGE_SIZE`]) starting
+    /// from item ID `start`. To fetch the next page, call again with `start`
+    /// set to the returned `next`; `next` is `None` once the end of the menu
+    /// has been reached.
+    pub fn get_menu(
+        env: Env,
+        restaurant_id: u64,
+        start: u64,
+        limit: u32,
+    ) -> Result<MenuPage, Error> {
+        let count: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MenuCount(restaurant_id))
+            .unwrap_or(0);
+        let limit = limit.min(MAX_PAGE_SIZE);
+
+        let mut items = vec![&env];
+        let mut item_id = start.max(1);
+        while item_id <= count && items.len() < limit {
+            items.push_back(load_menu_item(&env, restaurant_id, item_id)?);
+            item_id += 1;
+        }
+        Ok(MenuPage {
+            items,
+            next: (item_id <= count).then_some(item_id),
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Check a menu item's name, price and token.
+fn validate_item(
+    env: &Env,
+    restaurant_id: u64,
+    name: &String,
+    price: i128,
+    token: &Address,
+) -> Result<(), Error> {
+    validate_name(name)?;
+    if price <= 0 {
+        return Err(Error::InvalidPrice);
+    }
+    if !RestaurantRegistry::accepts_token(env.clone(), restaurant_id, token.clone())? {
+        return Err(Error::TokenNotAccepted);
+    }
+    Ok(())
+}
+
+fn load_menu_item(env: &Env, restaurant_id: u64, item_id: u64) -> Result<MenuItem, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MenuItem(restaurant_id, item_id))
+        .ok_or(Error::MenuItemNotFound)
+}
+
+fn save_menu_item(env: &Env, item: &MenuItem) {
+    let key = DataKey::MenuItem(item.restaurant_id, item.id);
+    env.storage().persistent().set(&key, item);
+    extend_persistent(env, &key);
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::events::{assert_last_event, MenuItemAdded};
+    use crate::{Error, ProfileUpdate, RestaurantRegistry, RestaurantRegistryClient, MAX_NAME_LEN};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{vec, Address, Env, String};
+
+    fn setup() -> (
+        Env,
+        RestaurantRegistryClient<'static>,
+        Address,
+        Address,
+        u64,
+    ) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(RestaurantRegistry, ());
+        let client = RestaurantRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        client.initialize(&admin);
+        let id = client.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "mamas-kitchen"),
+        );
+        (env, client, admin, owner, id)
+    }
+
+    #[test]
+    fn test_add_and_get_menu_item() {
+        let (env, client, _admin, owner, id) = setup();
+        let token = Address::generate(&env);
+
+        let item_id = client.add_menu_item(
+            &owner,
+            &id,
+            &String::from_str(&env, "Jollof Rice"),
+            &5_000_000,
+            &token,
+        );
+        assert_eq!(item_id, 1);
+        assert_last_event(
+            &env,
+            &client.address,
+            MenuItemAdded {
+                restaurant_id: id,
+                item_id,
+                name: String::from_str(&env, "Jollof Rice"),
+                price: 5_000_000,
+                token: token.clone(),
+            },
+        );
+
+        let item = client.get_menu_item(&id, &item_id);
+        assert_eq!(item.name, String::from_str(&env, "Jollof Rice"));
+        assert_eq!(item.price, 5_000_000);
+        assert_eq!(item.token, token);
+        assert!(item.is_available);
+        assert_eq!(client.get_menu(&id, &0, &10).items.len(), 1);
+    }
+
+    #[test]
+    fn test_get_menu_pages() {
+        let (env, client, _admin, owner, id) = setup();
+        let token = Address::generate(&env);
+        for name in ["Jollof Rice", "Suya", "Chapman"] {
+            client.add_menu_item(&owner, &id, &String::from_str(&env, name), &1, &token);
+        }
+
+        let page = client.get_menu(&id, &0, &2);
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.next, Some(3));
+        let page = client.get_menu(&id, &page.next.unwrap(), &2);
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items.get(0).unwrap().id, 3);
+        assert_eq!(page.next, None);
+    }
+
+    #[test]
+    fn test_menu_item_validated() {
+        let (env, client, _admin, owner, id) = setup();
+        let usdc = Address::generate(&env);
+        let add =
+            |name: &String, token: &Address| client.try_add_menu_item(&owner, &id, name, &1, token);
+        let suya = String::from_str(&env, "Suya");
+
+        assert_eq!(
+            add(&String::from_str(&env, ""), &usdc),
+            Err(Ok(Error::NameEmpty))
+        );
+        let long = String::from_bytes(&env, &[b'x'; MAX_NAME_LEN as usize + 1]);
+        assert_eq!(add(&long, &usdc), Err(Ok(Error::NameTooLong)));
+
+        client.update_profile(
+            &owner,
+            &id,
+            &0,
+            &ProfileUpdate {
+                payout_wallet: owner.clone(),
+                accepted_tokens: vec![&env, usdc.clone()],
+                cuisine_tags: vec![&env],
+                geohash: String::from_str(&env, ""),
+                contact_hash: None,
+            },
+        );
+        assert_eq!(
+            add(&suya, &Address::generate(&env)),
+            Err(Ok(Error::TokenNotAccepted))
+        );
+        let item_id = add(&suya, &usdc).unwrap().unwrap();
+        assert_eq!(
+            client
+                .try_update_menu_item(&owner, &id, &item_id, &suya, &1, &Address::generate(&env),),
+            Err(Ok(Error::TokenNotAccepted))
+        );
+    }
+
+    #[test]
+    fn test_update_and_toggle_menu_item() {
+        let (env, client, admin, owner, id) = setup();
+        let token = Address::generate(&env);
+
+        let item_id = client.add_menu_item(
+            &owner,
+            &id,
+            &String::from_str(&env, "Suya"),
+            &3_000_000,
+            &token,
+        );
+        client.update_menu_item(
+            &owner,
+            &id,
+            &item_id,
+            &String::from_str(&env, "Beef Suya"),
+            &3_500_000,
+            &token,
+        );
+        client.set_menu_item_available(&admin, &id, &item_id, &false);
+
+        let item = client.get_menu_item(&id, &item_id);
+        assert_eq!(item.name, String::from_str(&env, "Beef Suya"));
+        assert_eq!(item.price, 3_500_000);
+        assert!(!item.is_available);
+    }
+
+    #[test]
+    fn test_stranger_cannot_add_menu_item() {
+        let (env, client, _admin, _owner, id) = setup();
+        let stranger = Address::generate(&env);
+        assert_eq!(
+            client.try_add_menu_item(
+                &stranger,
+                &id,
+                &String::from_str(&env, "Free Food"),
+                &1,
+                &Address::generate(&env),
+            ),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+}
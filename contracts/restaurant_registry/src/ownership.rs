@@ -0,0 +1,471 @@
+//! # Ownership transfer
+//!
+//! Restaurants change hands in two steps so that a mistyped address can
+//! never take ownership: the current owner proposes a new owner, who must
+//! then accept. The proposal can be cancelled at any time before that.
+//!
+//! Accepting a transfer also points the restaurant's payout wallet at the
+//! new owner, revokes every staff role granted at the restaurant, detaches
+//! it from its brand and takes it out of its group, so the previous owner
+//! keeps no control through a role granted before the sale.
+//!
+//! If the owner has lost their key, the admin can start a recovery
+//! proposal instead. It can only be accepted after [`RECOVERY_DELAY`], which
+//! leaves the current owner time to cancel it if the request is fraudulent.
+
+use soroban_sdk::{contractimpl, contracttype, Address, Env};
+
+use crate::brands::detach_from_brand;
+use crate::closing::assert_not_closed;
+use crate::events::{
+    Event, OwnerRecoveryStarted, OwnerTransferCancelled, OwnerTransferProposed, OwnerTransferred,
+};
+use crate::groups::leave_group;
+use crate::profile::reset_payout_wallet;
+use crate::staff::clear_staff;
+use crate::{
+    append_to_list, assert_admin, assert_owner_or_admin, extend_persistent, load_restaurant,
+    remove_from_list, save_restaurant, DataKey, Error, RestaurantRegistry, RestaurantRegistryArgs,
+    RestaurantRegistryClient,
+};
+
+/// Waiting period, in seconds, before an admin-initiated recovery can be
+/// accepted (7 days).
+pub const RECOVERY_DELAY: u64 = 7 * 24 * 60 * 60;
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+/// A pending change of restaurant owner.
+#[contracttype]
+#[derive(Clone)]
+pub struct OwnerTransfer {
+    /// Address that must call `accept_owner_transfer`.
+    pub new_owner: Address,
+    /// Ledger timestamp at which the transfer was proposed.
+    pub proposed_at: u64,
+    /// Earliest ledger timestamp at which the transfer can be accepted.
+    pub accept_after: u64,
+    /// Whether the admin started this transfer as a lost-key recovery.
+    pub is_recovery: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contractimpl]
+impl RestaurantRegistry {
+    /// Propose `new_owner` as the next owner of a restaurant.
+    ///
+    /// Only the current owner may propose. Replaces any pending transfer,
+    /// including a recovery started by the admin.
+    pub fn propose_owner_transfer(
+        env: Env,
+        owner: Address,
+        restaurant_id: u64,
+        new_owner: Address,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_not_closed(&env, restaurant_id)?;
+        if restaurant.owner != owner {
+            return Err(Error::Unauthorized);
+        }
+        if new_owner == owner {
+            return Err(Error::AlreadyOwner);
+        }
+
+        let now = env.ledger().timestamp();
+        save_transfer(
+            &env,
+            restaurant_id,
+            &OwnerTransfer {
+                new_owner: new_owner.clone(),
+                proposed_at: now,
+                accept_after: now,
+                is_recovery: false,
+            },
+        );
+
+        OwnerTransferProposed {
+            restaurant_id,
+            owner,
+            new_owner,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Start a lost-key recovery that hands the restaurant to `new_owner`
+    /// (admin only).
+    ///
+    /// `new_owner` may accept once [`RECOVERY_DELAY`] has elapsed; until then
+    /// the current owner can cancel it.
+    pub fn start_owner_recovery(
+        env: Env,
+        admin: Address,
+        restaurant_id: u64,
+        new_owner: Address,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        assert_admin(&env, &admin)?;
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_not_closed(&env, restaurant_id)?;
+        if new_owner == restaurant.owner {
+            return Err(Error::AlreadyOwner);
+        }
+
+        let now = env.ledger().timestamp();
+        let transfer = OwnerTransfer {
+            new_owner: new_owner.clone(),
+            proposed_at: now,
+            accept_after: now + RECOVERY_DELAY,
+            is_recovery: true,
+        };
+        save_transfer(&env, restaurant_id, &transfer);
+
+        OwnerRecoveryStarted {
+            restaurant_id,
+            new_owner,
+            accept_after: transfer.accept_after,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Accept a pending transfer and become the restaurant's owner.
+    ///
+    /// Every staff role granted at the restaurant is revoked and the
+    /// restaurant leaves its brand and group; the new owner grants roles
+    /// afresh.
+    pub fn accept_owner_transfer(
+        env: Env,
+        new_owner: Address,
+        restaurant_id: u64,
+    ) -> Result<(), Error> {
+        new_owner.require_auth();
+
+        let transfer = load_transfer(&env, restaurant_id)?;
+        if transfer.new_owner != new_owner {
+            return Err(Error::Unauthorized);
+        }
+        if env.ledger().timestamp() < transfer.accept_after {
+            return Err(Error::RecoveryDelayNotElapsed);
+        }
+
+        let mut restaurant = load_restaurant(&env, restaurant_id)?;
+        let previous_owner = restaurant.owner.clone();
+        restaurant.owner = new_owner.clone();
+        detach_from_brand(&env, &mut restaurant);
+        save_restaurant(&env, &restaurant);
+        reset_payout_wallet(&env, restaurant_id, &new_owner);
+        clear_staff(&env, restaurant_id);
+        if let Some(group_id) = Self::get_restaurant_group(env.clone(), restaurant_id) {
+            leave_group(&env, group_id, restaurant_id);
+        }
+
+        remove_from_list(
+            &env,
+            DataKey::OwnerRestaurants(previous_owner.clone()),
+            restaurant_id,
+        );
+        append_to_list(
+            &env,
+            DataKey::OwnerRestaurants(new_owner.clone()),
+            restaurant_id,
+        );
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingOwner(restaurant_id));
+
+        OwnerTransferred {
+            restaurant_id,
+            previous_owner,
+            new_owner,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Cancel a pending transfer or recovery.
+    ///
+    /// Callable by the restaurant's owner **or** the contract admin.
+    pub fn cancel_owner_transfer(
+        env: Env,
+        caller: Address,
+        restaurant_id: u64,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_owner_or_admin(&env, &caller, &restaurant)?;
+
+        let transfer = load_transfer(&env, restaurant_id)?;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingOwner(restaurant_id));
+
+        OwnerTransferCancelled {
+            restaurant_id,
+            cancelled_by: caller,
+            new_owner: transfer.new_owner,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Return the pending ownership transfer of a restaurant, if any.
+    pub fn get_pending_owner_transfer(env: Env, restaurant_id: u64) -> Option<OwnerTransfer> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingOwner(restaurant_id))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn load_transfer(env: &Env, restaurant_id: u64) -> Result<OwnerTransfer, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingOwner(restaurant_id))
+        .ok_or(Error::NoPendingTransfer)
+}
+
+fn save_transfer(env: &Env, restaurant_id: u64, transfer: &OwnerTransfer) {
+    let key = DataKey::PendingOwner(restaurant_id);
+    env.storage().persistent().set(&key, transfer);
+    extend_persistent(env, &key);
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::RECOVERY_DELAY;
+    use crate::events::{
+        assert_event_published, assert_last_event, BranchDetached, GroupMemberRemoved,
+        OwnerTransferred, ProfileUpdated, RoleRevoked,
+    };
+    use crate::{
+        Error, Permission, ProfileUpdate, RestaurantRegistry, RestaurantRegistryClient, StaffRole,
+    };
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{vec, Address, Env, String};
+
+    fn setup() -> (
+        Env,
+        RestaurantRegistryClient<'static>,
+        Address,
+        Address,
+        u64,
+    ) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(RestaurantRegistry, ());
+        let client = RestaurantRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        client.initialize(&admin);
+        let id = client.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "mamas-kitchen"),
+        );
+        (env, client, admin, owner, id)
+    }
+
+    #[test]
+    fn test_propose_and_accept() {
+        let (env, client, _admin, owner, id) = setup();
+        let buyer = Address::generate(&env);
+
+        client.propose_owner_transfer(&owner, &id, &buyer);
+        // Ownership does not move until the buyer accepts.
+        assert_eq!(client.get_restaurant(&id).owner, owner);
+        assert_eq!(
+            client.get_pending_owner_transfer(&id).unwrap().new_owner,
+            buyer
+        );
+
+        client.accept_owner_transfer(&buyer, &id);
+        assert_eq!(client.get_restaurant(&id).owner, buyer);
+        assert!(client.get_pending_owner_transfer(&id).is_none());
+        assert_eq!(client.get_owner_restaurants(&owner).len(), 0);
+        assert_eq!(client.get_owner_restaurants(&buyer), vec![&env, id]);
+    }
+
+    #[test]
+    fn test_transfer_revokes_staff_and_brand() {
+        let (env, client, _admin, owner, _id) = setup();
+        let buyer = Address::generate(&env);
+        let brand_id = client.create_brand(&owner, &String::from_str(&env, "Mama's"));
+        let id = client.add_branch(
+            &owner,
+            &brand_id,
+            &String::from_str(&env, "Mama's Annex"),
+            &String::from_str(&env, "mamas-annex"),
+        );
+
+        // The seller grants themselves a Manager role before selling.
+        client.grant_role(&owner, &id, &owner, &StaffRole::Manager);
+        client.update_profile(
+            &owner,
+            &id,
+            &0,
+            &ProfileUpdate {
+                payout_wallet: owner.clone(),
+                accepted_tokens: vec![&env],
+                cuisine_tags: vec![&env],
+                geohash: String::from_str(&env, ""),
+                contact_hash: None,
+            },
+        );
+        client.propose_owner_transfer(&owner, &id, &buyer);
+        client.accept_owner_transfer(&buyer, &id);
+        assert_last_event(
+            &env,
+            &client.address,
+            OwnerTransferred {
+                restaurant_id: id,
+                previous_owner: owner.clone(),
+                new_owner: buyer.clone(),
+            },
+        );
+        assert_event_published(
+            &env,
+            &client.address,
+            BranchDetached {
+                brand_id,
+                restaurant_id: id,
+            },
+        );
+        assert_event_published(
+            &env,
+            &client.address,
+            ProfileUpdated {
+                restaurant_id: id,
+                version: 2,
+            },
+        );
+        assert_event_published(
+            &env,
+            &client.address,
+            RoleRevoked {
+                restaurant_id: id,
+                staff: owner.clone(),
+                role: StaffRole::Manager,
+            },
+        );
+        assert_eq!(client.get_payout_wallet(&id), buyer);
+
+        assert!(!client.has_permission(&id, &owner, &Permission::ManageMenu));
+        assert_eq!(client.get_staff(&id).len(), 0);
+        assert_eq!(
+            client.try_add_menu_item(
+                &owner,
+                &id,
+                &String::from_str(&env, "Jollof Rice"),
+                &5_000_000,
+                &Address::generate(&env),
+            ),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(client.get_restaurant(&id).brand_id, 0);
+        assert_eq!(client.get_brand_restaurants(&brand_id).len(), 0);
+    }
+
+    #[test]
+    fn test_transfer_leaves_group() {
+        let (env, client, _admin, owner, id) = setup();
+        let buyer = Address::generate(&env);
+
+        // The seller runs a group holding the restaurant and has a group
+        // role that applies there.
+        let group_id = client.create_group(&owner, &String::from_str(&env, "Naija Eats"));
+        client.add_group_member(&owner, &group_id, &id);
+        let manager = Address::generate(&env);
+        client.grant_group_role(&owner, &group_id, &manager, &StaffRole::Manager);
+        assert!(client.has_permission(&id, &manager, &Permission::ManageMenu));
+
+        client.propose_owner_transfer(&owner, &id, &buyer);
+        client.accept_owner_transfer(&buyer, &id);
+        assert_event_published(
+            &env,
+            &client.address,
+            GroupMemberRemoved {
+                group_id,
+                restaurant_id: id,
+            },
+        );
+
+        assert_eq!(client.get_restaurant_group(&id), None);
+        assert_eq!(client.get_group_members(&group_id), vec![&env]);
+        assert!(!client.has_permission(&id, &manager, &Permission::ManageMenu));
+        assert!(!client.has_role(&id, &manager, &StaffRole::Manager));
+    }
+
+    #[test]
+    fn test_cancelled_transfer_cannot_be_accepted() {
+        let (env, client, _admin, owner, id) = setup();
+        let buyer = Address::generate(&env);
+
+        client.propose_owner_transfer(&owner, &id, &buyer);
+        client.cancel_owner_transfer(&owner, &id);
+        assert_eq!(
+            client.try_accept_owner_transfer(&buyer, &id),
+            Err(Ok(Error::NoPendingTransfer))
+        );
+    }
+
+    #[test]
+    fn test_only_proposed_owner_can_accept() {
+        let (env, client, _admin, owner, id) = setup();
+        let buyer = Address::generate(&env);
+
+        client.propose_owner_transfer(&owner, &id, &buyer);
+        assert_eq!(
+            client.try_accept_owner_transfer(&Address::generate(&env), &id),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_admin_recovery_after_delay() {
+        let (env, client, admin, owner, id) = setup();
+        let new_key = Address::generate(&env);
+
+        env.ledger().set_timestamp(1_000);
+        client.start_owner_recovery(&admin, &id, &new_key);
+
+        let transfer = client.get_pending_owner_transfer(&id).unwrap();
+        assert!(transfer.is_recovery);
+        assert_eq!(transfer.accept_after, 1_000 + RECOVERY_DELAY);
+
+        // Too early.
+        assert!(client.try_accept_owner_transfer(&new_key, &id).is_err());
+
+        env.ledger().set_timestamp(1_000 + RECOVERY_DELAY);
+        client.accept_owner_transfer(&new_key, &id);
+        assert_eq!(client.get_restaurant(&id).owner, new_key);
+        assert_eq!(client.get_owner_restaurants(&owner).len(), 0);
+    }
+
+    #[test]
+    fn test_owner_cannot_start_recovery() {
+        let (env, client, _admin, owner, id) = setup();
+        assert_eq!(
+            client.try_start_owner_recovery(&owner, &id, &Address::generate(&env)),
+            Err(Ok(Error::NotAdmin))
+        );
+    }
+}
@@ -0,0 +1,11 @@
+//! Client interface for the Payment contract.
+//!
+//! Only the entry points the registry calls are declared here.
+
+use soroban_sdk::{contractclient, Address, Env};
+
+#[contractclient(name = "PaymentClient")]
+pub trait PaymentInterface {
+    fn escrowed_count(env: Env, restaurant_id: u64) -> u32;
+    fn order_contract(env: Env) -> Option<Address>;
+}
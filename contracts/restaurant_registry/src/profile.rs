@@ -0,0 +1,297 @@
+//! # Restaurant profile
+//!
+//! Extended, owner-editable details kept alongside each `Restaurant`:
+//! where payouts go, which tokens are accepted, and discovery metadata.
+//!
+//! Profiles are versioned. `update_profile` takes the version the caller
+//! last read and fails if someone else has updated the profile since, so
+//! concurrent edits from two dashboards cannot silently overwrite each other.
+
+use soroban_sdk::{contractimpl, contracttype, vec, Address, BytesN, Env, String, Symbol, Vec};
+
+use crate::closing::assert_not_closed;
+use crate::events::{Event, ProfileUpdated};
+use crate::{
+    assert_owner_or_admin, extend_persistent, load_restaurant, validate_geohash, DataKey, Error,
+    RestaurantRegistry, RestaurantRegistryArgs, RestaurantRegistryClient,
+};
+
+/// Maximum number of tokens a restaurant may list as accepted.
+pub const MAX_ACCEPTED_TOKENS: u32 = 10;
+/// Maximum number of cuisine tags per restaurant.
+pub const MAX_CUISINE_TAGS: u32 = 10;
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+/// Extended restaurant details, stored separately from `Restaurant`.
+#[contracttype]
+#[derive(Clone)]
+pub struct RestaurantProfile {
+    /// Wallet that receives released payments. Defaults to the owner.
+    pub payout_wallet: Address,
+    /// SEP-41 tokens accepted for payment. Empty means any token.
+    pub accepted_tokens: Vec<Address>,
+    /// Short cuisine tags used for discovery (e.g. `nigerian`, `vegan`).
+    pub cuisine_tags: Vec<Symbol>,
+    /// Geohash of the restaurant's location, at most
+    /// [`crate::MAX_GEOHASH_LEN`] base32 characters; empty if unset.
+    pub geohash: String,
+    /// Hash of the off-chain contact metadata document, if any.
+    pub contact_hash: Option<BytesN<32>>,
+    /// Incremented on every update, starting at 0.
+    pub version: u32,
+    /// Ledger timestamp of the last update.
+    pub updated_at: u64,
+}
+
+/// Owner-editable fields of a [`RestaurantProfile`].
+#[contracttype]
+#[derive(Clone)]
+pub struct ProfileUpdate {
+    pub payout_wallet: Address,
+    pub accepted_tokens: Vec<Address>,
+    pub cuisine_tags: Vec<Symbol>,
+    pub geohash: String,
+    pub contact_hash: Option<BytesN<32>>,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contractimpl]
+impl RestaurantRegistry {
+    /// Replace a restaurant's profile.
+    ///
+    /// Callable by the restaurant's owner **or** the contract admin.
+    /// `expected_version` must equal the profile's current `version`.
+    ///
+    /// # Returns
+    /// The new profile version.
+    ///
+    /// # Errors
+    /// [`Error::InvalidGeohash`] if `geohash` is too long or not base32.
+    pub fn update_profile(
+        env: Env,
+        caller: Address,
+        restaurant_id: u64,
+        expected_version: u32,
+        update: ProfileUpdate,
+    ) -> Result<u32, Error> {
+        caller.require_auth();
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_owner_or_admin(&env, &caller, &restaurant)?;
+
+        let current = Self::get_profile(env.clone(), restaurant_id)?;
+        if current.version != expected_version {
+            return Err(Error::ProfileVersionMismatch);
+        }
+        if update.accepted_tokens.len() > MAX_ACCEPTED_TOKENS {
+            return Err(Error::TooManyAcceptedTokens);
+        }
+        if update.cuisine_tags.len() > MAX_CUISINE_TAGS {
+            return Err(Error::TooManyCuisineTags);
+        }
+        validate_geohash(&update.geohash)?;
+
+        let profile = RestaurantProfile {
+            payout_wallet: update.payout_wallet,
+            accepted_tokens: update.accepted_tokens,
+            cuisine_tags: update.cuisine_tags,
+            geohash: update.geohash,
+            contact_hash: update.contact_hash,
+            version: current.version + 1,
+            updated_at: env.ledger().timestamp(),
+        };
+        save_profile(&env, restaurant_id, &profile);
+
+        ProfileUpdated {
+            restaurant_id,
+            version: profile.version,
+        }
+        .publish(&env);
+
+        Ok(profile.version)
+    }
+
+    /// Fetch a restaurant's profile.
+    ///
+    /// Restaurants that have never updated their profile get the default:
+    /// payouts to the owner, any token accepted, no discovery metadata.
+    pub fn get_profile(env: Env, restaurant_id: u64) -> Result<RestaurantProfile, Error> {
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&DataKey::Profile(restaurant_id))
+            .unwrap_or_else(|| RestaurantProfile {
+                payout_wallet: restaurant.owner,
+                accepted_tokens: vec![&env],
+                cuisine_tags: vec![&env],
+                geohash: String::from_str(&env, ""),
+                contact_hash: None,
+                version: 0,
+                updated_at: restaurant.created_at,
+            }))
+    }
+
+    /// Wallet that should receive payouts for a restaurant.
+    ///
+    /// # Errors
+    /// [`Error::RestaurantClosed`] once the restaurant has been closed, so
+    /// that nothing new is paid out to it.
+    pub fn get_payout_wallet(env: Env, restaurant_id: u64) -> Result<Address, Error> {
+        assert_not_closed(&env, restaurant_id)?;
+        Ok(Self::get_profile(env, restaurant_id)?.payout_wallet)
+    }
+
+    /// Whether a restaurant accepts payment in `token`.
+    pub fn accepts_token(env: Env, restaurant_id: u64, token: Address) -> Result<bool, Error> {
+        let accepted = Self::get_profile(env, restaurant_id)?.accepted_tokens;
+        Ok(accepted.is_empty() || accepted.contains(&token))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Point a restaurant's payouts at its new owner after an ownership change,
+/// so that the previous owner stops receiving funds.
+pub(crate) fn reset_payout_wallet(env: &Env, restaurant_id: u64, new_owner: &Address) {
+    let Some(mut profile) = env
+        .storage()
+        .persistent()
+        .get::<_, RestaurantProfile>(&DataKey::Profile(restaurant_id))
+    else {
+        // Default profile already pays the current owner.
+        return;
+    };
+    profile.payout_wallet = new_owner.clone();
+    profile.version += 1;
+    profile.updated_at = env.ledger().timestamp();
+    save_profile(env, restaurant_id, &profile);
+
+    ProfileUpdated {
+        restaurant_id,
+        version: profile.version,
+    }
+    .publish(env);
+}
+
+fn save_profile(env: &Env, restaurant_id: u64, profile: &RestaurantProfile) {
+    let key = DataKey::Profile(restaurant_id);
+    env.storage().persistent().set(&key, profile);
+    extend_persistent(env, &key);
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::ProfileUpdate;
+    use crate::{Error, RestaurantRegistry, RestaurantRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{symbol_short, vec, Address, BytesN, Env, String};
+
+    fn setup() -> (Env, RestaurantRegistryClient<'static>, Address, u64) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(RestaurantRegistry, ());
+        let client = RestaurantRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&Address::generate(&env));
+        let id = client.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "mamas-kitchen"),
+        );
+        (env, client, owner, id)
+    }
+
+    fn make_update(env: &Env, wallet: &Address, token: &Address) -> ProfileUpdate {
+        ProfileUpdate {
+            payout_wallet: wallet.clone(),
+            accepted_tokens: vec![env, token.clone()],
+            cuisine_tags: vec![env, symbol_short!("nigerian")],
+            geohash: String::from_str(env, "s14bk7"),
+            contact_hash: Some(BytesN::from_array(env, &[7; 32])),
+        }
+    }
+
+    #[test]
+    fn test_default_profile() {
+        let (env, client, owner, id) = setup();
+
+        let profile = client.get_profile(&id);
+        assert_eq!(profile.version, 0);
+        assert_eq!(profile.payout_wallet, owner);
+        assert!(client.accepts_token(&id, &Address::generate(&env)));
+    }
+
+    #[test]
+    fn test_update_profile() {
+        let (env, client, owner, id) = setup();
+        let wallet = Address::generate(&env);
+        let usdc = Address::generate(&env);
+
+        let version = client.update_profile(&owner, &id, &0, &make_update(&env, &wallet, &usdc));
+        assert_eq!(version, 1);
+
+        let profile = client.get_profile(&id);
+        assert_eq!(profile.version, 1);
+        assert_eq!(profile.geohash, String::from_str(&env, "s14bk7"));
+        assert_eq!(client.get_payout_wallet(&id), wallet);
+        assert!(client.accepts_token(&id, &usdc));
+        assert!(!client.accepts_token(&id, &Address::generate(&env)));
+    }
+
+    #[test]
+    fn test_stale_profile_update_rejected() {
+        let (env, client, owner, id) = setup();
+        let update = make_update(&env, &owner, &Address::generate(&env));
+
+        client.update_profile(&owner, &id, &0, &update);
+        assert_eq!(
+            client.try_update_profile(&owner, &id, &0, &update),
+            Err(Ok(Error::ProfileVersionMismatch))
+        );
+    }
+
+    #[test]
+    fn test_invalid_geohash_rejected() {
+        let (env, client, owner, id) = setup();
+        let mut update = make_update(&env, &owner, &Address::generate(&env));
+        update.geohash = String::from_str(&env, "s14bk7s14bk7s");
+        assert_eq!(
+            client.try_update_profile(&owner, &id, &0, &update),
+            Err(Ok(Error::InvalidGeohash))
+        );
+        assert_eq!(client.get_profile(&id).version, 0);
+    }
+
+    #[test]
+    fn test_ownership_transfer_resets_payout_wallet() {
+        let (env, client, owner, id) = setup();
+        let wallet = Address::generate(&env);
+        let buyer = Address::generate(&env);
+
+        client.update_profile(
+            &owner,
+            &id,
+            &0,
+            &make_update(&env, &wallet, &Address::generate(&env)),
+        );
+        client.propose_owner_transfer(&owner, &id, &buyer);
+        client.accept_owner_transfer(&buyer, &id);
+
+        assert_eq!(client.get_payout_wallet(&id), buyer);
+        assert_eq!(client.get_profile(&id).version, 2);
+    }
+}
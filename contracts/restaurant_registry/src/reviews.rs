@@ -0,0 +1,527 @@
+//! # Reviews and ratings
+//!
+//! Reviews are tied to real purchases: each delivered order in the Order
+//! contract can carry exactly one review, written by that order's customer.
+//! The registry asks the Order contract (set by the admin with
+//! `set_order_contract`) for the order before accepting a review.
+//!
+//! Review and response texts live off-chain; only their hashes are stored.
+//! The restaurant's owner or a manager may post one response per review.
+//! `get_rating_summary` and `average_rating` aggregate a restaurant's
+//! ratings without walking its reviews.
+
+use soroban_sdk::{contractimpl, contracttype, vec, Address, BytesN, Env, Vec};
+
+use crate::events::{Event, OrderContractSet, ReviewResponded, ReviewSubmitted};
+use crate::orders::{OrderClient, OrderError, OrderStatus};
+use crate::{
+    assert_admin, extend_instance, load_restaurant, ttl::save, DataKey, Error, RestaurantRegistry,
+    RestaurantRegistryArgs, RestaurantRegistryClient, ReviewError, StaffRole, MAX_PAGE_SIZE,
+};
+
+/// Lowest star rating a review may give.
+pub const MIN_RATING: u32 = 1;
+/// Highest star rating a review may give.
+pub const MAX_RATING: u32 = 5;
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+/// A customer's review of one delivered order.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Review {
+    pub order_id: u64,
+    pub restaurant_id: u64,
+    pub customer: Address,
+    /// Whole stars, from [`MIN_RATING`] to [`MAX_RATING`].
+    pub rating: u32,
+    /// Hash of the off-chain review text.
+    pub comment_hash: BytesN<32>,
+    pub created_at: u64,
+    /// Hash of the restaurant's off-chain response, once posted.
+    pub response_hash: Option<BytesN<32>>,
+    /// Ledger timestamp at which the response was posted; `0` if none.
+    pub responded_at: u64,
+}
+
+/// Aggregate of every rating a restaurant has received.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RatingSummary {
+    pub review_count: u32,
+    /// Sum of all ratings.
+    pub rating_total: u64,
+    /// Number of reviews per rating; index 0 counts one-star reviews.
+    pub star_counts: Vec<u32>,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contractimpl]
+impl RestaurantRegistry {
+    /// Set the Order contract that reviews are checked against (admin only).
+    pub fn set_order_contract(
+        env: Env,
+        admin: Address,
+        order_contract: Address,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        assert_admin(&env, &admin)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::OrderContract, &order_contract);
+        extend_instance(&env);
+
+        OrderContractSet { order_contract }.publish(&env);
+        Ok(())
+    }
+
+    /// Review a delivered order. Only the order's customer may do so, once.
+    ///
+    /// # Errors
+    /// - [`ReviewError::InvalidRating`] if `rating` is outside
+    ///   [`MIN_RATING`]`..=`[`MAX_RATING`].
+    /// - [`ReviewError::AlreadyReviewed`] if the order already has a review.
+    /// - [`ReviewError::OrderContractNotSet`] if no Order contract is
+    ///   configured, or it could not be queried.
+    /// - [`ReviewError::OrderNotFound`] if the Order contract has no such order.
+    /// - [`ReviewError::Unauthorized`] if `customer` did not place the order.
+    /// - [`ReviewError::OrderNotDelivered`] if the order is not `Delivered`.
+    pub fn submit_review(
+        env: Env,
+        customer: Address,
+        order_id: u64,
+        rating: u32,
+        comment_hash: BytesN<32>,
+    ) -> Result<(), ReviewError> {
+        customer.require_auth();
+
+        if !(MIN_RATING..=MAX_RATING).contains(&rating) {
+            return Err(ReviewError::InvalidRating);
+        }
+        let key = DataKey::Review(order_id);
+        if env.storage().persistent().has(&key) {
+            return Err(ReviewError::AlreadyReviewed);
+        }
+
+        let order = match order_client(&env)?.try_get_order_summary(&order_id) {
+            Ok(Ok(order)) => order,
+            Err(Ok(OrderError::OrderNotFound)) => return Err(ReviewError::OrderNotFound),
+            _ => return Err(ReviewError::OrderContractNotSet),
+        };
+        if order.customer != customer {
+            return Err(ReviewError::Unauthorized);
+        }
+        if order.status != OrderStatus::Delivered {
+            return Err(ReviewError::OrderNotDelivered);
+        }
+        let restaurant_id = order.restaurant_id;
+        load_restaurant(&env, restaurant_id)?;
+
+        let review = Review {
+            order_id,
+            restaurant_id,
+            customer: customer.clone(),
+            rating,
+            comment_hash,
+            created_at: env.ledger().timestamp(),
+            response_hash: None,
+            responded_at: 0,
+        };
+        save(&env, &key, &review);
+
+        let mut summary = Self::get_rating_summary(env.clone(), restaurant_id);
+        save(
+            &env,
+            &DataKey::RestaurantReview(restaurant_id, summary.review_count),
+            &order_id,
+        );
+        summary.review_count += 1;
+        summary.rating_total += rating as u64;
+        let index = rating - MIN_RATING;
+        let stars = summary.star_counts.get_unchecked(index);
+        summary.star_counts.set(index, stars + 1);
+        save(&env, &DataKey::RatingSummary(restaurant_id), &summary);
+
+        ReviewSubmitted {
+            restaurant_id,
+            order_id,
+            customer,
+            rating,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Post the restaurant's response to a review.
+    ///
+    /// Callable by the restaurant's owner or one of its managers, once per
+    /// review.
+    ///
+    /// # Errors
+    /// - [`ReviewError::ReviewNotFound`] if the order has no review.
+    /// - [`ReviewError::Unauthorized`] if `caller` is neither owner nor
+    ///   manager.
+    /// - [`ReviewError::AlreadyResponded`] if a response was already posted.
+    pub fn respond_to_review(
+        env: Env,
+        caller: Address,
+        order_id: u64,
+        response_hash: BytesN<32>,
+    ) -> Result<(), ReviewError> {
+        caller.require_auth();
+
+        let mut review = Self::get_review(env.clone(), order_id)?;
+        let restaurant = load_restaurant(&env, review.restaurant_id)?;
+        if caller != restaurant.owner
+            && !Self::has_role(
+                env.clone(),
+                restaurant.id,
+                caller.clone(),
+                StaffRole::Manager,
+            )
+        {
+            return Err(ReviewError::Unauthorized);
+        }
+        if review.response_hash.is_some() {
+            return Err(ReviewError::AlreadyResponded);
+        }
+
+        review.response_hash = Some(response_hash);
+        review.responded_at = env.ledger().timestamp();
+        save(&env, &DataKey::Review(order_id), &review);
+
+        ReviewResponded {
+            restaurant_id: restaurant.id,
+            order_id,
+            responder: caller,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Reads (view)
+    // -----------------------------------------------------------------------
+
+    /// Fetch the review of an order.
+    pub fn get_review(env: Env, order_id: u64) -> Result<Review, ReviewError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Review(order_id))
+            .ok_or(ReviewError::ReviewNotFound)
+    }
+
+    /// Return the IDs of a restaurant's reviewed orders, oldest review
+    /// first.
+    ///
+    /// Returns up to `limit` IDs (capped at [`MAX_PAGE_SIZE`]) starting from
+    /// index `start`. To fetch the next page, call again with `start`
+    /// advanced by the number of IDs returned; an empty result means the
+    /// end of the reviews has been reached.
+    pub fn get_restaurant_reviews(
+        env: Env,
+        restaurant_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        let count = Self::get_rating_summary(env.clone(), restaurant_id).review_count;
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+        let storage = env.storage().persistent();
+        let mut page = vec![&env];
+        for index in start..end {
+            if let Some(order_id) = storage.get(&DataKey::RestaurantReview(restaurant_id, index)) {
+                page.push_back(order_id);
+            }
+        }
+        page
+    }
+
+    /// Return the aggregate of a restaurant's ratings.
+    pub fn get_rating_summary(env: Env, restaurant_id: u64) -> RatingSummary {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RatingSummary(restaurant_id))
+            .unwrap_or_else(|| RatingSummary {
+                review_count: 0,
+                rating_total: 0,
+                star_counts: vec![&env, 0, 0, 0, 0, 0],
+            })
+    }
+
+    /// Return a restaurant's mean rating in hundredths of a star (e.g. `450`
+    /// for 4.5 stars), rounded down; `0` if it has no reviews.
+    pub fn average_rating(env: Env, restaurant_id: u64) -> u32 {
+        let summary = Self::get_rating_summary(env, restaurant_id);
+        if summary.review_count == 0 {
+            return 0;
+        }
+        (summary.rating_total * 100 / summary.review_count as u64) as u32
+    }
+
+    /// Return the Order contract reviews are checked against, if set.
+    pub fn order_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::OrderContract)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+pub(crate) fn order_client(env: &Env) -> Result<OrderClient<'_>, Error> {
+    let address: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::OrderContract)
+        .ok_or(Error::OrderContractNotSet)?;
+    Ok(OrderClient::new(env, &address))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::RatingSummary;
+    use crate::events::{assert_last_event, ReviewSubmitted};
+    use crate::{Error, RestaurantRegistry, RestaurantRegistryClient, ReviewError, StaffRole};
+    use order::{
+        Fulfilment, FulfilmentChannel, OrderContract, OrderContractClient, OrderItem, OrderStatus,
+    };
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{vec, Address, BytesN, Env, String};
+
+    /// Deploy a registry and an order contract wired to each other, with one
+    /// restaurant selling one item.
+    fn setup() -> (
+        Env,
+        RestaurantRegistryClient<'static>,
+        OrderContractClient<'static>,
+        Address,
+        Address,
+    ) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+
+        let registry_id = env.register(RestaurantRegistry, ());
+        let registry = RestaurantRegistryClient::new(&env, &registry_id);
+        registry.initialize(&admin);
+        let id = registry.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "mamas-kitchen"),
+        );
+        registry.add_menu_item(
+            &owner,
+            &id,
+            &String::from_str(&env, "Jollof Rice"),
+            &5_000_000,
+            &Address::generate(&env),
+        );
+
+        let orders_id = env.register(OrderContract, ());
+        let orders = OrderContractClient::new(&env, &orders_id);
+        orders.initialize(&admin, &registry_id);
+        registry.set_order_contract(&admin, &orders_id);
+
+        (env, registry, orders, admin, owner)
+    }
+
+    /// Place an order for restaurant 1 and move it to `status`.
+    fn order(
+        env: &Env,
+        orders: &OrderContractClient,
+        admin: &Address,
+        customer: &Address,
+        status: OrderStatus,
+    ) -> u64 {
+        let item = OrderItem {
+            menu_item_id: 1,
+            name: String::from_str(env, ""),
+            quantity: 1,
+            unit_price: 5_000_000,
+        };
+        let id = orders.place_order(
+            customer,
+            &1,
+            &vec![env, item],
+            &String::from_str(env, ""),
+            &None,
+            &Fulfilment {
+                channel: FulfilmentChannel::Pickup,
+                table_id: None,
+            },
+        );
+        orders.set_status(admin, &id, &status, &None);
+        id
+    }
+
+    fn hash(env: &Env, byte: u8) -> BytesN<32> {
+        BytesN::from_array(env, &[byte; 32])
+    }
+
+    // -----------------------------------------------------------------------
+    // Submitting
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_review_delivered_order() {
+        let (env, registry, orders, admin, _owner) = setup();
+        let customer = Address::generate(&env);
+        let first = order(&env, &orders, &admin, &customer, OrderStatus::Delivered);
+        let second = order(&env, &orders, &admin, &customer, OrderStatus::Delivered);
+
+        registry.submit_review(&customer, &first, &5, &hash(&env, 1));
+        registry.submit_review(&customer, &second, &4, &hash(&env, 2));
+        assert_last_event(
+            &env,
+            &registry.address,
+            ReviewSubmitted {
+                restaurant_id: 1,
+                order_id: second,
+                customer: customer.clone(),
+                rating: 4,
+            },
+        );
+
+        let review = registry.get_review(&first);
+        assert_eq!(review.restaurant_id, 1);
+        assert_eq!(review.customer, customer);
+        assert_eq!(review.rating, 5);
+        assert_eq!(review.response_hash, None);
+        assert_eq!(
+            registry.get_restaurant_reviews(&1, &0, &10),
+            vec![&env, first, second]
+        );
+        assert_eq!(
+            registry.get_restaurant_reviews(&1, &1, &1),
+            vec![&env, second]
+        );
+        assert_eq!(registry.get_restaurant_reviews(&1, &2, &10), vec![&env]);
+        assert_eq!(
+            registry.get_rating_summary(&1),
+            RatingSummary {
+                review_count: 2,
+                rating_total: 9,
+                star_counts: vec![&env, 0, 0, 0, 1, 1],
+            }
+        );
+        assert_eq!(registry.average_rating(&1), 450);
+    }
+
+    #[test]
+    fn test_review_rejections() {
+        let (env, registry, orders, admin, _owner) = setup();
+        let customer = Address::generate(&env);
+        let delivered = order(&env, &orders, &admin, &customer, OrderStatus::Delivered);
+        let ready = order(&env, &orders, &admin, &customer, OrderStatus::Ready);
+
+        assert_eq!(
+            registry.try_submit_review(&customer, &delivered, &0, &hash(&env, 1)),
+            Err(Ok(ReviewError::InvalidRating))
+        );
+        assert_eq!(
+            registry.try_submit_review(&customer, &delivered, &6, &hash(&env, 1)),
+            Err(Ok(ReviewError::InvalidRating))
+        );
+        assert_eq!(
+            registry.try_submit_review(&Address::generate(&env), &delivered, &5, &hash(&env, 1)),
+            Err(Ok(ReviewError::Unauthorized))
+        );
+        assert_eq!(
+            registry.try_submit_review(&customer, &ready, &5, &hash(&env, 1)),
+            Err(Ok(ReviewError::OrderNotDelivered))
+        );
+        assert_eq!(
+            registry.try_submit_review(&customer, &99, &5, &hash(&env, 1)),
+            Err(Ok(ReviewError::OrderNotFound))
+        );
+
+        registry.submit_review(&customer, &delivered, &3, &hash(&env, 1));
+        assert_eq!(
+            registry.try_submit_review(&customer, &delivered, &5, &hash(&env, 2)),
+            Err(Ok(ReviewError::AlreadyReviewed))
+        );
+        assert_eq!(registry.average_rating(&1), 300);
+    }
+
+    #[test]
+    fn test_review_needs_order_contract() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let registry = RestaurantRegistryClient::new(&env, &env.register(RestaurantRegistry, ()));
+        registry.initialize(&Address::generate(&env));
+
+        assert_eq!(registry.order_contract(), None);
+        assert_eq!(
+            registry.try_submit_review(&Address::generate(&env), &1, &5, &hash(&env, 1)),
+            Err(Ok(ReviewError::OrderContractNotSet))
+        );
+        assert_eq!(
+            registry.try_set_order_contract(&Address::generate(&env), &Address::generate(&env)),
+            Err(Ok(Error::NotAdmin))
+        );
+    }
+
+    #[test]
+    fn test_misconfigured_order_contract_reported() {
+        let (env, registry, _orders, admin, _owner) = setup();
+
+        // The registry itself has no `get_order_summary`.
+        registry.set_order_contract(&admin, &registry.address);
+        assert_eq!(
+            registry.try_submit_review(&Address::generate(&env), &1, &5, &hash(&env, 1)),
+            Err(Ok(ReviewError::OrderContractNotSet))
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Responding
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_respond_to_review() {
+        let (env, registry, orders, admin, owner) = setup();
+        let customer = Address::generate(&env);
+        let manager = Address::generate(&env);
+        let cashier = Address::generate(&env);
+        registry.grant_role(&owner, &1, &manager, &StaffRole::Manager);
+        registry.grant_role(&owner, &1, &cashier, &StaffRole::Cashier);
+
+        let first = order(&env, &orders, &admin, &customer, OrderStatus::Delivered);
+        let second = order(&env, &orders, &admin, &customer, OrderStatus::Delivered);
+        registry.submit_review(&customer, &first, &2, &hash(&env, 1));
+        registry.submit_review(&customer, &second, &5, &hash(&env, 2));
+
+        assert_eq!(
+            registry.try_respond_to_review(&cashier, &first, &hash(&env, 9)),
+            Err(Ok(ReviewError::Unauthorized))
+        );
+        registry.respond_to_review(&owner, &first, &hash(&env, 9));
+        registry.respond_to_review(&manager, &second, &hash(&env, 8));
+        assert_eq!(
+            registry.get_review(&first).response_hash,
+            Some(hash(&env, 9))
+        );
+
+        assert_eq!(
+            registry.try_respond_to_review(&owner, &first, &hash(&env, 7)),
+            Err(Ok(ReviewError::AlreadyResponded))
+        );
+        assert_eq!(
+            registry.try_respond_to_review(&owner, &99, &hash(&env, 7)),
+            Err(Ok(ReviewError::ReviewNotFound))
+        );
+    }
+}
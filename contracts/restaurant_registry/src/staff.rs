@@ -0,0 +1,390 @@
+//! # Staff roles
+//!
+//! Restaurant owners grant staff accounts one or more roles per restaurant.
+//! Each role carries a fixed set of permissions; other contracts query them
+//! through `has_role` / `has_permission`. The Order contract checks the order
+//! permissions (including `CancelOrder`); the Payment contract checks
+//! `RefundPayment`.
+//! Roles granted by a restaurant's group apply there too.
+//!
+//! | Permission      | Manager | Kitchen | Cashier |
+//! |-----------------|:-------:|:-------:|:-------:|
+//! | `ManageMenu`    |    ✓    |         |         |
+//! | `ConfirmOrder`  |    ✓    |         |    ✓    |
+//! | `PrepareOrder`  |    ✓    |    ✓    |         |
+//! | `ReadyOrder`    |    ✓    |    ✓    |         |
+//! | `DeliverOrder`  |    ✓    |         |    ✓    |
+//! | `CancelOrder`   |    ✓    |         |    ✓    |
+//! | `RefundPayment` |    ✓    |         |         |
+
+use soroban_sdk::{contractimpl, contracttype, vec, Address, Env, Vec};
+
+use crate::closing::assert_not_closed;
+use crate::events::{Event, RoleGranted, RoleRevoked};
+use crate::groups::group_roles;
+use crate::{
+    assert_owner_or_admin, load_restaurant, read_admin, ttl::save, DataKey, Error, Restaurant,
+    RestaurantRegistry, RestaurantRegistryArgs, RestaurantRegistryClient,
+};
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// A role that can be granted to a staff account for one restaurant.
+#[contracttype]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StaffRole {
+    /// Runs the restaurant day to day; holds every permission.
+    Manager,
+    /// Prepares food; moves orders to `Preparing` and `Ready`.
+    Kitchen,
+    /// Front of house; confirms, hands over and cancels orders.
+    Cashier,
+}
+
+/// An individual action gated by a staff role.
+#[contracttype]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Permission {
+    /// Add, edit and toggle menu items.
+    ManageMenu,
+    /// Move an order from `Pending` to `Confirmed`.
+    ConfirmOrder,
+    /// Move an order from `Confirmed` to `Preparing`.
+    PrepareOrder,
+    /// Move an order from `Preparing` to `Ready`.
+    ReadyOrder,
+    /// Move an order from `Ready` to `Delivered`.
+    DeliverOrder,
+    /// Cancel an order that has not been delivered.
+    CancelOrder,
+    /// Refund an escrowed payment to the customer.
+    RefundPayment,
+}
+
+impl StaffRole {
+    /// Whether this role carries `permission`.
+    pub fn allows(&self, permission: Permission) -> bool {
+        match self {
+            StaffRole::Manager => true,
+            StaffRole::Kitchen => matches!(
+                permission,
+                Permission::PrepareOrder | Permission::ReadyOrder
+            ),
+            StaffRole::Cashier => matches!(
+                permission,
+                Permission::ConfirmOrder | Permission::DeliverOrder | Permission::CancelOrder
+            ),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contractimpl]
+impl RestaurantRegistry {
+    /// Grant `role` at `restaurant_id` to `staff`.
+    ///
+    /// Callable by the restaurant's owner **or** the contract admin.
+    pub fn grant_role(
+        env: Env,
+        caller: Address,
+        restaurant_id: u64,
+        staff: Address,
+        role: StaffRole,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_owner_or_admin(&env, &caller, &restaurant)?;
+
+        let mut roles = staff_roles(&env, restaurant_id, &staff);
+        if roles.contains(role) {
+            return Err(Error::RoleAlreadyGranted);
+        }
+        if roles.is_empty() {
+            let mut members = Self::get_staff(env.clone(), restaurant_id);
+            members.push_back(staff.clone());
+            save(&env, &DataKey::StaffMembers(restaurant_id), &members);
+        }
+        roles.push_back(role);
+        save(
+            &env,
+            &DataKey::StaffRoles(restaurant_id, staff.clone()),
+            &roles,
+        );
+
+        RoleGranted {
+            restaurant_id,
+            staff,
+            role,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Revoke `role` at `restaurant_id` from `staff`.
+    ///
+    /// Callable by the restaurant's owner **or** the contract admin.
+    pub fn revoke_role(
+        env: Env,
+        caller: Address,
+        restaurant_id: u64,
+        staff: Address,
+        role: StaffRole,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_owner_or_admin(&env, &caller, &restaurant)?;
+
+        let mut roles = staff_roles(&env, restaurant_id, &staff);
+        let index = roles.first_index_of(role).ok_or(Error::RoleNotGranted)?;
+        roles.remove(index);
+
+        let key = DataKey::StaffRoles(restaurant_id, staff.clone());
+        if roles.is_empty() {
+            env.storage().persistent().remove(&key);
+            let mut members = Self::get_staff(env.clone(), restaurant_id);
+            if let Some(i) = members.first_index_of(&staff) {
+                members.remove(i);
+            }
+            save(&env, &DataKey::StaffMembers(restaurant_id), &members);
+        } else {
+            save(&env, &key, &roles);
+        }
+
+        RoleRevoked {
+            restaurant_id,
+            staff,
+            role,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Whether `addr` holds `role` at `restaurant_id`, directly or through
+    /// the restaurant's group.
+    pub fn has_role(env: Env, restaurant_id: u64, addr: Address, role: StaffRole) -> bool {
+        staff_roles(&env, restaurant_id, &addr).contains(role)
+            || group_roles(&env, restaurant_id, &addr).contains(role)
+    }
+
+    /// Whether any role `addr` holds at `restaurant_id` grants `permission`.
+    ///
+    /// Only staff grants are considered; callers that also accept the owner
+    /// should check `Restaurant.owner` themselves.
+    pub fn has_permission(
+        env: Env,
+        restaurant_id: u64,
+        addr: Address,
+        permission: Permission,
+    ) -> bool {
+        has_staff_permission(&env, restaurant_id, &addr, permission)
+    }
+
+    /// Return every role granted to `addr` at `restaurant_id` itself,
+    /// excluding roles held through its group.
+    pub fn get_staff_roles(env: Env, restaurant_id: u64, addr: Address) -> Vec<StaffRole> {
+        staff_roles(&env, restaurant_id, &addr)
+    }
+
+    /// Return every address holding at least one role at `restaurant_id`.
+    pub fn get_staff(env: Env, restaurant_id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::StaffMembers(restaurant_id))
+            .unwrap_or_else(|| vec![&env])
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Fail unless `caller` is the restaurant's owner, the contract admin, or a
+/// staff member whose roles grant `permission`.
+pub(crate) fn assert_can(
+    env: &Env,
+    caller: &Address,
+    restaurant: &Restaurant,
+    permission: Permission,
+) -> Result<(), Error> {
+    assert_not_closed(env, restaurant.id)?;
+    if caller != &restaurant.owner
+        && caller != &read_admin(env)?
+        && !has_staff_permission(env, restaurant.id, caller, permission)
+    {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Revoke every role granted at `restaurant_id` itself, e.g. when the
+/// restaurant changes hands. Roles held through its group are untouched.
+pub(crate) fn clear_staff(env: &Env, restaurant_id: u64) {
+    let storage = env.storage().persistent();
+    let members_key = DataKey::StaffMembers(restaurant_id);
+    let members: Vec<Address> = storage.get(&members_key).unwrap_or_else(|| vec![env]);
+    for staff in members.iter() {
+        let key = DataKey::StaffRoles(restaurant_id, staff.clone());
+        for role in staff_roles(env, restaurant_id, &staff).iter() {
+            RoleRevoked {
+                restaurant_id,
+                staff: staff.clone(),
+                role,
+            }
+            .publish(env);
+        }
+        storage.remove(&key);
+    }
+    storage.remove(&members_key);
+}
+
+fn has_staff_permission(
+    env: &Env,
+    restaurant_id: u64,
+    addr: &Address,
+    permission: Permission,
+) -> bool {
+    staff_roles(env, restaurant_id, addr)
+        .iter()
+        .chain(group_roles(env, restaurant_id, addr).iter())
+        .any(|role| role.allows(permission))
+}
+
+fn staff_roles(env: &Env, restaurant_id: u64, addr: &Address) -> Vec<StaffRole> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StaffRoles(restaurant_id, addr.clone()))
+        .unwrap_or_else(|| vec![env])
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::{Permission, StaffRole};
+    use crate::events::{assert_last_event, RoleGranted, RoleRevoked};
+    use crate::{Error, RestaurantRegistry, RestaurantRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{vec, Address, Env, String};
+
+    fn setup() -> (Env, RestaurantRegistryClient<'static>, Address, u64) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(RestaurantRegistry, ());
+        let client = RestaurantRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&Address::generate(&env));
+        let id = client.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "mamas-kitchen"),
+        );
+        (env, client, owner, id)
+    }
+
+    #[test]
+    fn test_grant_and_revoke_role() {
+        let (env, client, owner, id) = setup();
+        let cook = Address::generate(&env);
+
+        client.grant_role(&owner, &id, &cook, &StaffRole::Kitchen);
+        assert_last_event(
+            &env,
+            &client.address,
+            RoleGranted {
+                restaurant_id: id,
+                staff: cook.clone(),
+                role: StaffRole::Kitchen,
+            },
+        );
+        assert!(client.has_role(&id, &cook, &StaffRole::Kitchen));
+        assert!(!client.has_role(&id, &cook, &StaffRole::Manager));
+        assert!(client.has_permission(&id, &cook, &Permission::ReadyOrder));
+        assert!(!client.has_permission(&id, &cook, &Permission::RefundPayment));
+        assert_eq!(client.get_staff(&id), vec![&env, cook.clone()]);
+
+        client.revoke_role(&owner, &id, &cook, &StaffRole::Kitchen);
+        assert_last_event(
+            &env,
+            &client.address,
+            RoleRevoked {
+                restaurant_id: id,
+                staff: cook.clone(),
+                role: StaffRole::Kitchen,
+            },
+        );
+        assert!(!client.has_role(&id, &cook, &StaffRole::Kitchen));
+        assert!(!client.has_permission(&id, &cook, &Permission::ReadyOrder));
+        assert_eq!(client.get_staff(&id).len(), 0);
+    }
+
+    #[test]
+    fn test_grants_are_per_restaurant() {
+        let (env, client, owner, id) = setup();
+        let manager = Address::generate(&env);
+        let other_id = client.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Mama's Annex"),
+            &String::from_str(&env, "mamas-annex"),
+        );
+
+        client.grant_role(&owner, &id, &manager, &StaffRole::Manager);
+        assert!(client.has_permission(&id, &manager, &Permission::RefundPayment));
+        assert!(!client.has_permission(&other_id, &manager, &Permission::RefundPayment));
+    }
+
+    #[test]
+    fn test_manager_can_manage_menu() {
+        let (env, client, owner, id) = setup();
+        let manager = Address::generate(&env);
+
+        client.grant_role(&owner, &id, &manager, &StaffRole::Manager);
+        let item_id = client.add_menu_item(
+            &manager,
+            &id,
+            &String::from_str(&env, "Jollof Rice"),
+            &5_000_000,
+            &Address::generate(&env),
+        );
+        assert_eq!(client.get_menu_item(&id, &item_id).price, 5_000_000);
+    }
+
+    #[test]
+    fn test_kitchen_cannot_manage_menu() {
+        let (env, client, owner, id) = setup();
+        let cook = Address::generate(&env);
+
+        client.grant_role(&owner, &id, &cook, &StaffRole::Kitchen);
+        assert_eq!(
+            client.try_add_menu_item(
+                &cook,
+                &id,
+                &String::from_str(&env, "Jollof Rice"),
+                &5_000_000,
+                &Address::generate(&env),
+            ),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_staff_cannot_grant_roles() {
+        let (env, client, owner, id) = setup();
+        let manager = Address::generate(&env);
+
+        client.grant_role(&owner, &id, &manager, &StaffRole::Manager);
+        assert_eq!(
+            client.try_grant_role(&manager, &id, &Address::generate(&env), &StaffRole::Kitchen),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+}
@@ -0,0 +1,302 @@
+//! # Suspension
+//!
+//! Admin-only sanction that sits alongside the owner's `is_active` flag.
+//! Deactivation is the owner's own choice and can be reversed by them; a
+//! suspension can only be lifted by the admin or by reaching its end time.
+//!
+//! While suspended, a restaurant reports [`RestaurantStatus::Suspended`] and
+//! `is_open_at` returns `false`, so the Order contract refuses new orders.
+//! The owner may file one appeal per suspension for the admin to review.
+
+use soroban_sdk::{contractimpl, contracttype, Address, BytesN, Env};
+
+use crate::closing::is_closed;
+use crate::events::{Event, RestaurantSuspended, SuspensionAppealed, SuspensionLifted};
+use crate::{
+    assert_admin, extend_persistent, load_restaurant, DataKey, Error, RestaurantRegistry,
+    RestaurantRegistryArgs, RestaurantRegistryClient,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+/// Why a restaurant was suspended.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum SuspensionReason {
+    Fraud = 1,
+    FoodSafety = 2,
+    Compliance = 3,
+    CustomerComplaints = 4,
+    Other = 5,
+}
+
+/// An admin-imposed suspension of a restaurant.
+#[contracttype]
+#[derive(Clone)]
+pub struct Suspension {
+    pub reason: SuspensionReason,
+    pub suspended_by: Address,
+    pub suspended_at: u64,
+    /// Ledger timestamp at which the suspension ends by itself; `None` means
+    /// until lifted by the admin.
+    pub until: Option<u64>,
+    /// Hash of the owner's off-chain appeal statement, once filed.
+    pub appeal_hash: Option<BytesN<32>>,
+    /// Ledger timestamp at which the appeal was filed; `0` if none.
+    pub appealed_at: u64,
+}
+
+impl Suspension {
+    fn is_in_force(&self, now: u64) -> bool {
+        self.until.is_none_or(|until| now < until)
+    }
+}
+
+/// Overall state of a restaurant, combining the owner's active flag with
+/// any suspension.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RestaurantStatus {
+    Active,
+    /// Deactivated by its owner (or the admin) through `set_active`.
+    Inactive,
+    /// Suspended by the admin; takes precedence over the active flag.
+    Suspended,
+    /// Closed for good through `close_restaurant`; takes precedence over
+    /// everything else.
+    Closed,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contractimpl]
+impl RestaurantRegistry {
+    /// Suspend a restaurant (admin only).
+    ///
+    /// Replaces any existing suspension, including its appeal.
+    pub fn suspend_restaurant(
+        env: Env,
+        admin: Address,
+        restaurant_id: u64,
+        reason: SuspensionReason,
+        until: Option<u64>,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        assert_admin(&env, &admin)?;
+        load_restaurant(&env, restaurant_id)?;
+
+        let now = env.ledger().timestamp();
+        if until.is_some_and(|until| until <= now) {
+            return Err(Error::SuspensionEndInPast);
+        }
+
+        save_suspension(
+            &env,
+            restaurant_id,
+            &Suspension {
+                reason,
+                suspended_by: admin,
+                suspended_at: now,
+                until,
+                appeal_hash: None,
+                appealed_at: 0,
+            },
+        );
+
+        RestaurantSuspended {
+            restaurant_id,
+            reason,
+            until,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Lift a restaurant's suspension (admin only).
+    pub fn lift_suspension(env: Env, admin: Address, restaurant_id: u64) -> Result<(), Error> {
+        admin.require_auth();
+        assert_admin(&env, &admin)?;
+
+        let key = DataKey::Suspension(restaurant_id);
+        if !env.storage().persistent().has(&key) {
+            return Err(Error::NotSuspended);
+        }
+        env.storage().persistent().remove(&key);
+
+        SuspensionLifted { restaurant_id }.publish(&env);
+        Ok(())
+    }
+
+    /// Appeal a suspension that is in force (owner only, once per
+    /// suspension).
+    pub fn appeal_suspension(
+        env: Env,
+        owner: Address,
+        restaurant_id: u64,
+        evidence_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        if restaurant.owner != owner {
+            return Err(Error::Unauthorized);
+        }
+        let mut suspension =
+            Self::get_suspension(env.clone(), restaurant_id).ok_or(Error::NotSuspended)?;
+        if suspension.appeal_hash.is_some() {
+            return Err(Error::AppealAlreadyFiled);
+        }
+
+        suspension.appeal_hash = Some(evidence_hash.clone());
+        suspension.appealed_at = env.ledger().timestamp();
+        save_suspension(&env, restaurant_id, &suspension);
+
+        SuspensionAppealed {
+            restaurant_id,
+            evidence_hash,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Return a restaurant's suspension if one is in force.
+    pub fn get_suspension(env: Env, restaurant_id: u64) -> Option<Suspension> {
+        env.storage()
+            .persistent()
+            .get::<_, Suspension>(&DataKey::Suspension(restaurant_id))
+            .filter(|s| s.is_in_force(env.ledger().timestamp()))
+    }
+
+    /// Return a restaurant's overall status.
+    pub fn get_status(env: Env, restaurant_id: u64) -> Result<RestaurantStatus, Error> {
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        Ok(if is_closed(&env, restaurant_id) {
+            RestaurantStatus::Closed
+        } else if Self::get_suspension(env, restaurant_id).is_some() {
+            RestaurantStatus::Suspended
+        } else if restaurant.is_active {
+            RestaurantStatus::Active
+        } else {
+            RestaurantStatus::Inactive
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn save_suspension(env: &Env, restaurant_id: u64, suspension: &Suspension) {
+    let key = DataKey::Suspension(restaurant_id);
+    env.storage().persistent().set(&key, suspension);
+    extend_persistent(env, &key);
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::{RestaurantStatus, SuspensionReason};
+    use crate::events::{assert_last_event, SuspensionAppealed};
+    use crate::{Error, RestaurantRegistry, RestaurantRegistryClient};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, BytesN, Env, String};
+
+    fn setup() -> (
+        Env,
+        RestaurantRegistryClient<'static>,
+        Address,
+        Address,
+        u64,
+    ) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(RestaurantRegistry, ());
+        let client = RestaurantRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        client.initialize(&admin);
+        let id = client.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "mamas-kitchen"),
+        );
+        (env, client, admin, owner, id)
+    }
+
+    #[test]
+    fn test_owner_cannot_override_suspension() {
+        let (_env, client, admin, owner, id) = setup();
+
+        client.suspend_restaurant(&admin, &id, &SuspensionReason::Fraud, &None);
+        assert_eq!(client.get_status(&id), RestaurantStatus::Suspended);
+        assert!(!client.is_open_at(&id, &0));
+
+        // Toggling the active flag does not clear the suspension.
+        client.set_active(&owner, &id, &false);
+        client.set_active(&owner, &id, &true);
+        assert_eq!(client.get_status(&id), RestaurantStatus::Suspended);
+
+        client.lift_suspension(&admin, &id);
+        assert_eq!(client.get_status(&id), RestaurantStatus::Active);
+        assert!(client.is_open_at(&id, &0));
+        assert_eq!(
+            client.try_lift_suspension(&admin, &id),
+            Err(Ok(Error::NotSuspended))
+        );
+    }
+
+    #[test]
+    fn test_suspension_expires() {
+        let (env, client, admin, _owner, id) = setup();
+
+        env.ledger().set_timestamp(1_000);
+        client.suspend_restaurant(&admin, &id, &SuspensionReason::FoodSafety, &Some(2_000));
+        assert!(client.get_suspension(&id).is_some());
+
+        env.ledger().set_timestamp(2_000);
+        assert!(client.get_suspension(&id).is_none());
+        assert_eq!(client.get_status(&id), RestaurantStatus::Active);
+    }
+
+    #[test]
+    fn test_appeal_recorded_once() {
+        let (env, client, admin, owner, id) = setup();
+        let evidence = BytesN::from_array(&env, &[9; 32]);
+
+        client.suspend_restaurant(&admin, &id, &SuspensionReason::Compliance, &None);
+        client.appeal_suspension(&owner, &id, &evidence);
+        assert_last_event(
+            &env,
+            &client.address,
+            SuspensionAppealed {
+                restaurant_id: id,
+                evidence_hash: evidence.clone(),
+            },
+        );
+
+        let suspension = client.get_suspension(&id).unwrap();
+        assert_eq!(suspension.appeal_hash, Some(evidence.clone()));
+        assert_eq!(
+            client.try_appeal_suspension(&owner, &id, &evidence),
+            Err(Ok(Error::AppealAlreadyFiled))
+        );
+    }
+
+    #[test]
+    fn test_owner_cannot_suspend() {
+        let (_env, client, _admin, owner, id) = setup();
+        assert_eq!(
+            client.try_suspend_restaurant(&owner, &id, &SuspensionReason::Other, &None),
+            Err(Ok(Error::NotAdmin))
+        );
+    }
+}
@@ -0,0 +1,255 @@
+//! # Tables
+//!
+//! Dine-in restaurants register their tables (or the QR codes placed on
+//! them) here. Each table has a per-restaurant ID encoded in its QR code, a
+//! display label and an active flag. The Order contract checks dine-in
+//! orders against these records, so only active tables can take orders.
+//!
+//! Tables are part of the QR menu, so they are managed by the same callers
+//! as the menu: the owner, staff with `ManageMenu`, or the registry admin.
+
+use soroban_sdk::{contractimpl, contracttype, vec, Address, Env, String, Vec};
+
+use crate::events::{Event, TableActiveSet, TableAdded, TableUpdated};
+use crate::staff::assert_can;
+use crate::{
+    load_restaurant, ttl::save, validate_name, DataKey, Error, Permission, RestaurantRegistry,
+    RestaurantRegistryArgs, RestaurantRegistryClient, MAX_PAGE_SIZE,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+/// A table or QR code at a restaurant.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Table {
+    /// Per-restaurant auto-incrementing identifier (starts at 1).
+    pub id: u64,
+    pub restaurant_id: u64,
+    /// Display label, e.g. "Patio 4". Same limits as a restaurant name.
+    pub label: String,
+    /// Whether dine-in orders can be placed at this table.
+    pub is_active: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contractimpl]
+impl RestaurantRegistry {
+    /// Register a table at a restaurant.
+    ///
+    /// Callable by the restaurant's owner, staff with `ManageMenu`, or the
+    /// contract admin.
+    ///
+    /// # Returns
+    /// The newly assigned table ID (starts at 1 per restaurant).
+    pub fn add_table(
+        env: Env,
+        caller: Address,
+        restaurant_id: u64,
+        label: String,
+    ) -> Result<u64, Error> {
+        caller.require_auth();
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_can(&env, &caller, &restaurant, Permission::ManageMenu)?;
+        validate_name(&label)?;
+
+        let count: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TableCount(restaurant_id))
+            .unwrap_or(0);
+        let table_id = count + 1;
+
+        let table = Table {
+            id: table_id,
+            restaurant_id,
+            label: label.clone(),
+            is_active: true,
+        };
+        save(&env, &DataKey::Table(restaurant_id, table_id), &table);
+        save(&env, &DataKey::TableCount(restaurant_id), &table_id);
+
+        TableAdded {
+            restaurant_id,
+            table_id,
+            label,
+        }
+        .publish(&env);
+        Ok(table_id)
+    }
+
+    /// Rename a table.
+    ///
+    /// Callable by the restaurant's owner, staff with `ManageMenu`, or the
+    /// contract admin.
+    pub fn update_table(
+        env: Env,
+        caller: Address,
+        restaurant_id: u64,
+        table_id: u64,
+        label: String,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_can(&env, &caller, &restaurant, Permission::ManageMenu)?;
+        validate_name(&label)?;
+
+        let mut table = Self::get_table(env.clone(), restaurant_id, table_id)?;
+        table.label = label.clone();
+        save(&env, &DataKey::Table(restaurant_id, table_id), &table);
+
+        TableUpdated {
+            restaurant_id,
+            table_id,
+            label,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Open or close a table for dine-in orders.
+    ///
+    /// Callable by the restaurant's owner, staff with `ManageMenu`, or the
+    /// contract admin.
+    pub fn set_table_active(
+        env: Env,
+        caller: Address,
+        restaurant_id: u64,
+        table_id: u64,
+        active: bool,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+        assert_can(&env, &caller, &restaurant, Permission::ManageMenu)?;
+
+        let mut table = Self::get_table(env.clone(), restaurant_id, table_id)?;
+        table.is_active = active;
+        save(&env, &DataKey::Table(restaurant_id, table_id), &table);
+
+        TableActiveSet {
+            restaurant_id,
+            table_id,
+            active,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Fetch a single table.
+    pub fn get_table(env: Env, restaurant_id: u64, table_id: u64) -> Result<Table, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Table(restaurant_id, table_id))
+            .ok_or(Error::TableNotFound)
+    }
+
+    /// List a restaurant's tables in ID order, including inactive ones.
+    ///
+    /// Returns up to `limit` tables (capped at [`MAX_PAGE_SIZE`]) starting
+    /// from table ID `start`. To fetch the next page, call again with
+    /// `start` set to the last returned ID plus one; an empty result means
+    /// the end of the tables has been reached.
+    pub fn get_tables(
+        env: Env,
+        restaurant_id: u64,
+        start: u64,
+        limit: u32,
+    ) -> Result<Vec<Table>, Error> {
+        let count: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TableCount(restaurant_id))
+            .unwrap_or(0);
+        let start = start.max(1);
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE).into())
+            .min(count + 1);
+
+        let mut tables = vec![&env];
+        for table_id in start..end {
+            tables.push_back(Self::get_table(env.clone(), restaurant_id, table_id)?);
+        }
+        Ok(tables)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::{Error, RestaurantRegistry, RestaurantRegistryClient, StaffRole};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{Address, Env, String};
+
+    fn setup() -> (Env, RestaurantRegistryClient<'static>, Address, u64) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(RestaurantRegistry, ());
+        let client = RestaurantRegistryClient::new(&env, &contract_id);
+
+        client.initialize(&Address::generate(&env));
+        let owner = Address::generate(&env);
+        let id = client.register_restaurant(
+            &owner,
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "mamas-kitchen"),
+        );
+        (env, client, owner, id)
+    }
+
+    #[test]
+    fn test_add_and_manage_tables() {
+        let (env, client, owner, id) = setup();
+        let manager = Address::generate(&env);
+        client.grant_role(&owner, &id, &manager, &StaffRole::Manager);
+
+        let first = client.add_table(&owner, &id, &String::from_str(&env, "Table 1"));
+        let second = client.add_table(&manager, &id, &String::from_str(&env, "Patio"));
+        assert_eq!((first, second), (1, 2));
+
+        client.update_table(&manager, &id, &second, &String::from_str(&env, "Patio 4"));
+        client.set_table_active(&owner, &id, &first, &false);
+
+        let tables = client.get_tables(&id, &0, &10);
+        assert_eq!(tables.len(), 2);
+        assert!(!tables.get_unchecked(0).is_active);
+        let page = client.get_tables(&id, &2, &1);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get_unchecked(0).id, second);
+        assert_eq!(client.get_tables(&id, &3, &10).len(), 0);
+        assert_eq!(
+            client.get_table(&id, &second).label,
+            String::from_str(&env, "Patio 4")
+        );
+    }
+
+    #[test]
+    fn test_table_rejections() {
+        let (env, client, owner, id) = setup();
+        let kitchen = Address::generate(&env);
+        client.grant_role(&owner, &id, &kitchen, &StaffRole::Kitchen);
+
+        assert_eq!(
+            client.try_add_table(&kitchen, &id, &String::from_str(&env, "Table 1")),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(
+            client.try_add_table(&owner, &id, &String::from_str(&env, "")),
+            Err(Ok(Error::NameEmpty))
+        );
+        assert_eq!(
+            client.try_set_table_active(&owner, &id, &1, &false),
+            Err(Ok(Error::TableNotFound))
+        );
+    }
+}
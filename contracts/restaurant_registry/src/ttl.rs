@@ -0,0 +1,232 @@
+//! # Storage TTL
+//!
+//! Every write extends the entries it touches according to the registry's
+//! [`TtlPolicy`], which the admin may tune. Restaurants that are read but
+//! rarely written (a busy menu that never changes, say) can be kept alive by
+//! anyone through the permissionless `bump_restaurant` and
+//! `bump_menu_items`.
+//!
+//! Contracts cannot read an entry's TTL, so the registry records the ledger
+//! until which it has extended each restaurant record; `restaurant_ttl`
+//! reports from that record. Entries extended outside the contract (e.g. by
+//! an `ExtendFootprintTTLOp`) may live longer than reported.
+//!
+//! The policy and the extension logic live in the shared `storage-ttl`
+//! crate; this module binds them to the registry's records.
+
+use soroban_sdk::{contractimpl, Address, Env, IntoVal, Val, Vec};
+pub use storage_ttl::{TtlPolicy, DAY_IN_LEDGERS};
+
+use crate::events::{Event, TtlPolicySet};
+use crate::{
+    assert_admin, load_restaurant, DataKey, Error, RestaurantRegistry, RestaurantRegistryArgs,
+    RestaurantRegistryClient,
+};
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contractimpl]
+impl RestaurantRegistry {
+    /// Replace the TTL policy (admin only).
+    ///
+    /// # Errors
+    /// [`Error::InvalidTtlPolicy`] if a threshold exceeds its extension, an
+    /// extension is zero, or an extension exceeds the network's maximum TTL.
+    pub fn set_ttl_policy(env: Env, admin: Address, policy: TtlPolicy) -> Result<(), Error> {
+        admin.require_auth();
+        assert_admin(&env, &admin)?;
+
+        if !policy.is_valid(env.storage().max_ttl()) {
+            return Err(Error::InvalidTtlPolicy);
+        }
+
+        env.storage().instance().set(&DataKey::TtlPolicy, &policy);
+        extend_instance(&env);
+
+        TtlPolicySet { policy }.publish(&env);
+        Ok(())
+    }
+
+    /// Return the TTL policy in force.
+    pub fn ttl_policy(env: Env) -> TtlPolicy {
+        policy(&env)
+    }
+
+    /// Extend a restaurant's record, slug, profile, opening hours and menu
+    /// and table counters, and the contract instance. Anyone may call this.
+    ///
+    /// # Returns
+    /// The restaurant record's remaining TTL, in ledgers.
+    pub fn bump_restaurant(env: Env, restaurant_id: u64) -> Result<u32, Error> {
+        let restaurant = load_restaurant(&env, restaurant_id)?;
+
+        let remaining = extend_restaurant(&env, restaurant_id);
+        for key in [
+            DataKey::SlugToId(restaurant.slug),
+            DataKey::Profile(restaurant_id),
+            DataKey::OpeningHours(restaurant_id),
+            DataKey::MenuCount(restaurant_id),
+            DataKey::TableCount(restaurant_id),
+        ] {
+            if env.storage().persistent().has(&key) {
+                extend_persistent(&env, &key);
+            }
+        }
+        extend_instance(&env);
+        Ok(remaining)
+    }
+
+    /// Extend the given menu items of a restaurant. Anyone may call this.
+    ///
+    /// Unknown item IDs are skipped.
+    pub fn bump_menu_items(env: Env, restaurant_id: u64, item_ids: Vec<u64>) {
+        for item_id in item_ids.iter() {
+            let key = DataKey::MenuItem(restaurant_id, item_id);
+            if env.storage().persistent().has(&key) {
+                extend_persistent(&env, &key);
+            }
+        }
+    }
+
+    /// Return the remaining TTL of a restaurant's record, in ledgers, as
+    /// last recorded by the registry.
+    ///
+    /// Records not extended since TTL tracking was introduced report `0`
+    /// until they are next written or bumped.
+    pub fn restaurant_ttl(env: Env, restaurant_id: u64) -> Result<u32, Error> {
+        load_restaurant(&env, restaurant_id)?;
+        Ok(storage_ttl::remaining_ttl(
+            &env,
+            &DataKey::LiveUntil(restaurant_id),
+        ))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn policy(env: &Env) -> TtlPolicy {
+    storage_ttl::policy(env, &DataKey::TtlPolicy)
+}
+
+/// Extend the contract instance according to the policy.
+pub(crate) fn extend_instance(env: &Env) {
+    storage_ttl::extend_instance(env, &policy(env));
+}
+
+/// Extend a persistent entry according to the policy.
+pub(crate) fn extend_persistent(env: &Env, key: &DataKey) {
+    storage_ttl::extend_persistent(env, &policy(env), key);
+}
+
+/// Write a persistent entry and extend it according to the policy.
+pub(crate) fn save<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    extend_persistent(env, key);
+}
+
+/// Extend a restaurant record and record until when it lives.
+///
+/// Returns the record's remaining TTL.
+pub(crate) fn extend_restaurant(env: &Env, restaurant_id: u64) -> u32 {
+    let live_until_key = DataKey::LiveUntil(restaurant_id);
+    storage_ttl::extend_tracked(env, &policy(env), &live_until_key, |extend_to| {
+        env.storage().persistent().extend_ttl(
+            &DataKey::Restaurant(restaurant_id),
+            extend_to,
+            extend_to,
+        );
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::{TtlPolicy, DAY_IN_LEDGERS};
+    use crate::{DataKey, Error, RestaurantRegistry, RestaurantRegistryClient};
+    use soroban_sdk::testutils::storage::Persistent as _;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, Env, String};
+
+    fn setup() -> (Env, RestaurantRegistryClient<'static>, Address, u64) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(RestaurantRegistry, ());
+        let client = RestaurantRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+        let id = client.register_restaurant(
+            &Address::generate(&env),
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "mamas-kitchen"),
+        );
+        (env, client, admin, id)
+    }
+
+    #[test]
+    fn test_bump_restaurant_reports_ttl() {
+        let (env, client, _admin, id) = setup();
+        let full = TtlPolicy::DEFAULT.persistent_extend_to;
+        assert_eq!(client.restaurant_ttl(&id), full);
+
+        env.ledger().with_mut(|l| l.sequence_number += 1_000);
+        assert_eq!(client.restaurant_ttl(&id), full - 1_000);
+
+        assert_eq!(client.bump_restaurant(&id), full);
+        assert_eq!(client.restaurant_ttl(&id), full);
+        env.as_contract(&client.address, || {
+            assert_eq!(
+                env.storage().persistent().get_ttl(&DataKey::Restaurant(id)),
+                full
+            );
+        });
+    }
+
+    #[test]
+    fn test_ttl_policy() {
+        let (env, client, admin, _id) = setup();
+        let policy = TtlPolicy {
+            instance_threshold: DAY_IN_LEDGERS,
+            instance_extend_to: 7 * DAY_IN_LEDGERS,
+            persistent_threshold: 10_000,
+            persistent_extend_to: 20_000,
+        };
+        client.set_ttl_policy(&admin, &policy);
+        assert_eq!(client.ttl_policy(), policy);
+
+        let id = client.register_restaurant(
+            &Address::generate(&env),
+            &String::from_str(&env, "Suya Spot"),
+            &String::from_str(&env, "suya-spot"),
+        );
+        assert_eq!(client.restaurant_ttl(&id), 20_000);
+
+        // Above the threshold a bump is a no-op; below it extends in full.
+        env.ledger().with_mut(|l| l.sequence_number += 5_000);
+        assert_eq!(client.bump_restaurant(&id), 15_000);
+        env.ledger().with_mut(|l| l.sequence_number += 5_001);
+        assert_eq!(client.bump_restaurant(&id), 20_000);
+
+        assert_eq!(
+            client.try_set_ttl_policy(
+                &admin,
+                &TtlPolicy {
+                    persistent_threshold: 30_000,
+                    ..policy
+                }
+            ),
+            Err(Ok(Error::InvalidTtlPolicy))
+        );
+        assert_eq!(
+            client.try_set_ttl_policy(&Address::generate(&env), &policy),
+            Err(Ok(Error::NotAdmin))
+        );
+    }
+}
@@ -0,0 +1,285 @@
+//! # Upgrades and migrations
+//!
+//! The admin replaces the contract code in place with `upgrade`, keeping
+//! the contract address and all stored data. When the new code changes the
+//! layout of stored records, the admin then calls `migrate` in batches until
+//! every legacy record has been rewritten. A migration cursor records how
+//! far the batches have got, and the stored schema version is only raised
+//! once the last restaurant has been examined.
+//!
+//! Schema versions:
+//! - **1** – original layout: `Restaurant` without `brand_id`, one
+//!   restaurant per owner (`OwnerToId`), no slug index.
+//! - **2** – current layout ([`SCHEMA_VERSION`]).
+
+use soroban_sdk::{
+    contractimpl, contracttype, Address, BytesN, Env, Map, String, Symbol, TryFromVal, Val,
+};
+
+use crate::events::{ContractMigrated, ContractUpgraded, Event};
+use crate::{
+    append_to_list, assert_admin, extend_instance, extend_persistent, save_restaurant, DataKey,
+    Error, Restaurant, RestaurantRegistry, RestaurantRegistryArgs, RestaurantRegistryClient,
+};
+
+/// Layout version of the records written by this code.
+pub const SCHEMA_VERSION: u32 = 2;
+
+// ---------------------------------------------------------------------------
+// Legacy layouts
+// ---------------------------------------------------------------------------
+
+/// `Restaurant` as stored by schema version 1.
+#[contracttype]
+#[derive(Clone)]
+struct RestaurantV1 {
+    id: u64,
+    owner: Address,
+    name: String,
+    slug: String,
+    is_active: bool,
+    created_at: u64,
+}
+
+/// Storage keys dropped after schema version 1.
+#[contracttype]
+enum LegacyKey {
+    OwnerToId(Address),
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contractimpl]
+impl RestaurantRegistry {
+    /// Replace the contract code with the uploaded Wasm `new_wasm_hash`
+    /// (admin only).
+    ///
+    /// Storage is left untouched; call `migrate` afterwards if the new code
+    /// raises [`SCHEMA_VERSION`].
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        admin.require_auth();
+        assert_admin(&env, &admin)?;
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        ContractUpgraded { new_wasm_hash }.publish(&env);
+        Ok(())
+    }
+
+    /// Rewrite the next `limit` restaurants from a legacy layout into the
+    /// current one (admin only).
+    ///
+    /// Each call resumes from the migration cursor, so the admin calls this
+    /// with batches small enough to fit the transaction budget until
+    /// `schema_version` reports [`SCHEMA_VERSION`]. The batch that passes
+    /// the last restaurant records [`SCHEMA_VERSION`] as the stored version
+    /// and clears the cursor. Does nothing once the data is current.
+    ///
+    /// # Returns
+    /// The number of restaurants rewritten by this call.
+    pub fn migrate(env: Env, admin: Address, limit: u32) -> Result<u32, Error> {
+        admin.require_auth();
+        assert_admin(&env, &admin)?;
+
+        if Self::schema_version(env.clone()) == SCHEMA_VERSION {
+            return Ok(0);
+        }
+
+        let instance = env.storage().instance();
+        let count: u64 = instance.get(&DataKey::Count).unwrap_or(0);
+        let start: u64 = instance.get(&DataKey::MigrationCursor).unwrap_or(1);
+        let end = start.saturating_add(limit.into()).min(count + 1);
+
+        let mut migrated = 0u32;
+        for id in start..end {
+            if migrate_restaurant(&env, id) {
+                migrated += 1;
+            }
+        }
+
+        if end > count {
+            instance.set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+            instance.remove(&DataKey::MigrationCursor);
+        } else {
+            instance.set(&DataKey::MigrationCursor, &end);
+        }
+        extend_instance(&env);
+
+        ContractMigrated {
+            schema_version: Self::schema_version(env.clone()),
+            migrated,
+        }
+        .publish(&env);
+        Ok(migrated)
+    }
+
+    /// Return the next restaurant ID `migrate` will examine, or `None` if no
+    /// migration is in progress.
+    pub fn migration_cursor(env: Env) -> Option<u64> {
+        env.storage().instance().get(&DataKey::MigrationCursor)
+    }
+
+    /// Return the layout version of the stored data.
+    ///
+    /// Deployments that predate versioning report `1`.
+    pub fn schema_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(1)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Rewrite one version-1 restaurant. Returns `false` if there is nothing to
+/// do.
+fn migrate_restaurant(env: &Env, restaurant_id: u64) -> bool {
+    let key = DataKey::Restaurant(restaurant_id);
+    let Some(fields) = env.storage().persistent().get::<_, Map<Symbol, Val>>(&key) else {
+        return false;
+    };
+    if fields.contains_key(Symbol::new(env, "brand_id")) {
+        return false;
+    }
+    let Ok(legacy) = RestaurantV1::try_from_val(env, fields.as_val()) else {
+        return false;
+    };
+
+    save_restaurant(
+        env,
+        &Restaurant {
+            id: legacy.id,
+            owner: legacy.owner.clone(),
+            brand_id: 0,
+            name: legacy.name,
+            slug: legacy.slug.clone(),
+            is_active: legacy.is_active,
+            created_at: legacy.created_at,
+        },
+    );
+
+    env.storage()
+        .persistent()
+        .remove(&LegacyKey::OwnerToId(legacy.owner.clone()));
+    append_to_list(env, DataKey::OwnerRestaurants(legacy.owner), legacy.id);
+
+    // Version 1 did not enforce unique slugs; the first restaurant migrated
+    // keeps a shared slug.
+    let slug_key = DataKey::SlugToId(legacy.slug);
+    if !env.storage().persistent().has(&slug_key) {
+        env.storage().persistent().set(&slug_key, &legacy.id);
+        extend_persistent(env, &slug_key);
+    }
+    true
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::{LegacyKey, RestaurantV1, SCHEMA_VERSION};
+    use crate::events::{assert_last_event, ContractMigrated};
+    use crate::{DataKey, Error, RestaurantRegistry, RestaurantRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{vec, Address, BytesN, Env, String};
+
+    fn setup() -> (Env, RestaurantRegistryClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(RestaurantRegistry, ());
+        let client = RestaurantRegistryClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+        (env, client, admin)
+    }
+
+    #[test]
+    fn test_migrate_version_1_restaurant() {
+        let (env, client, admin) = setup();
+        let owner = Address::generate(&env);
+        let other = Address::generate(&env);
+
+        // Simulate records written by a version-1 deployment.
+        env.as_contract(&client.address, || {
+            let storage = env.storage();
+            storage.instance().remove(&DataKey::SchemaVersion);
+            storage.instance().set(&DataKey::Count, &2u64);
+            for (id, owner, slug) in [(1, &owner, "mamas-kitchen"), (2, &other, "suya-spot")] {
+                storage.persistent().set(
+                    &DataKey::Restaurant(id),
+                    &RestaurantV1 {
+                        id,
+                        owner: owner.clone(),
+                        name: String::from_str(&env, "Mama's Kitchen"),
+                        slug: String::from_str(&env, slug),
+                        is_active: true,
+                        created_at: 0,
+                    },
+                );
+                storage
+                    .persistent()
+                    .set(&LegacyKey::OwnerToId(owner.clone()), &id);
+            }
+        });
+        assert_eq!(client.schema_version(), 1);
+
+        // A partial batch advances the cursor but leaves the version alone.
+        assert_eq!(client.migrate(&admin, &1), 1);
+        assert_last_event(
+            &env,
+            &client.address,
+            ContractMigrated {
+                schema_version: 1,
+                migrated: 1,
+            },
+        );
+        assert_eq!(client.schema_version(), 1);
+        assert_eq!(client.migration_cursor(), Some(2));
+
+        assert_eq!(client.migrate(&admin, &10), 1);
+        assert_last_event(
+            &env,
+            &client.address,
+            ContractMigrated {
+                schema_version: SCHEMA_VERSION,
+                migrated: 1,
+            },
+        );
+        assert_eq!(client.schema_version(), SCHEMA_VERSION);
+        assert_eq!(client.migration_cursor(), None);
+        assert_eq!(client.get_owner_restaurants(&other), vec![&env, 2]);
+        assert_eq!(client.get_restaurant(&1).brand_id, 0);
+        assert_eq!(client.get_owner_restaurants(&owner), vec![&env, 1]);
+        assert_eq!(
+            client
+                .get_restaurant_by_slug(&String::from_str(&env, "mamas-kitchen"))
+                .id,
+            1
+        );
+
+        // Migrating current data is a no-op.
+        assert_eq!(client.migrate(&admin, &10), 0);
+        assert_eq!(client.get_owner_restaurants(&owner), vec![&env, 1]);
+    }
+
+    #[test]
+    fn test_only_admin_upgrades_and_migrates() {
+        let (env, client, _admin) = setup();
+        let rando = Address::generate(&env);
+
+        assert_eq!(client.schema_version(), SCHEMA_VERSION);
+        assert_eq!(
+            client.try_upgrade(&rando, &BytesN::from_array(&env, &[0; 32])),
+            Err(Ok(Error::NotAdmin))
+        );
+        assert_eq!(client.try_migrate(&rando, &10), Err(Ok(Error::NotAdmin)));
+    }
+}
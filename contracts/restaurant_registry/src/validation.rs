@@ -0,0 +1,158 @@
+//! # Input validation
+//!
+//! Slugs become subdomains, so they are limited to lowercase ASCII letters,
+//! digits and single inner hyphens, within [`MIN_SLUG_LEN`]..=[`MAX_SLUG_LEN`]
+//! bytes, and may not be one of the platform's reserved words. Names are
+//! free text but capped at [`MAX_NAME_LEN`] bytes to bound storage rent.
+//! Profile geohashes use the geohash base32 alphabet and are at most
+//! [`MAX_GEOHASH_LEN`] characters.
+
+use soroban_sdk::String;
+
+use crate::Error;
+
+/// Maximum length of a restaurant or brand name, in bytes.
+pub const MAX_NAME_LEN: u32 = 64;
+/// Minimum length of a slug, in bytes.
+pub const MIN_SLUG_LEN: u32 = 3;
+/// Maximum length of a slug, in bytes.
+pub const MAX_SLUG_LEN: u32 = 32;
+/// Maximum length of a geohash, in characters (about 4 cm precision).
+pub const MAX_GEOHASH_LEN: u32 = 12;
+
+/// Characters of the geohash base32 alphabet (no `a`, `i`, `l` or `o`).
+const GEOHASH_ALPHABET: &[u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Slugs that collide with platform subdomains or routes.
+const RESERVED_SLUGS: [&str; 12] = [
+    "admin",
+    "api",
+    "app",
+    "assets",
+    "auth",
+    "dashboard",
+    "help",
+    "mail",
+    "static",
+    "status",
+    "support",
+    "www",
+];
+
+/// Check a restaurant or brand name.
+pub(crate) fn validate_name(name: &String) -> Result<(), Error> {
+    if name.is_empty() {
+        return Err(Error::NameEmpty);
+    }
+    if name.len() > MAX_NAME_LEN {
+        return Err(Error::NameTooLong);
+    }
+    Ok(())
+}
+
+/// Check that `slug` is usable as a subdomain.
+pub(crate) fn validate_slug(slug: &String) -> Result<(), Error> {
+    let len = slug.len();
+    if len < MIN_SLUG_LEN {
+        return Err(Error::SlugTooShort);
+    }
+    if len > MAX_SLUG_LEN {
+        return Err(Error::SlugTooLong);
+    }
+
+    let mut buf = [0u8; MAX_SLUG_LEN as usize];
+    let bytes = &mut buf[..len as usize];
+    slug.copy_into_slice(bytes);
+
+    let mut previous = b'-';
+    for &byte in bytes.iter() {
+        match byte {
+            b'a'..=b'z' | b'0'..=b'9' => {}
+            b'-' if previous != b'-' => {}
+            b'-' => return Err(Error::SlugInvalidHyphen),
+            _ => return Err(Error::SlugInvalidCharacter),
+        }
+        previous = byte;
+    }
+    if previous == b'-' {
+        return Err(Error::SlugInvalidHyphen);
+    }
+
+    if RESERVED_SLUGS.iter().any(|word| word.as_bytes() == &*bytes) {
+        return Err(Error::SlugReserved);
+    }
+    Ok(())
+}
+
+/// Check a profile geohash. An empty geohash means the location is unset.
+pub(crate) fn validate_geohash(geohash: &String) -> Result<(), Error> {
+    let len = geohash.len();
+    if len > MAX_GEOHASH_LEN {
+        return Err(Error::InvalidGeohash);
+    }
+
+    let mut buf = [0u8; MAX_GEOHASH_LEN as usize];
+    let bytes = &mut buf[..len as usize];
+    geohash.copy_into_slice(bytes);
+    if !bytes.iter().all(|byte| GEOHASH_ALPHABET.contains(byte)) {
+        return Err(Error::InvalidGeohash);
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::{validate_geohash, validate_name, validate_slug};
+    use crate::Error;
+    use soroban_sdk::{Env, String};
+
+    #[test]
+    fn test_slug_rules() {
+        let env = Env::default();
+        let check = |s: &str| validate_slug(&String::from_str(&env, s));
+
+        assert_eq!(check("mamas-kitchen-2"), Ok(()));
+        assert_eq!(check("ab"), Err(Error::SlugTooShort));
+        assert_eq!(
+            check("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"),
+            Err(Error::SlugTooLong)
+        );
+        assert_eq!(check("Mamas"), Err(Error::SlugInvalidCharacter));
+        assert_eq!(check("mamas_kitchen"), Err(Error::SlugInvalidCharacter));
+        assert_eq!(check("mamás"), Err(Error::SlugInvalidCharacter));
+        assert_eq!(check("-mamas"), Err(Error::SlugInvalidHyphen));
+        assert_eq!(check("mamas-"), Err(Error::SlugInvalidHyphen));
+        assert_eq!(check("mamas--kitchen"), Err(Error::SlugInvalidHyphen));
+        assert_eq!(check("admin"), Err(Error::SlugReserved));
+    }
+
+    #[test]
+    fn test_name_rules() {
+        let env = Env::default();
+        let check = |s: &str| validate_name(&String::from_str(&env, s));
+
+        assert_eq!(check("Mama's Kitchen"), Ok(()));
+        assert_eq!(check(""), Err(Error::NameEmpty));
+        assert_eq!(
+            check("xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx"),
+            Err(Error::NameTooLong)
+        );
+    }
+
+    #[test]
+    fn test_geohash_rules() {
+        let env = Env::default();
+        let check = |s: &str| validate_geohash(&String::from_str(&env, s));
+
+        assert_eq!(check(""), Ok(()));
+        assert_eq!(check("s14bk7"), Ok(()));
+        assert_eq!(check("s14bk7zzzzzz"), Ok(()));
+        assert_eq!(check("s14bk7zzzzzzz"), Err(Error::InvalidGeohash));
+        assert_eq!(check("s14bka"), Err(Error::InvalidGeohash));
+        assert_eq!(check("S14BK7"), Err(Error::InvalidGeohash));
+    }
+}
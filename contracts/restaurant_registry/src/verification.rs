@@ -0,0 +1,333 @@
+//! # Verification
+//!
+//! The admin appoints attesters (e.g. a KYC provider or a food-safety
+//! inspector). Attesters record [`Attestation`]s against a restaurant, each
+//! at a [`VerificationLevel`], with a hash of the off-chain evidence and an
+//! expiry.
+//!
+//! A restaurant's effective level is the highest level among its attestations
+//! that have not expired and whose attester is still appointed, so removing a
+//! compromised attester withdraws everything it has attested. The Order and
+//! Payment contracts compare this level against a per-deployment minimum.
+
+use soroban_sdk::{contractimpl, contracttype, vec, Address, BytesN, Env, Vec};
+
+use crate::events::{
+    AttestationRevoked, AttesterAdded, AttesterRemoved, Event, RestaurantAttested,
+};
+use crate::{
+    assert_admin, extend_persistent, load_restaurant, read_admin, DataKey, Error,
+    RestaurantRegistry, RestaurantRegistryArgs, RestaurantRegistryClient,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+/// How thoroughly a restaurant has been vetted. Higher levels imply the
+/// lower ones.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VerificationLevel {
+    /// No valid attestation.
+    Unverified = 0,
+    /// The owner's identity has been checked.
+    Identity = 1,
+    /// The restaurant holds a valid food-business licence.
+    FoodLicense = 2,
+}
+
+/// A verification recorded by an attester.
+#[contracttype]
+#[derive(Clone)]
+pub struct Attestation {
+    pub level: VerificationLevel,
+    pub attester: Address,
+    /// Hash of the off-chain evidence document.
+    pub evidence_hash: BytesN<32>,
+    /// Ledger timestamp at which the attestation was recorded.
+    pub issued_at: u64,
+    /// Ledger timestamp from which the attestation no longer counts.
+    pub expires_at: u64,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contractimpl]
+impl RestaurantRegistry {
+    /// Appoint `attester` (admin only).
+    pub fn add_attester(env: Env, admin: Address, attester: Address) -> Result<(), Error> {
+        admin.require_auth();
+        assert_admin(&env, &admin)?;
+
+        let key = DataKey::Attester(attester.clone());
+        env.storage().persistent().set(&key, &true);
+        extend_persistent(&env, &key);
+
+        AttesterAdded { attester }.publish(&env);
+        Ok(())
+    }
+
+    /// Remove an attester (admin only). Its attestations stop counting.
+    pub fn remove_attester(env: Env, admin: Address, attester: Address) -> Result<(), Error> {
+        admin.require_auth();
+        assert_admin(&env, &admin)?;
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Attester(attester.clone()));
+
+        AttesterRemoved { attester }.publish(&env);
+        Ok(())
+    }
+
+    /// Whether `address` is an appointed attester.
+    pub fn is_attester(env: Env, address: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Attester(address))
+    }
+
+    /// Record that a restaurant meets `level`, until `expires_at`.
+    ///
+    /// Only appointed attesters may call this. Replaces any existing
+    /// attestation at the same level.
+    pub fn attest(
+        env: Env,
+        attester: Address,
+        restaurant_id: u64,
+        level: VerificationLevel,
+        evidence_hash: BytesN<32>,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        attester.require_auth();
+
+        if !Self::is_attester(env.clone(), attester.clone()) {
+            return Err(Error::NotAttester);
+        }
+        load_restaurant(&env, restaurant_id)?;
+        if level == VerificationLevel::Unverified {
+            return Err(Error::InvalidVerificationLevel);
+        }
+        let now = env.ledger().timestamp();
+        if expires_at <= now {
+            return Err(Error::ExpiryInPast);
+        }
+
+        let mut attestations = without_level(&env, restaurant_id, level);
+        attestations.push_back(Attestation {
+            level,
+            attester: attester.clone(),
+            evidence_hash,
+            issued_at: now,
+            expires_at,
+        });
+        save_attestations(&env, restaurant_id, &attestations);
+
+        RestaurantAttested {
+            restaurant_id,
+            level,
+            attester,
+            expires_at,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Withdraw a restaurant's attestation at `level`.
+    ///
+    /// Callable by the attester that recorded it **or** the contract admin.
+    pub fn revoke_attestation(
+        env: Env,
+        caller: Address,
+        restaurant_id: u64,
+        level: VerificationLevel,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let attestation = Self::get_attestations(env.clone(), restaurant_id)
+            .iter()
+            .find(|a| a.level == level)
+            .ok_or(Error::AttestationNotFound)?;
+        if caller != read_admin(&env)? && caller != attestation.attester {
+            return Err(Error::Unauthorized);
+        }
+
+        let attestations = without_level(&env, restaurant_id, level);
+        save_attestations(&env, restaurant_id, &attestations);
+
+        AttestationRevoked {
+            restaurant_id,
+            level,
+            revoked_by: caller,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Every attestation recorded for a restaurant, including expired ones.
+    pub fn get_attestations(env: Env, restaurant_id: u64) -> Vec<Attestation> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Attestations(restaurant_id))
+            .unwrap_or_else(|| vec![&env])
+    }
+
+    /// A restaurant's current verification level.
+    pub fn verification_level(env: Env, restaurant_id: u64) -> VerificationLevel {
+        let now = env.ledger().timestamp();
+        let mut level = VerificationLevel::Unverified;
+        for attestation in Self::get_attestations(env.clone(), restaurant_id).iter() {
+            if attestation.expires_at > now
+                && attestation.level > level
+                && Self::is_attester(env.clone(), attestation.attester)
+            {
+                level = attestation.level;
+            }
+        }
+        level
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn without_level(env: &Env, restaurant_id: u64, level: VerificationLevel) -> Vec<Attestation> {
+    let mut kept = vec![env];
+    for attestation in RestaurantRegistry::get_attestations(env.clone(), restaurant_id).iter() {
+        if attestation.level != level {
+            kept.push_back(attestation);
+        }
+    }
+    kept
+}
+
+fn save_attestations(env: &Env, restaurant_id: u64, attestations: &Vec<Attestation>) {
+    let key = DataKey::Attestations(restaurant_id);
+    env.storage().persistent().set(&key, attestations);
+    extend_persistent(env, &key);
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::VerificationLevel;
+    use crate::{Error, RestaurantRegistry, RestaurantRegistryClient};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, BytesN, Env, String};
+
+    fn setup() -> (
+        Env,
+        RestaurantRegistryClient<'static>,
+        Address,
+        Address,
+        u64,
+    ) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(RestaurantRegistry, ());
+        let client = RestaurantRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let attester = Address::generate(&env);
+        client.initialize(&admin);
+        client.add_attester(&admin, &attester);
+        let id = client.register_restaurant(
+            &Address::generate(&env),
+            &String::from_str(&env, "Mama's Kitchen"),
+            &String::from_str(&env, "mamas-kitchen"),
+        );
+        (env, client, admin, attester, id)
+    }
+
+    #[test]
+    fn test_highest_unexpired_level_counts() {
+        let (env, client, _admin, attester, id) = setup();
+        let evidence = BytesN::from_array(&env, &[1; 32]);
+        assert_eq!(
+            client.verification_level(&id),
+            VerificationLevel::Unverified
+        );
+
+        env.ledger().set_timestamp(1_000);
+        client.attest(
+            &attester,
+            &id,
+            &VerificationLevel::Identity,
+            &evidence,
+            &5_000,
+        );
+        client.attest(
+            &attester,
+            &id,
+            &VerificationLevel::FoodLicense,
+            &evidence,
+            &2_000,
+        );
+        assert_eq!(
+            client.verification_level(&id),
+            VerificationLevel::FoodLicense
+        );
+
+        // The licence lapses first; identity verification still holds.
+        env.ledger().set_timestamp(2_000);
+        assert_eq!(client.verification_level(&id), VerificationLevel::Identity);
+
+        env.ledger().set_timestamp(5_000);
+        assert_eq!(
+            client.verification_level(&id),
+            VerificationLevel::Unverified
+        );
+        assert_eq!(client.get_attestations(&id).len(), 2);
+    }
+
+    #[test]
+    fn test_revoke_and_remove_attester() {
+        let (env, client, admin, attester, id) = setup();
+        let evidence = BytesN::from_array(&env, &[1; 32]);
+
+        client.attest(
+            &attester,
+            &id,
+            &VerificationLevel::FoodLicense,
+            &evidence,
+            &1_000,
+        );
+        client.attest(
+            &attester,
+            &id,
+            &VerificationLevel::Identity,
+            &evidence,
+            &1_000,
+        );
+        client.revoke_attestation(&attester, &id, &VerificationLevel::FoodLicense);
+        assert_eq!(client.verification_level(&id), VerificationLevel::Identity);
+
+        client.remove_attester(&admin, &attester);
+        assert!(!client.is_attester(&attester));
+        assert_eq!(
+            client.verification_level(&id),
+            VerificationLevel::Unverified
+        );
+    }
+
+    #[test]
+    fn test_non_attester_cannot_attest() {
+        let (env, client, _admin, _attester, id) = setup();
+        assert_eq!(
+            client.try_attest(
+                &Address::generate(&env),
+                &id,
+                &VerificationLevel::Identity,
+                &BytesN::from_array(&env, &[1; 32]),
+                &1_000,
+            ),
+            Err(Ok(Error::NotAttester))
+        );
+    }
+}
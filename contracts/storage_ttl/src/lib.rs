@@ -0,0 +1,138 @@
+//! # Storage TTL
+//!
+//! The TTL policy and extension helpers shared by every contract in the
+//! workspace. Each contract stores its own [`TtlPolicy`], which the admin
+//! may tune, and extends the entries it writes according to it.
+//!
+//! Contracts cannot read an entry's TTL, so a contract records the ledger
+//! until which it has extended each tracked record under a "live until" key
+//! and reports from that record. Entries extended outside the contract
+//! (e.g. by an `ExtendFootprintTTLOp`) may live longer than reported.
+
+#![no_std]
+
+use soroban_sdk::{contracttype, Env, IntoVal, Val};
+
+/// Ledgers in one day, at five seconds per ledger.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// How long entries are kept alive, in ledgers.
+///
+/// An entry is extended to `*_extend_to` ledgers whenever it is written or
+/// bumped with fewer than `*_threshold` ledgers left.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TtlPolicy {
+    /// Applies to the contract instance (admin, counters, this policy).
+    pub instance_threshold: u32,
+    pub instance_extend_to: u32,
+    /// Applies to every persistent record.
+    pub persistent_threshold: u32,
+    pub persistent_extend_to: u32,
+}
+
+impl TtlPolicy {
+    /// One day for the instance, about 120 days for records.
+    pub const DEFAULT: TtlPolicy = TtlPolicy {
+        instance_threshold: DAY_IN_LEDGERS,
+        instance_extend_to: DAY_IN_LEDGERS,
+        persistent_threshold: 120 * DAY_IN_LEDGERS,
+        persistent_extend_to: 120 * DAY_IN_LEDGERS,
+    };
+
+    /// Whether every threshold is within its extension, and every extension
+    /// is non-zero and at most `max_ttl`.
+    pub fn is_valid(&self, max_ttl: u32) -> bool {
+        [
+            (self.instance_threshold, self.instance_extend_to),
+            (self.persistent_threshold, self.persistent_extend_to),
+        ]
+        .iter()
+        .all(|&(threshold, extend_to)| {
+            extend_to > 0 && threshold <= extend_to && extend_to <= max_ttl
+        })
+    }
+}
+
+/// The policy stored in instance storage under `key`, or
+/// [`TtlPolicy::DEFAULT`] if none was set.
+pub fn policy<K: IntoVal<Env, Val>>(env: &Env, key: &K) -> TtlPolicy {
+    env.storage()
+        .instance()
+        .get(key)
+        .unwrap_or(TtlPolicy::DEFAULT)
+}
+
+/// Extend the contract instance according to `policy`.
+pub fn extend_instance(env: &Env, policy: &TtlPolicy) {
+    env.storage()
+        .instance()
+        .extend_ttl(policy.instance_threshold, policy.instance_extend_to);
+}
+
+/// Extend a persistent entry according to `policy`.
+pub fn extend_persistent<K: IntoVal<Env, Val>>(env: &Env, policy: &TtlPolicy, key: &K) {
+    env.storage().persistent().extend_ttl(
+        key,
+        policy.persistent_threshold,
+        policy.persistent_extend_to,
+    );
+}
+
+/// Extend a tracked record and record until when it lives.
+///
+/// If fewer than `persistent_threshold` ledgers are recorded under
+/// `live_until_key`, `extend` is called with the number of ledgers to extend
+/// the record's entries to, and the new lifetime is recorded. Returns the
+/// record's remaining TTL.
+pub fn extend_tracked<K, F>(env: &Env, policy: &TtlPolicy, live_until_key: &K, extend: F) -> u32
+where
+    K: IntoVal<Env, Val>,
+    F: FnOnce(u32),
+{
+    let remaining = remaining_ttl(env, live_until_key);
+    if remaining >= policy.persistent_threshold {
+        return remaining;
+    }
+
+    let extend_to = policy.persistent_extend_to;
+    extend(extend_to);
+    let storage = env.storage().persistent();
+    let remaining = extend_to.min(env.storage().max_ttl());
+    storage.set(live_until_key, &(env.ledger().sequence() + remaining));
+    storage.extend_ttl(live_until_key, extend_to, extend_to);
+    remaining
+}
+
+/// Remaining TTL recorded under `live_until_key`; `0` if none was recorded.
+pub fn remaining_ttl<K: IntoVal<Env, Val>>(env: &Env, live_until_key: &K) -> u32 {
+    let live_until: u32 = env.storage().persistent().get(live_until_key).unwrap_or(0);
+    live_until.saturating_sub(env.ledger().sequence())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::{TtlPolicy, DAY_IN_LEDGERS};
+
+    #[test]
+    fn test_policy_validity() {
+        let max_ttl = 200 * DAY_IN_LEDGERS;
+        assert!(TtlPolicy::DEFAULT.is_valid(max_ttl));
+        assert!(!TtlPolicy::DEFAULT.is_valid(DAY_IN_LEDGERS));
+        assert!(!TtlPolicy {
+            persistent_threshold: 121 * DAY_IN_LEDGERS,
+            ..TtlPolicy::DEFAULT
+        }
+        .is_valid(max_ttl));
+        assert!(!TtlPolicy {
+            instance_threshold: 0,
+            instance_extend_to: 0,
+            ..TtlPolicy::DEFAULT
+        }
+        .is_valid(max_ttl));
+    }
+}